@@ -0,0 +1,254 @@
+//! # 动画模块 (`animation`)
+//!
+//! `animation` 模块提供按名称区分的动画片段 (`AnimationClip`) 和每个实体持有的动画播放器 (`Animator`)。
+//!
+//! 动画片段与资源清单中的贴图一一对应，名称相同。片段的帧率、播放方式（循环、停在最后一帧、往返）
+//! 和帧事件（例如 "头部在第 2 帧掉落"）写在 `assets.toml` 中；贴图加载后，片段的帧数和每帧时长
+//! 会按实际加载到的帧（以及 GIF 中每帧的延迟）更新。
+//!
+//! 实体按自身状态调用 `Animator::play` 切换片段，每次更新时 `Animator::update` 返回这段时间内触发的帧事件，
+//! 无需手工维护帧计数器。片段表在第一次使用时从资源目录读取清单，因此在没有图形上下文的测试中也可以使用。
+
+use crate::core::asset_manifest::{AssetManifest, SpriteEntry};
+use crate::core::resources::RESOURCE_DIR;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+
+/// 当前生效的动画片段，按贴图名称索引。
+static CLIPS: LazyLock<RwLock<HashMap<String, AnimationClip>>> = LazyLock::new(|| {
+    let clips = AssetManifest::load(Path::new(RESOURCE_DIR))
+        .map(|manifest| clips_from_manifest(&manifest))
+        .unwrap_or_default();
+    RwLock::new(clips)
+});
+
+/// 动画片段的播放方式。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaybackMode {
+    /// 播放到最后一帧后从第一帧重新开始。
+    #[default]
+    Loop,
+    /// 只播放一次，结束后停在最后一帧。
+    HoldLast,
+    /// 正向播放到最后一帧后反向播放回第一帧，如此往返。
+    PingPong,
+}
+
+/// 一个动画片段：每帧的显示时长、播放方式和帧事件。
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationClip {
+    /// 每帧的显示时长（毫秒），长度即帧数。
+    pub frame_durations: Vec<u64>,
+    /// 播放方式。
+    pub mode: PlaybackMode,
+    /// 帧事件：事件名称到帧序号（从 0 开始）的映射，播放到该帧时触发。
+    pub events: BTreeMap<String, usize>,
+}
+
+impl Default for AnimationClip {
+    /// 没有定义的片段：只有一帧、循环播放、没有帧事件。
+    fn default() -> Self {
+        AnimationClip { frame_durations: vec![frame_duration(10.0)], mode: PlaybackMode::Loop, events: BTreeMap::new() }
+    }
+}
+
+/// 把帧率换算成每帧的显示时长（毫秒），至少为 1 毫秒。
+pub fn frame_duration(fps: f32) -> u64 {
+    ((1000.0 / fps).round() as u64).max(1)
+}
+
+impl AnimationClip {
+    /// 根据清单条目创建动画片段。帧数取自条目的帧号范围，没有范围时视为一帧。
+    pub fn from_entry(entry: &SpriteEntry) -> AnimationClip {
+        let frame_count = entry.frames.map_or(1, |[first, last]| last - first + 1);
+        AnimationClip {
+            frame_durations: vec![frame_duration(entry.fps); frame_count],
+            mode: entry.mode,
+            events: entry.events.clone(),
+        }
+    }
+
+    /// 片段的帧数。
+    pub fn frame_count(&self) -> usize {
+        self.frame_durations.len()
+    }
+
+    /// 一个播放周期中依次显示的帧序号。往返播放时不重复两端的帧。
+    fn timeline(&self) -> Vec<usize> {
+        let count = self.frame_count();
+        let mut timeline: Vec<usize> = (0..count).collect();
+        if self.mode == PlaybackMode::PingPong && count > 2 {
+            timeline.extend((1..count - 1).rev());
+        }
+        timeline
+    }
+
+    /// 一个播放周期的总时长（毫秒）。对于 `HoldLast` 即播放完成所需的时间。
+    pub fn duration(&self) -> u64 {
+        self.timeline().iter().map(|&frame| self.frame_durations[frame]).sum()
+    }
+
+    /// 片段播放了 `elapsed_ms` 毫秒时显示的帧序号。
+    pub fn frame_at(&self, elapsed_ms: u64) -> usize {
+        let timeline = self.timeline();
+        let duration = self.duration();
+        let last = timeline.last().copied().unwrap_or(0);
+        let mut time = match self.mode {
+            PlaybackMode::HoldLast if elapsed_ms >= duration => return last,
+            PlaybackMode::HoldLast => elapsed_ms,
+            _ if duration == 0 => return last,
+            _ => elapsed_ms % duration,
+        };
+        for frame in timeline {
+            let frame_duration = self.frame_durations[frame];
+            if time < frame_duration {
+                return frame;
+            }
+            time -= frame_duration;
+        }
+        last
+    }
+
+    /// 只播放一次的片段在播放了 `elapsed_ms` 毫秒时是否已经播放完成。循环和往返播放的片段永远不会完成。
+    pub fn is_finished(&self, elapsed_ms: u64) -> bool {
+        self.mode == PlaybackMode::HoldLast && elapsed_ms >= self.duration()
+    }
+
+    /// 列出播放时间从 `from` 推进到 `to`（毫秒）时进入对应帧的帧事件，按触发顺序排列。
+    ///
+    /// 恰好在 `to` 时刻进入的帧会触发事件；第一帧的事件在从 0 开始播放时触发。
+    pub fn events_between(&self, from: u64, to: u64) -> Vec<String> {
+        if self.events.is_empty() || from >= to {
+            return Vec::new();
+        }
+
+        let duration = self.duration();
+        let repeats = self.mode != PlaybackMode::HoldLast && duration > 0;
+        let earliest = if from == 0 { 0 } else { from + 1 };
+        let mut fired = Vec::new();
+        let mut start = 0;
+        for frame in self.timeline() {
+            for (name, _) in self.events.iter().filter(|(_, event_frame)| **event_frame == frame) {
+                let mut time = start;
+                if repeats && time < earliest {
+                    time += (earliest - time).div_ceil(duration) * duration;
+                }
+                while time >= earliest && time <= to {
+                    fired.push((time, name.clone()));
+                    if !repeats {
+                        break;
+                    }
+                    time += duration;
+                }
+            }
+            start += self.frame_durations[frame];
+        }
+        fired.sort();
+        fired.into_iter().map(|(_, name)| name).collect()
+    }
+}
+
+/// 为清单中的每个条目创建动画片段。
+fn clips_from_manifest(manifest: &AssetManifest) -> HashMap<String, AnimationClip> {
+    manifest.sprites.iter().map(|(name, entry)| (name.clone(), AnimationClip::from_entry(entry))).collect()
+}
+
+/// 按资源清单重新建立所有动画片段。
+pub fn install_manifest(manifest: &AssetManifest) {
+    *CLIPS.write().unwrap() = clips_from_manifest(manifest);
+}
+
+/// 按实际加载到的帧更新片段的帧数和每帧时长，片段不存在时以默认播放方式创建。
+///
+/// # Arguments
+///
+/// * `name` - 贴图（片段）名称。
+/// * `frame_durations` - 每帧的显示时长（毫秒），不能为空。
+pub fn set_frame_durations(name: &str, frame_durations: Vec<u64>) {
+    if frame_durations.is_empty() {
+        return;
+    }
+    let mut clips = CLIPS.write().unwrap();
+    clips.entry(name.to_string()).or_default().frame_durations = frame_durations;
+}
+
+/// 获取名称对应的动画片段，没有定义时返回只有一帧的默认片段。
+pub fn clip(name: &str) -> AnimationClip {
+    CLIPS.read().unwrap().get(name).cloned().unwrap_or_default()
+}
+
+/// 获取名称对应的片段在播放了 `elapsed_ms` 毫秒时显示的帧序号。
+pub fn frame_at(name: &str, elapsed_ms: u64) -> usize {
+    CLIPS.read().unwrap().get(name).map_or(0, |clip| clip.frame_at(elapsed_ms))
+}
+
+/// 实体持有的动画播放器：记录当前播放的片段和播放时间。
+#[derive(Debug, Clone)]
+pub struct Animator {
+    /// 当前片段的名称（即贴图名称）。
+    clip_name: String,
+    /// 当前片段，切换片段时从片段表中复制。
+    clip: AnimationClip,
+    /// 当前片段已经播放的时间（毫秒）。
+    elapsed: u64,
+}
+
+impl Animator {
+    /// 创建一个从头播放指定片段的播放器。
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_name` - 片段名称，即资源清单中的贴图名称。
+    pub fn new(clip_name: &str) -> Animator {
+        Animator { clip_name: clip_name.to_string(), clip: clip(clip_name), elapsed: 0 }
+    }
+
+    /// 切换到指定片段并从头播放。已经在播放该片段时不做任何事。
+    ///
+    /// # Arguments
+    ///
+    /// * `clip_name` - 片段名称。
+    pub fn play(&mut self, clip_name: &str) {
+        if self.clip_name != clip_name {
+            *self = Animator::new(clip_name);
+        }
+    }
+
+    /// 推进播放时间。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 经过的时间（毫秒）。
+    ///
+    /// # Returns
+    ///
+    /// 返回这段时间内触发的帧事件名称，按触发顺序排列。
+    pub fn update(&mut self, dt: u64) -> Vec<String> {
+        let from = self.elapsed;
+        self.elapsed += dt;
+        self.clip.events_between(from, self.elapsed)
+    }
+
+    /// 当前片段的名称。
+    pub fn clip_name(&self) -> &str {
+        &self.clip_name
+    }
+
+    /// 当前显示的帧序号。
+    pub fn frame(&self) -> usize {
+        self.clip.frame_at(self.elapsed)
+    }
+
+    /// 当前片段已经播放的时间（毫秒）。
+    pub fn elapsed(&self) -> u64 {
+        self.elapsed
+    }
+
+    /// 当前片段是否已经播放完成，只有 `HoldLast` 片段会完成。
+    pub fn is_finished(&self) -> bool {
+        self.clip.is_finished(self.elapsed)
+    }
+}
@@ -0,0 +1,186 @@
+//! # 资源清单模块 (`asset_manifest`)
+//!
+//! `asset_manifest` 模块描述资源目录下的 `assets.toml`：清单按名称列出游戏用到的每一个贴图，
+//! 包括文件路径模式、帧号范围、播放帧率、绘制原点和缩放。尚无素材的贴图可以声明一个占位图像。
+//! 路径以 `.gif` 结尾的贴图是 GIF 动画，帧序列和每帧的延迟直接从文件解码。
+//! 标记为 `required` 的贴图缺失时游戏无法启动，其余缺失的贴图以棋盘格占位图代替。
+//!
+//! 每个贴图同时也是一个同名的动画片段，条目中的 `mode` 和 `events` 决定片段的播放方式和帧事件（见 `animation` 模块）。
+//!
+//! 清单只是纯数据，解析和校验不需要 ggez 的上下文；`Resources` 按清单加载图像，
+//! 动画的帧数以实际加载到的图像为准。
+
+use crate::core::animation::PlaybackMode;
+use ggez::{GameError, GameResult};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// 资源清单的文件名。
+pub const MANIFEST_FILE_NAME: &str = "assets.toml";
+
+/// 资源清单：贴图名称到贴图条目的映射。
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssetManifest {
+    /// 按名称排列的贴图条目。名称即 `Resources` 中的贴图键。
+    pub sprites: BTreeMap<String, SpriteEntry>,
+}
+
+/// 清单中的一个贴图条目。
+///
+/// `path` 与 `placeholder` 必须且只能给出一个。
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpriteEntry {
+    /// 相对资源目录的图像路径，例如 `"/plants/Peashooter/{}.png"`。给出 `frames` 时 `{}` 会被帧号替换。
+    /// 以 `.gif` 结尾时按 GIF 动画解码。
+    pub path: Option<String>,
+    /// 帧号范围 `[首帧, 末帧]`（包含两端）。不给出时 `path` 指向单张图像或 GIF 动画。
+    pub frames: Option<[usize; 2]>,
+    /// 动画的播放帧率（帧/秒）。GIF 动画使用文件中每帧的延迟，忽略此项。
+    #[serde(default = "default_fps")]
+    pub fps: f32,
+    /// 绘制原点，以图像宽高的比例表示，`[0.0, 0.0]` 为左上角，`[0.5, 0.5]` 为中心。
+    #[serde(default)]
+    pub origin: [f32; 2],
+    /// 在草坪上绘制时的缩放。
+    #[serde(default = "default_scale")]
+    pub scale: [f32; 2],
+    /// 尚无素材时使用的占位图像。
+    pub placeholder: Option<Placeholder>,
+    /// 是否为必需的素材。必需的素材缺失时加载会返回错误，而不是使用棋盘格占位图。
+    #[serde(default)]
+    pub required: bool,
+    /// 动画片段的播放方式：循环、播放一次后停在最后一帧或往返播放。
+    #[serde(default)]
+    pub mode: PlaybackMode,
+    /// 帧事件：事件名称到帧序号（从 0 开始）的映射，例如 `{ head_drop = 2 }`。
+    #[serde(default)]
+    pub events: BTreeMap<String, usize>,
+}
+
+/// 占位图像的描述：在指定形状的画布上绘制一个彩色椭圆。
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Placeholder {
+    /// 椭圆的 RGBA 颜色。
+    pub color: [u8; 4],
+    /// 占位图像的画布形状。
+    pub shape: PlaceholderShape,
+}
+
+/// 占位图像的画布形状。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlaceholderShape {
+    /// 64×64 的透明画布，用于草坪上的植物。
+    Plant,
+    /// 与商店卡片尺寸相近、带卡片底色的画布。
+    Card,
+    /// 24×24 的透明画布，用于投射物。
+    Projectile,
+}
+
+/// 清单中未写出 `fps` 时的默认帧率。
+fn default_fps() -> f32 {
+    10.0
+}
+
+/// 清单中未写出 `scale` 时的默认缩放。
+fn default_scale() -> [f32; 2] {
+    [1.0, 1.0]
+}
+
+impl SpriteEntry {
+    /// 该条目是否指向一个 GIF 动画。
+    pub fn is_gif(&self) -> bool {
+        self.path.as_ref().is_some_and(|path| path.to_ascii_lowercase().ends_with(".gif"))
+    }
+
+    /// 列出该条目需要加载的所有图像路径，按帧号顺序排列。占位条目返回空列表。
+    pub fn frame_paths(&self) -> Vec<String> {
+        match (&self.path, self.frames) {
+            (Some(path), Some([first, last])) => (first..=last).map(|i| path.replace("{}", &i.to_string())).collect(),
+            (Some(path), None) => vec![path.clone()],
+            (None, _) => Vec::new(),
+        }
+    }
+}
+
+impl AssetManifest {
+    /// 从 TOML 文本解析资源清单并进行校验。
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - 清单文件的内容。
+    ///
+    /// # Returns
+    ///
+    /// 解析并校验成功返回 `AssetManifest`，否则返回 `GameError::ResourceLoadError`。
+    pub fn from_toml(content: &str) -> GameResult<AssetManifest> {
+        let manifest: AssetManifest = toml::from_str(content)
+            .map_err(|e| GameError::ResourceLoadError(format!("failed to parse {}: {}", MANIFEST_FILE_NAME, e)))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    /// 从指定目录读取 `assets.toml`。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - 资源目录（通常是 `Resource`）。
+    ///
+    /// # Returns
+    ///
+    /// 读取、解析并校验成功返回 `AssetManifest`，否则返回 `GameError::ResourceLoadError`。
+    pub fn load(dir: &Path) -> GameResult<AssetManifest> {
+        let path = dir.join(MANIFEST_FILE_NAME);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| GameError::ResourceLoadError(format!("failed to read {}: {}", path.display(), e)))?;
+        AssetManifest::from_toml(&content)
+    }
+
+    /// 检查每个条目是否完整且数值合理。
+    fn validate(&self) -> GameResult {
+        let invalid = |name: &str, reason: &str| {
+            Err(GameError::ResourceLoadError(format!("sprite '{}' in {}: {}", name, MANIFEST_FILE_NAME, reason)))
+        };
+
+        for (name, entry) in &self.sprites {
+            match (&entry.path, &entry.placeholder) {
+                (None, None) => return invalid(name, "needs either a path or a placeholder"),
+                (Some(_), Some(_)) => return invalid(name, "cannot have both a path and a placeholder"),
+                _ => {}
+            }
+            if entry.required && entry.placeholder.is_some() {
+                return invalid(name, "a placeholder sprite cannot be required");
+            }
+            if entry.is_gif() && entry.frames.is_some() {
+                return invalid(name, "GIF sprites take their frames from the file and cannot have a frame range");
+            }
+            if let (Some(path), Some([first, last])) = (&entry.path, entry.frames) {
+                if first > last {
+                    return invalid(name, "frame range is empty");
+                }
+                if !path.contains("{}") {
+                    return invalid(name, "animated path must contain '{}' for the frame number");
+                }
+            }
+            if !entry.is_gif() {
+                let frame_count = entry.frames.map_or(1, |[first, last]| last.saturating_sub(first) + 1);
+                if entry.events.values().any(|frame| *frame >= frame_count) {
+                    return invalid(name, "event frame is outside the animation");
+                }
+            }
+            if !(entry.fps.is_finite() && entry.fps > 0.0) {
+                return invalid(name, "fps must be positive");
+            }
+            if entry.scale.iter().any(|s| !(s.is_finite() && *s > 0.0)) {
+                return invalid(name, "scale must be positive");
+            }
+        }
+        Ok(())
+    }
+}
@@ -5,22 +5,52 @@
 
 use crate::ui::grid::Grid;
 use crate::plants::PlantType;
-use crate::core::resources::Resources;
-use crate::entities::sun::Sun;
-use crate::zombies::Zombie;
-use crate::entities::pea::Pea;
+use crate::core::resources::{Resources, RESOURCE_DIR};
+use crate::core::asset_manifest::MANIFEST_FILE_NAME;
+use crate::core::hot_reload::FileWatcher;
+use crate::entities::sun::{Sun, SUN_VALUE};
+use crate::zombies::{self, Zombie, ZombieType};
+use crate::entities::projectile::Projectile;
 use crate::ui::shop::Shop;
 use crate::ui::shovel::Shovel;
-use crate::plants::Plant;
+use crate::plants::{Plant, PlantEvent, WorldEffect, WorldState};
 use crate::mechanics::collision::CollisionManager;
 use crate::mechanics::entity_manager::EntityManager;
+use crate::mechanics::level_controller::ENDLESS_ZOMBIES;
 use crate::ui::input_handler::InputHandler;
-use crate::core::renderer::Renderer;
+use crate::core::renderer::{GameView, Renderer};
+use crate::core::render_cache::RenderCache;
+use crate::core::profile::Profile;
+use crate::core::tuning;
+use crate::core::leaderboard::{EndlessStats, Leaderboard};
+use crate::entities::reward::SeedPacketReward;
+use crate::mechanics::campaign::{self, Environment, LevelDefinition};
+use crate::ui::level_select::{LevelChoice, LevelSelect};
+use crate::ui::seed_chooser::SeedChooser;
+use crate::ui::fog::Fog;
+use crate::ui::lawn::LawnGeometry;
 
 use ggez::event::EventHandler;
+use ggez::input::keyboard::{KeyCode, KeyMods};
 use ggez::input::mouse::MouseButton;
 use ggez::{Context, GameResult};
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// 调校文件 `tuning.toml` 和关卡文件 `levels.toml` 所在的目录。
+const TUNING_DIR: &str = "Resource";
+
+/// 调试构建中重新读取调校文件的按键。
+const RELOAD_TUNING_KEY: KeyCode = KeyCode::F5;
+
+/// 加载画面中每一帧用于加载贴图的时间，其余时间留给绘制进度条。
+const LOADING_FRAME_BUDGET: Duration = Duration::from_millis(12);
+
+/// 判断点 `(x, y)` 是否落在以 `(x, y, width, height)` 表示的按钮区域内。
+fn button_contains(rect: (f32, f32, f32, f32), x: f32, y: f32) -> bool {
+    let (btn_x, btn_y, btn_w, btn_h) = rect;
+    x >= btn_x && x <= btn_x + btn_w && y >= btn_y && y <= btn_y + btn_h
+}
 
 /// 游戏状态结构体，封装了游戏世界中的所有动态数据和状态。
 ///
@@ -33,14 +63,16 @@ pub struct GameState {
     
     /// 游戏区域的网格布局。
     grid: Grid,
+    /// 覆盖草坪右侧的迷雾，只影响绘制。
+    fog: Fog,
     /// 当前场景中所有植物的集合。
     plants: Vec<Plant>,
     /// 当前场景中所有僵尸的集合。
     zombies: Vec<Zombie>,
     /// 当前场景中所有阳光的集合。
     suns: Vec<Sun>,
-    /// 当前场景中所有豌豆的集合。
-    peas: Vec<Pea>,
+    /// 当前场景中所有投射物（豌豆、抛物线投掷物等）的集合。
+    projectiles: Vec<Projectile>,
     
     /// 玩家当前的阳光数量。
     sun_count: i32,
@@ -64,17 +96,51 @@ pub struct GameState {
     
     /// 游戏当前的状态（运行中、暂停等）
     game_state: crate::core::states::GameState,
+    /// 加载完成后要进入的状态，仅在 `Loading` 状态中使用。
+    state_after_loading: crate::core::states::GameState,
     /// 暂停按钮的位置和尺寸
     pause_button_rect: (f32, f32, f32, f32),
     /// 游戏暂停的时间点
     pause_start_time: Option<std::time::Instant>,
     /// 标记是否为初始暂停状态
     is_initial_pause: bool,
+    /// "重新开始"按钮的位置和尺寸，在失败、胜利和暂停画面中显示
+    restart_button_rect: (f32, f32, f32, f32),
+    /// "返回选关"按钮的位置和尺寸，与"重新开始"按钮一同显示
+    level_select_button_rect: (f32, f32, f32, f32),
+
+    /// 玩家的冒险模式存档。
+    profile: Profile,
+    /// 存档所在的目录（用户数据目录）。
+    profile_dir: PathBuf,
+    /// 当前关卡的定义，在开始关卡时从关卡列表中复制，重新读取关卡文件不会影响正在进行的关卡。
+    current_level: LevelDefinition,
+    /// 关卡胜利后掉落、尚未领取的奖励卡片。
+    reward_packet: Option<SeedPacketReward>,
+    /// 关卡开始前的选卡界面，仅在选卡阶段存在。
+    seed_chooser: Option<SeedChooser>,
+    /// 玩家为当前关卡选择的卡片，重新开始关卡时沿用。
+    chosen_seeds: Vec<PlantType>,
+
+    /// 当前是否在进行无尽模式。
+    endless: bool,
+    /// 无尽模式本局的统计数据。
+    endless_stats: EndlessStats,
+    /// 无尽模式的本地排行榜。
+    leaderboard: Leaderboard,
+
+    /// 开发模式（调试构建）下监视资源目录的文件改动，发布构建中为 `None`。
+    watcher: Option<FileWatcher>,
+    /// 渲染器跨帧复用的精灵批次和文字缓存。
+    render_cache: RenderCache,
 }
 
 impl GameState {
     /// 创建并初始化一个新的 `GameState` 实例。
     ///
+    /// 此时只读取资源清单，界面和植物的贴图在随后的加载画面中分批加载，
+    /// 僵尸的贴图在进入关卡时按本关的僵尸阵容加载。
+    ///
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境，用于加载资源等。
@@ -83,7 +149,23 @@ impl GameState {
     ///
     /// 返回一个 `GameResult`，其中包含初始化完成的 `GameState` 实例或者一个错误。
     pub fn new(ctx: &mut Context) -> GameResult<GameState> {
-        let resources = Resources::new(ctx)?;
+        if let Err(e) = tuning::load(Path::new(TUNING_DIR)) {
+            println!("Warning: {}, using default tuning values", e);
+        }
+        if let Err(e) = campaign::load(Path::new(TUNING_DIR)) {
+            println!("Warning: {}, using built-in levels", e);
+        }
+        let mut resources = Resources::empty(ctx)?;
+        let level_sprites = zombies::sprite_keys(&crate::core::registry::zombie_types());
+        let startup_sprites: Vec<String> = resources
+            .sprite_names()
+            .into_iter()
+            .filter(|name| !level_sprites.iter().any(|key| key == name))
+            .collect();
+        resources.request(startup_sprites);
+        let profile_dir = ggez::filesystem::user_data_dir(ctx).to_path_buf();
+        let profile = Profile::load(&profile_dir);
+        let leaderboard = Leaderboard::load(&profile_dir);
         let grid = Grid::new();
         let shop = Shop::new();
         let shovel = Shovel::new();
@@ -92,11 +174,12 @@ impl GameState {
         Ok(GameState {
             resources,
             grid,
+            fog: Fog::none(),
             plants: Vec::new(),
             zombies: Vec::new(),
             suns: Vec::new(),
-            peas: Vec::new(),
-            sun_count: 50,
+            projectiles: Vec::new(),
+            sun_count: tuning::current().initial_sun,
             selected_plant: None,
             game_over: false,
             victory: false,
@@ -105,12 +188,243 @@ impl GameState {
             shop,
             shovel,
             entity_manager,
-            game_state: crate::core::states::GameState::Paused,
+            game_state: crate::core::states::GameState::Loading,
+            state_after_loading: crate::core::states::GameState::LevelSelect,
             pause_button_rect: (950.0, 10.0, 80.0, 40.0), // x, y, width, height
             pause_start_time: None,
             is_initial_pause: true,
+            restart_button_rect: (570.0, 400.0, 120.0, 45.0), // x, y, width, height
+            level_select_button_rect: (710.0, 400.0, 120.0, 45.0), // x, y, width, height
+            profile,
+            profile_dir,
+            current_level: campaign::levels().remove(0),
+            reward_packet: None,
+            seed_chooser: None,
+            chosen_seeds: Vec::new(),
+            endless: false,
+            endless_stats: EndlessStats::default(),
+            leaderboard,
+            watcher: if cfg!(debug_assertions) { Some(FileWatcher::new(Path::new(RESOURCE_DIR))) } else { None },
+            render_cache: RenderCache::new(),
         })
     }
+
+    /// 获取当前关卡的定义。
+    fn current_level_definition(&self) -> &LevelDefinition {
+        &self.current_level
+    }
+
+    /// 开始冒险模式中的第 `index` 关。
+    ///
+    /// 首先进入选卡阶段，上一次选择且仍可用的卡片会被预先放入卡槽。
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 关卡在当前关卡列表中的索引，超出范围时开始最后一关。
+    pub fn start_level(&mut self, index: usize) {
+        if let Some(level) = campaign::level(index.min(campaign::level_count() - 1)) {
+            self.current_level = level;
+        }
+        self.endless = false;
+        self.open_seed_chooser();
+        self.load_level_sprites();
+    }
+
+    /// 开始无尽模式。与冒险模式一样，首先进入选卡阶段。
+    pub fn start_endless(&mut self) {
+        self.endless = true;
+        self.open_seed_chooser();
+        self.load_level_sprites();
+    }
+
+    /// 加载本关僵尸阵容用到的贴图。还有贴图没有加载时先显示加载画面，加载完成后回到当前阶段。
+    fn load_level_sprites(&mut self) {
+        self.resources.request(zombies::sprite_keys(&self.current_zombie_types()));
+        if !self.resources.is_ready() {
+            self.state_after_loading = self.game_state;
+            self.game_state = crate::core::states::GameState::Loading;
+        }
+    }
+
+    /// 获取当前关卡（或无尽模式）中会出现的僵尸类型，用于在选卡界面展示僵尸阵容。
+    fn current_zombie_types(&self) -> Vec<ZombieType> {
+        if self.endless {
+            ENDLESS_ZOMBIES.to_vec()
+        } else {
+            self.current_level_definition().zombie_types()
+        }
+    }
+
+    /// 获取当前关卡（或无尽模式）所处的环境。无尽模式始终在白天进行。
+    fn current_environment(&self) -> Environment {
+        if self.endless {
+            Environment::Day
+        } else {
+            self.current_level_definition().environment
+        }
+    }
+
+    /// 获取当前关卡（或无尽模式）草坪的几何信息。无尽模式使用标准草坪。
+    fn current_geometry(&self) -> LawnGeometry {
+        if self.endless {
+            LawnGeometry::STANDARD
+        } else {
+            self.current_level_definition().layout.geometry
+        }
+    }
+
+    /// 打开选卡界面，上一次选择且仍可用的卡片会被预先放入卡槽。
+    fn open_seed_chooser(&mut self) {
+        let mut chooser = SeedChooser::new(&self.profile.unlocked_plants, self.profile.seed_slots);
+        for &plant_type in &self.chosen_seeds {
+            chooser.pick(plant_type);
+        }
+        self.seed_chooser = Some(chooser);
+        self.game_state = crate::core::states::GameState::SeedSelect;
+    }
+
+    /// 结束无尽模式的休整期：使用重新选择的卡片替换商店，场上的植物和阳光保持不变。
+    fn resume_endless(&mut self) {
+        self.shop = Shop::with_plants(&self.chosen_seeds);
+        self.selected_plant = None;
+        self.shovel.reset();
+        self.entity_manager.level_controller.resume();
+        self.game_state = crate::core::states::GameState::InGame;
+    }
+
+    /// 处理无尽模式失败：把本局成绩记录到排行榜并保存。
+    fn on_endless_game_over(&mut self) {
+        if let Some(rank) = self.leaderboard.record_stats(self.endless_stats) {
+            println!("无尽模式成绩进入排行榜第 {} 名", rank + 1);
+        }
+
+        if let Err(e) = self.leaderboard.save(&self.profile_dir) {
+            println!("Warning: Failed to save leaderboard: {}", e);
+        }
+    }
+
+    /// 处理关卡胜利：记录通关并保存存档，首次通关时掉落奖励卡片。
+    fn on_level_victory(&mut self) {
+        let new_plant = self.profile.complete_level(&self.current_level);
+
+        if let Err(e) = self.profile.save(&self.profile_dir) {
+            println!("Warning: Failed to save profile: {}", e);
+        }
+
+        if let Some(plant_type) = new_plant {
+            self.reward_packet = Some(SeedPacketReward::new(plant_type, 675.0, 150.0));
+        }
+    }
+
+    /// 返回选关界面。
+    fn return_to_level_select(&mut self) {
+        self.reward_packet = None;
+        self.selected_plant = None;
+        self.shovel.reset();
+        self.game_state = crate::core::states::GameState::LevelSelect;
+    }
+
+    /// 重新开始当前关卡。
+    ///
+    /// 按关卡的草坪布局重建网格（夜间关卡会重新随机放置墓碑）、商店（包括卡片冷却）、铲子、`EntityManager` 以及其内部的 `LevelController`，
+    /// 清空场上所有实体并恢复关卡的初始阳光。已加载的 `Resources` 会被保留，无需重新读取图片。
+    /// 商店中的卡片来自选卡阶段选择的植物。重置后游戏直接进入运行状态。
+    /// 无尽模式下会重新开始一局并清空本局统计数据。
+    pub fn reset_level(&mut self) {
+        let level = self.current_level_definition();
+        let (initial_sun, entity_manager, grid, fog) = if self.endless {
+            (tuning::current().initial_sun, EntityManager::endless(), Grid::new(), Fog::none())
+        } else {
+            let grid = Grid::with_graves(level.layout.clone(), level.graves);
+            (level.initial_sun, EntityManager::for_level(level), grid, Fog::new(level.fog_columns))
+        };
+
+        self.grid = grid;
+        self.fog = fog;
+        self.plants.clear();
+        self.zombies.clear();
+        self.suns.clear();
+        self.projectiles.clear();
+
+        self.sun_count = initial_sun;
+        self.selected_plant = None;
+        self.game_over = false;
+        self.victory = false;
+        self.show_final_wave = false;
+        self.final_wave_message_time = None;
+
+        self.shop = Shop::with_plants(&self.chosen_seeds);
+        self.shovel = Shovel::new();
+        self.entity_manager = entity_manager;
+        self.reward_packet = None;
+        self.endless_stats = EndlessStats::default();
+
+        self.game_state = crate::core::states::GameState::InGame;
+        self.pause_start_time = None;
+        self.is_initial_pause = false;
+    }
+
+    /// 重新读取调校文件，成功后刷新场上植物、僵尸和商店卡片缓存的规格。
+    ///
+    /// 文件有误时打印警告并保留当前的数值。
+    fn reload_tuning(&mut self) {
+        match tuning::load(Path::new(TUNING_DIR)) {
+            Ok(()) => {
+                for plant in &mut self.plants {
+                    plant.refresh_spec();
+                }
+                for zombie in &mut self.zombies {
+                    zombie.refresh_spec();
+                }
+                self.shop.refresh_specs();
+                println!("Reloaded {}", tuning::TUNING_FILE_NAME);
+            }
+            Err(e) => println!("Warning: {}, keeping current tuning values", e),
+        }
+    }
+
+    /// 开发模式下处理资源目录中改动的文件：重新读取调校文件、关卡文件、资源清单，或重新加载用到该图像的贴图。
+    ///
+    /// 文件有误时打印警告并保留当前的数值、关卡和贴图。新的关卡列表在下一次从选关界面开始关卡时生效。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境，用于加载图像。
+    /// * `path` - 改动的文件，相对资源目录。
+    fn reload_changed_file(&mut self, ctx: &mut Context, path: &Path) {
+        if path == Path::new(tuning::TUNING_FILE_NAME) {
+            self.reload_tuning();
+        } else if path == Path::new(campaign::LEVELS_FILE_NAME) {
+            match campaign::load(Path::new(TUNING_DIR)) {
+                Ok(()) => println!("Reloaded {}", campaign::LEVELS_FILE_NAME),
+                Err(e) => println!("Warning: {}, keeping current levels", e),
+            }
+        } else if path == Path::new(MANIFEST_FILE_NAME) {
+            match self.resources.reload_manifest(ctx) {
+                Ok(names) => println!("Reloaded {} ({} sprite(s) changed)", MANIFEST_FILE_NAME, names.len()),
+                Err(e) => println!("Warning: {}, keeping current sprites", e),
+            }
+        } else {
+            let resource_path = format!("/{}", path.to_string_lossy().replace('\\', "/"));
+            match self.resources.reload_file(ctx, &resource_path) {
+                Ok(names) => {
+                    for name in names {
+                        println!("Reloaded sprite '{}' ({})", name, resource_path);
+                    }
+                }
+                Err(e) => println!("Warning: Failed to reload {}: {}", resource_path, e),
+            }
+        }
+    }
+
+    /// 判断当前是否应该显示"重新开始"按钮。
+    ///
+    /// 在游戏失败、胜利或玩家手动暂停（非初始暂停）时显示。
+    fn can_restart(&self) -> bool {
+        self.game_over
+            || self.victory
+            || (self.game_state == crate::core::states::GameState::Paused && !self.is_initial_pause)
+    }
 }
 
 impl EventHandler for GameState {
@@ -181,10 +495,48 @@ impl EventHandler for GameState {
         // (1000ms / FPS) gives ms per frame.
         const FIXED_UPDATE_DT_MS: u64 = (1000.0_f32 / DESIRED_FPS as f32) as u64;
 
+        // 加载画面：每帧加载一部分贴图，加载期间不推进游戏逻辑
+        if self.game_state == crate::core::states::GameState::Loading {
+            self.resources.load_pending(ctx, LOADING_FRAME_BUDGET)?;
+            if self.resources.is_ready() {
+                self.game_state = self.state_after_loading;
+            }
+            while ggez::timer::check_update_time(ctx, DESIRED_FPS) {}
+            return Ok(());
+        }
+
+        // 开发模式：重新加载改动过的贴图、资源清单和调校文件
+        if let Some(watcher) = &mut self.watcher {
+            let changed = watcher.update(ggez::timer::delta(ctx).as_millis() as u64);
+            for path in changed {
+                self.reload_changed_file(ctx, &path);
+            }
+        }
+
         // ggez::timer::check_update_time 会根据自上次 EventHandler::update 调用以来的时间
         // 来决定逻辑更新循环（while 循环体）需要执行多少次，以达到 DESIRED_FPS。
         while ggez::timer::check_update_time(ctx, DESIRED_FPS) {
-            if self.game_over || self.game_state == crate::core::states::GameState::Paused {
+            // 奖励卡片在胜利画面中也需要继续播放下落动画
+            if let Some(packet) = &mut self.reward_packet {
+                packet.update(FIXED_UPDATE_DT_MS);
+            }
+
+            // 选卡阶段播放草坪滚动动画
+            if let Some(chooser) = &mut self.seed_chooser {
+                chooser.update(FIXED_UPDATE_DT_MS);
+            }
+
+            // 无尽模式的休整期超时后，使用已经选好的卡片自动继续（没有选卡时沿用上一次的卡片）
+            if self.endless && self.entity_manager.level_controller.break_remaining() == Some(Duration::ZERO) {
+                if let Some(chooser) = self.seed_chooser.take() {
+                    if chooser.is_ready() {
+                        self.chosen_seeds = chooser.chosen;
+                    }
+                }
+                self.resume_endless();
+            }
+
+            if self.game_over || self.victory || self.game_state != crate::core::states::GameState::InGame {
                 continue;
             }
             
@@ -193,26 +545,61 @@ impl EventHandler for GameState {
                 sun.update(FIXED_UPDATE_DT_MS);
             }
 
-            // 更新植物并收集新产生的阳光
-            let mut new_suns = Vec::new();
+            // 更新植物，收集它们对世界的效果，再统一执行
+            let mut world_effects = Vec::new();
             for plant in &mut self.plants {
-                plant.update(FIXED_UPDATE_DT_MS, &mut new_suns, &mut self.peas, &self.zombies); // 传递 self.zombies
+                plant.update(FIXED_UPDATE_DT_MS, &self.zombies, &mut world_effects);
+            }
+            let mut plant_events = Vec::new();
+            let mut world = WorldState {
+                zombies: &mut self.zombies,
+                plants: &mut self.plants,
+                projectiles: &mut self.projectiles,
+                suns: &mut self.suns,
+                events: &mut plant_events,
+            };
+            for effect in world_effects {
+                if matches!(effect, WorldEffect::SpawnSun(_)) {
+                    self.endless_stats.sun_produced += SUN_VALUE as u32;
+                }
+                self.endless_stats.zombies_killed += effect.apply(&mut world) as u32;
             }
-            self.suns.append(&mut new_suns);
 
-            // 更新僵尸
+            // 处理植物发出的事件：三叶草吹散迷雾
+            self.fog.update(FIXED_UPDATE_DT_MS);
+            for event in plant_events {
+                match event {
+                    PlantEvent::BlowFog => self.fog.blow_away(),
+                }
+            }
+
+            // 清理被移除或被效果杀死的植物
+            for plant in self.plants.iter().filter(|plant| plant.is_dead) {
+                self.grid.remove(plant.grid_x, plant.grid_y, plant.get_plant_type());
+            }
+            self.plants.retain(|plant| !plant.is_dead);
+
+            // 更新僵尸，会投掷的僵尸向植物投出敌方投掷物
             for zombie in &mut self.zombies {
                 zombie.update(FIXED_UPDATE_DT_MS);
+                if let Some(lob) = zombie.throw_at_plants(&self.plants, FIXED_UPDATE_DT_MS) {
+                    self.projectiles.push(lob);
+                }
             }
 
-            // 更新豌豆
-            for pea in &mut self.peas {
-                pea.update(FIXED_UPDATE_DT_MS);
+            // 更新投射物
+            for projectile in &mut self.projectiles {
+                projectile.update(FIXED_UPDATE_DT_MS);
             }
 
             // 处理碰撞逻辑
-            CollisionManager::handle_pea_zombie_collision(&mut self.peas, &mut self.zombies);
-            CollisionManager::handle_zombie_plant_interaction(&mut self.zombies, &mut self.plants, ctx);
+            let geometry = self.grid.geometry();
+            CollisionManager::handle_projectile_plant_interaction(&mut self.projectiles, &self.plants);
+            let kills = CollisionManager::handle_projectile_collision(&mut self.projectiles, &mut self.zombies, &mut self.plants, &geometry);
+            self.endless_stats.zombies_killed += kills as u32;
+            let dt = ggez::timer::delta(ctx).as_millis() as u64;
+            let drowned = CollisionManager::handle_zombie_plant_interaction(&mut self.zombies, &mut self.plants, &mut self.grid, dt);
+            self.endless_stats.zombies_killed += drowned as u32;
 
             // 检查游戏是否结束
             for zombie in &self.zombies {
@@ -222,12 +609,18 @@ impl EventHandler for GameState {
                 }
             }
 
-            if self.game_over { continue; }
+            if self.game_over {
+                if self.endless {
+                    self.on_endless_game_over();
+                }
+                continue;
+            }
 
             // 基于时间生成自然阳光
             if self.entity_manager.check_natural_sun_spawn(FIXED_UPDATE_DT_MS) {
                 let new_sun = self.entity_manager.spawn_natural_sun();
                 self.suns.push(new_sun);
+                self.endless_stats.sun_produced += SUN_VALUE as u32;
             }
 
             // 通过关卡控制器更新并生成僵尸，传递当前场上的僵尸信息
@@ -236,11 +629,24 @@ impl EventHandler for GameState {
                 let zombie = self.entity_manager.spawn_zombie(spawn_info.zombie_type, spawn_info.row);
                 self.zombies.push(zombie);
             }
+
+            // 无尽模式：记录坚持的旗帜数，进入休整期时打开选卡界面，场上的植物保持不变
+            if self.endless {
+                self.endless_stats.flags_survived = self.entity_manager.level_controller.flags_completed();
+                if self.entity_manager.level_controller.is_on_break() {
+                    self.open_seed_chooser();
+                    continue;
+                }
+            }
             
             // 检查是否应该显示最后一波信息
             if self.entity_manager.level_controller.is_final_wave_announced() {
                 self.show_final_wave = true;
                 self.final_wave_message_time = Some(Instant::now());
+
+                // 最后一波时，僵尸从每个墓碑中爬出
+                let mut grave_zombies = self.entity_manager.spawn_grave_zombies(&self.grid.graves());
+                self.zombies.append(&mut grave_zombies);
             }
             
             // 如果已经显示"最后一波来袭"信息5秒钟，则隐藏它
@@ -253,6 +659,7 @@ impl EventHandler for GameState {
             // 检查关卡是否胜利完成
             if self.entity_manager.level_controller.is_level_completed(&self.zombies) {
                 self.victory = true;
+                self.on_level_victory();
             }
             
             // 更新商店（只有在游戏未暂停时才更新）
@@ -277,24 +684,47 @@ impl EventHandler for GameState {
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
-        Renderer::draw_game(
-            ctx,
-            &self.resources,
-            &self.grid,
-            &self.plants,
-            &self.peas,
-            &self.zombies,
-            &self.suns,
-            &self.shop,
-            self.sun_count,
-            self.game_over,
-            self.victory,
-            self.show_final_wave,
-            self.game_state,
-            self.pause_button_rect,
-            &self.shovel,
-            self.is_initial_pause
-        )
+        if self.game_state == crate::core::states::GameState::Loading {
+            return Renderer::draw_loading(ctx, self.resources.progress());
+        }
+
+        if self.game_state == crate::core::states::GameState::LevelSelect {
+            return Renderer::draw_level_select(ctx, &self.resources, &self.profile);
+        }
+
+        if let Some(chooser) = &self.seed_chooser {
+            let geometry = self.current_geometry();
+            return Renderer::draw_seed_select(ctx, &self.resources, chooser, &self.current_zombie_types(), self.current_environment(), &geometry);
+        }
+
+        let menu_buttons = if self.can_restart() {
+            vec![("Restart", self.restart_button_rect), ("Levels", self.level_select_button_rect)]
+        } else {
+            Vec::new()
+        };
+
+        let view = GameView {
+            environment: self.current_environment(),
+            grid: &self.grid,
+            fog: &self.fog,
+            plants: &self.plants,
+            projectiles: &self.projectiles,
+            zombies: &self.zombies,
+            suns: &self.suns,
+            shop: &self.shop,
+            sun_count: self.sun_count,
+            game_over: self.game_over,
+            victory: self.victory,
+            show_final_wave: self.show_final_wave,
+            game_state: self.game_state,
+            pause_button_rect: self.pause_button_rect,
+            shovel: &self.shovel,
+            is_initial_pause: self.is_initial_pause,
+            menu_buttons: &menu_buttons,
+            reward_packet: self.reward_packet.as_ref(),
+            endless: if self.endless { Some((&self.endless_stats, &self.leaderboard)) } else { None },
+        };
+        Renderer::draw_game(ctx, &self.resources, &mut self.render_cache, &view)
     }
 
     /// 处理鼠标按键按下事件。
@@ -309,11 +739,62 @@ impl EventHandler for GameState {
     /// * `x` - 鼠标点击位置的x坐标。
     /// * `y` - 鼠标点击位置的y坐标。
     fn mouse_button_down_event(&mut self, _ctx: &mut Context, button: MouseButton, x: f32, y: f32) {
+        // 加载画面中不处理点击
+        if self.game_state == crate::core::states::GameState::Loading {
+            return;
+        }
+
+        // 选关界面中只处理关卡按钮
+        if self.game_state == crate::core::states::GameState::LevelSelect {
+            if button == MouseButton::Left {
+                match LevelSelect::handle_click(x, y, &self.profile) {
+                    Some(LevelChoice::Adventure(index)) => self.start_level(index),
+                    Some(LevelChoice::Endless) => self.start_endless(),
+                    None => {}
+                }
+            }
+            return;
+        }
+
+        // 选卡阶段只处理选卡界面的点击，确认后正式开始关卡（或结束无尽模式的休整期）
+        if let Some(chooser) = &mut self.seed_chooser {
+            if button == MouseButton::Left && chooser.handle_click(x, y) {
+                self.chosen_seeds = chooser.chosen.clone();
+                self.seed_chooser = None;
+                if self.endless && self.entity_manager.level_controller.is_on_break() {
+                    self.resume_endless();
+                } else {
+                    self.reset_level();
+                }
+            }
+            return;
+        }
+
+        // 点击奖励卡片后领取新植物并返回选关界面
+        if button == MouseButton::Left {
+            if let Some(packet) = &mut self.reward_packet {
+                if !packet.collected && packet.contains_point(x, y) {
+                    packet.collected = true;
+                    self.return_to_level_select();
+                    return;
+                }
+            }
+        }
+
+        // 检查是否点击了"重新开始"或"返回选关"按钮（失败、胜利或暂停画面中）
+        if button == MouseButton::Left && self.can_restart() {
+            if button_contains(self.restart_button_rect, x, y) {
+                self.reset_level();
+                return;
+            }
+            if button_contains(self.level_select_button_rect, x, y) {
+                self.return_to_level_select();
+                return;
+            }
+        }
+
         // 检查是否点击了暂停按钮
-        let (btn_x, btn_y, btn_w, btn_h) = self.pause_button_rect;
-        if button == MouseButton::Left 
-           && x >= btn_x && x <= btn_x + btn_w 
-           && y >= btn_y && y <= btn_y + btn_h {
+        if button == MouseButton::Left && button_contains(self.pause_button_rect, x, y) {
             // 切换游戏状态
             match self.game_state {
                 crate::core::states::GameState::Paused => {
@@ -377,4 +858,24 @@ impl EventHandler for GameState {
             &mut self.shovel
         );
     }
+
+    /// 处理键盘按下事件。
+    ///
+    /// 与 ggez 的默认行为一样，按下 Esc 退出游戏。
+    /// 调试构建中按下 F5 会重新读取调校文件 `tuning.toml`；文件有误时保留当前数值并打印错误。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境，用于退出游戏。
+    /// * `keycode` - 被按下的按键。
+    /// * `_keymods` - 同时按下的修饰键 (在此方法中未使用)。
+    /// * `repeat` - 是否为按住按键产生的重复事件。
+    fn key_down_event(&mut self, ctx: &mut Context, keycode: KeyCode, _keymods: KeyMods, repeat: bool) {
+        if keycode == KeyCode::Escape {
+            ggez::event::quit(ctx);
+        }
+        if cfg!(debug_assertions) && keycode == RELOAD_TUNING_KEY && !repeat {
+            self.reload_tuning();
+        }
+    }
 }
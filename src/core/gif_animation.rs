@@ -0,0 +1,110 @@
+//! # GIF 动画解码模块 (`gif_animation`)
+//!
+//! 游戏的很多素材只有 GIF 动画（例如 `SnowPea.gif`、`BucketZombieWalk.gif`、`Boom.gif`）。
+//! 本模块把 GIF 文件解码成完整的 RGBA 帧序列和每帧的延迟，`Resources` 再把这些帧上传为图像，
+//! 这样无需手工拆帧就能直接使用现有素材。
+//!
+//! GIF 的每一帧可能只覆盖画布的一部分，并带有处置方式（保留、清除为背景、恢复为上一帧），
+//! 解码时会按处置方式合成，输出的每一帧都是整张画布。解码只需要一个 `Read`，不需要 ggez 的上下文。
+
+use ggez::{GameError, GameResult};
+use gif::{ColorOutput, DecodeOptions, DisposalMethod};
+use std::io::Read;
+
+/// GIF 中延迟过短（不超过 10 毫秒）的帧按此延迟播放，与常见浏览器的处理一致。
+pub const DEFAULT_FRAME_DELAY_MS: u64 = 100;
+
+/// 解码后的一帧：整张画布的像素和该帧的显示时长。
+#[derive(Debug, Clone, PartialEq)]
+pub struct GifFrame {
+    /// 画布宽度。
+    pub width: u16,
+    /// 画布高度。
+    pub height: u16,
+    /// 按行排列的 RGBA 像素数据。
+    pub rgba: Vec<u8>,
+    /// 该帧的显示时长（毫秒）。
+    pub delay_ms: u64,
+}
+
+/// 把 GIF 中以厘秒为单位的延迟换算成毫秒，过短的延迟使用 `DEFAULT_FRAME_DELAY_MS`。
+fn frame_delay_ms(delay_cs: u16) -> u64 {
+    if delay_cs <= 1 {
+        DEFAULT_FRAME_DELAY_MS
+    } else {
+        delay_cs as u64 * 10
+    }
+}
+
+/// 解码一个 GIF 动画。
+///
+/// # Arguments
+///
+/// * `reader` - GIF 文件的内容。
+/// * `name` - 文件名称，用于错误信息。
+///
+/// # Returns
+///
+/// 成功时返回按顺序排列的全部帧；文件不是合法的 GIF 或不包含任何帧时返回 `GameError::ResourceLoadError`。
+pub fn decode_gif<R: Read>(reader: R, name: &str) -> GameResult<Vec<GifFrame>> {
+    let error = |e: gif::DecodingError| GameError::ResourceLoadError(format!("failed to decode {}: {}", name, e));
+
+    let mut options = DecodeOptions::new();
+    options.set_color_output(ColorOutput::RGBA);
+    let mut decoder = options.read_info(reader).map_err(error)?;
+
+    let (width, height) = (decoder.width(), decoder.height());
+    let canvas_width = width as usize;
+    let mut canvas = vec![0u8; canvas_width * height as usize * 4];
+    let mut frames = Vec::new();
+
+    while let Some(frame) = decoder.read_next_frame().map_err(error)? {
+        let saved = match frame.dispose {
+            DisposalMethod::Previous => Some(canvas.clone()),
+            _ => None,
+        };
+
+        // 把这一帧覆盖的区域画到画布上，完全透明的像素保留画布原有内容
+        let (left, top) = (frame.left as usize, frame.top as usize);
+        let frame_width = frame.width as usize;
+        for y in 0..frame.height as usize {
+            for x in 0..frame_width {
+                let (cx, cy) = (left + x, top + y);
+                if cx >= canvas_width || cy >= height as usize {
+                    continue;
+                }
+                let src = (y * frame_width + x) * 4;
+                let pixel = &frame.buffer[src..src + 4];
+                if pixel[3] != 0 {
+                    let dst = (cy * canvas_width + cx) * 4;
+                    canvas[dst..dst + 4].copy_from_slice(pixel);
+                }
+            }
+        }
+
+        frames.push(GifFrame { width, height, rgba: canvas.clone(), delay_ms: frame_delay_ms(frame.delay) });
+
+        // 按处置方式为下一帧准备画布
+        match frame.dispose {
+            DisposalMethod::Background => {
+                for y in top..(top + frame.height as usize).min(height as usize) {
+                    for x in left..(left + frame_width).min(canvas_width) {
+                        let dst = (y * canvas_width + x) * 4;
+                        canvas[dst..dst + 4].fill(0);
+                    }
+                }
+            }
+            DisposalMethod::Previous => {
+                if let Some(saved) = saved {
+                    canvas = saved;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if frames.is_empty() {
+        return Err(GameError::ResourceLoadError(format!("{} contains no frames", name)));
+    }
+    Ok(frames)
+}
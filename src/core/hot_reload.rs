@@ -0,0 +1,95 @@
+//! # 热重载模块 (`hot_reload`)
+//!
+//! 开发模式（调试构建）下，`FileWatcher` 定期检查资源目录中所有文件的修改时间，
+//! 找出新增或修改过的文件。游戏据此重新加载改动的贴图、资源清单 `assets.toml`、调校文件 `tuning.toml`
+//! 或关卡文件 `levels.toml`，美术和策划修改文件后无需重启游戏即可看到效果。
+//!
+//! 检查通过轮询完成，不依赖操作系统的文件通知。
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 两次检查文件之间的间隔（毫秒）。
+pub const POLL_INTERVAL_MS: u64 = 500;
+
+/// 监视一个目录下的所有文件，报告新增或修改过的文件。
+pub struct FileWatcher {
+    /// 被监视的目录。
+    root: PathBuf,
+    /// 每个文件上一次检查时的修改时间。
+    stamps: HashMap<PathBuf, SystemTime>,
+    /// 距离上一次检查经过的时间（毫秒）。
+    since_poll: u64,
+}
+
+/// 递归列出目录下所有文件的修改时间，无法读取的目录和文件会被跳过。
+fn scan(dir: &Path, stamps: &mut HashMap<PathBuf, SystemTime>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => scan(&path, stamps),
+            Ok(metadata) => {
+                if let Ok(modified) = metadata.modified() {
+                    stamps.insert(path, modified);
+                }
+            }
+            Err(_) => {}
+        }
+    }
+}
+
+impl FileWatcher {
+    /// 开始监视目录，当前已有的文件不会被报告为改动。
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - 要监视的目录（通常是资源目录 `Resource`）。
+    pub fn new(root: &Path) -> FileWatcher {
+        let mut stamps = HashMap::new();
+        scan(root, &mut stamps);
+        FileWatcher { root: root.to_path_buf(), stamps, since_poll: 0 }
+    }
+
+    /// 立即检查一次目录。
+    ///
+    /// # Returns
+    ///
+    /// 返回自上一次检查以来新增或修改过的文件，路径相对于被监视的目录，按路径排序。
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut stamps = HashMap::new();
+        scan(&self.root, &mut stamps);
+
+        let mut changed: Vec<PathBuf> = stamps
+            .iter()
+            .filter(|(path, modified)| self.stamps.get(*path) != Some(*modified))
+            .filter_map(|(path, _)| path.strip_prefix(&self.root).ok().map(Path::to_path_buf))
+            .collect();
+        changed.sort();
+        self.stamps = stamps;
+        changed
+    }
+
+    /// 推进计时，每隔 `POLL_INTERVAL_MS` 检查一次目录。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 距离上一次调用经过的时间（毫秒）。
+    ///
+    /// # Returns
+    ///
+    /// 本次检查发现的改动文件；还没到检查时间时返回空列表。
+    pub fn update(&mut self, dt: u64) -> Vec<PathBuf> {
+        self.since_poll += dt;
+        if self.since_poll < POLL_INTERVAL_MS {
+            return Vec::new();
+        }
+        self.since_poll = 0;
+        self.poll()
+    }
+}
@@ -0,0 +1,160 @@
+//! # 无尽模式排行榜模块 (`leaderboard`)
+//!
+//! 记录无尽模式中每一局的成绩（坚持的旗帜数、击杀的僵尸数和产出的阳光），
+//! 并保留成绩最好的前 `LEADERBOARD_SIZE` 名。
+//! 排行榜以 TOML 格式保存在用户数据目录下的 `leaderboard.toml` 文件中。
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 排行榜文件的文件名。
+pub const LEADERBOARD_FILE_NAME: &str = "leaderboard.toml";
+/// 排行榜保留的最多成绩数量。
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// 无尽模式中一局游戏的统计数据。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EndlessStats {
+    /// 坚持过的旗帜数量。
+    pub flags_survived: usize,
+    /// 击杀的僵尸数量。
+    pub zombies_killed: u32,
+    /// 产出的阳光总量（包括自然掉落和植物生产）。
+    pub sun_produced: u32,
+}
+
+/// 排行榜中的一条成绩。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// 坚持过的旗帜数量。
+    pub flags_survived: usize,
+    /// 击杀的僵尸数量。
+    pub zombies_killed: u32,
+    /// 产出的阳光总量。
+    pub sun_produced: u32,
+    /// 取得成绩的时间（Unix 时间戳，秒）。
+    pub timestamp: u64,
+}
+
+impl LeaderboardEntry {
+    /// 根据一局的统计数据创建一条成绩。
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - 本局的统计数据。
+    /// * `timestamp` - 取得成绩的时间（Unix 时间戳，秒）。
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 `LeaderboardEntry`。
+    pub fn new(stats: EndlessStats, timestamp: u64) -> Self {
+        LeaderboardEntry {
+            flags_survived: stats.flags_survived,
+            zombies_killed: stats.zombies_killed,
+            sun_produced: stats.sun_produced,
+            timestamp,
+        }
+    }
+
+    /// 用于排序的键：先比较旗帜数，再比较击杀数，最后比较阳光产出。
+    fn rank_key(&self) -> (usize, u32, u32) {
+        (self.flags_survived, self.zombies_killed, self.sun_produced)
+    }
+}
+
+/// 无尽模式的本地排行榜，成绩按从好到差排列。
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Leaderboard {
+    /// 排行榜中的成绩，最多 `LEADERBOARD_SIZE` 条。
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// 从指定目录中读取排行榜。
+    ///
+    /// 如果文件不存在或无法解析，则返回一个空的排行榜。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - 存放排行榜文件的目录（通常是用户数据目录）。
+    ///
+    /// # Returns
+    ///
+    /// 返回读取到的 `Leaderboard`。
+    pub fn load(dir: &Path) -> Leaderboard {
+        let path = dir.join(LEADERBOARD_FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(content) => match toml::from_str(&content) {
+                Ok(leaderboard) => leaderboard,
+                Err(e) => {
+                    println!("Warning: Failed to parse leaderboard {}: {}", path.display(), e);
+                    Leaderboard::default()
+                }
+            },
+            Err(_) => Leaderboard::default(),
+        }
+    }
+
+    /// 将排行榜写入指定目录，目录不存在时会自动创建。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - 存放排行榜文件的目录。
+    ///
+    /// # Returns
+    ///
+    /// 写入成功返回 `Ok(())`，否则返回对应的 I/O 错误。
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(LEADERBOARD_FILE_NAME), content)
+    }
+
+    /// 记录一条新成绩，并只保留最好的 `LEADERBOARD_SIZE` 条。
+    ///
+    /// 成绩相同时，较早取得的成绩排在前面。
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - 新的成绩。
+    ///
+    /// # Returns
+    ///
+    /// 如果新成绩进入了排行榜，返回它的名次（从0开始），否则返回 `None`。
+    pub fn record(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let rank = self
+            .entries
+            .iter()
+            .position(|existing| entry.rank_key() > existing.rank_key())
+            .unwrap_or(self.entries.len());
+
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(LEADERBOARD_SIZE);
+        Some(rank)
+    }
+
+    /// 以当前时间记录一局无尽模式的统计数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - 本局的统计数据。
+    ///
+    /// # Returns
+    ///
+    /// 如果成绩进入了排行榜，返回它的名次（从0开始），否则返回 `None`。
+    pub fn record_stats(&mut self, stats: EndlessStats) -> Option<usize> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+        self.record(LeaderboardEntry::new(stats, timestamp))
+    }
+}
@@ -5,7 +5,16 @@
 //!
 //! - **游戏主循环与逻辑 (`game`)**: 包含游戏的核心状态 (`GameState` 结构体) 和主要的 `EventHandler` 实现，负责处理用户输入、更新游戏世界、执行游戏规则等。
 //! - **资源加载与管理 (`resources`)**: 定义 `Resources` 结构体，处理所有游戏资源（如图像、字体等）的加载和访问。
+//! - **资源清单 (`asset_manifest`)**: 解析并校验 `assets.toml`，按名称列出每个贴图的路径、帧号范围、帧率、绘制原点和缩放，`Resources` 据此加载贴图。
+//! - **GIF 动画解码 (`gif_animation`)**: 把 GIF 素材解码成完整的帧序列和每帧的延迟，无需手工拆帧。
+//! - **动画片段与播放器 (`animation`)**: 按名称定义动画片段（每帧时长、循环 / 停在最后一帧 / 往返播放、帧事件），实体通过 `Animator` 按状态切换片段。
+//! - **热重载 (`hot_reload`)**: 开发模式下监视资源目录，改动的贴图、资源清单和调校文件无需重启即可重新加载。
 //! - **渲染逻辑 (`renderer`)**: 定义 `Renderer` 结构体，负责将游戏世界中的所有元素绘制到屏幕上。
+//! - **渲染缓存 (`render_cache`)**: 按贴图帧合并实体绘制的精灵批处理器和跨帧复用的文字缓存，场上有大量僵尸时保持帧率。
+//! - **玩家存档 (`profile`)**: 定义 `Profile` 结构体，记录并保存冒险模式的进度。
+//! - **无尽模式排行榜 (`leaderboard`)**: 定义 `Leaderboard` 结构体，记录并保存无尽模式的最佳成绩。
+//! - **内容注册表 (`registry`)**: 登记所有植物和僵尸的规格（花费、冷却、生命值、贴图键和构造函数），供商店、工厂和资源加载器读取，下游 crate 也可以注册新的内容。
+//! - **数值调校 (`tuning`)**: 从 `tuning.toml` 读取平衡数值（初始阳光、阳光掉落节奏、豌豆以及植物和僵尸属性的覆盖），带有编译时默认值和加载时校验，调试时可按 F5 重新读取。
 //! - **游戏阶段定义 (`states`)**: 定义 `GameState` 枚举，用于表示和切换不同的游戏阶段，如主菜单 (`Menu`)、游戏进行中 (`InGame`) 和游戏结束 (`GameOver`)。
 //!
 //! 各个子模块协同工作，构成了游戏运行的基础。
@@ -14,7 +23,25 @@
 pub mod game;
 /// 资源管理模块 (`resources::Resources`)，负责加载和存储所有游戏资源。
 pub mod resources;
+/// 资源清单模块 (`asset_manifest::AssetManifest`)，负责 `assets.toml` 的解析和校验。
+pub mod asset_manifest;
+/// GIF 动画解码模块 (`gif_animation::decode_gif`)，把 GIF 文件解码为帧序列和每帧的延迟。
+pub mod gif_animation;
+/// 动画模块 (`animation::Animator`)，定义动画片段和每个实体持有的动画播放器。
+pub mod animation;
+/// 热重载模块 (`hot_reload::FileWatcher`)，开发模式下监视资源目录中的文件改动。
+pub mod hot_reload;
 /// 渲染模块 (`renderer::Renderer`)，负责将游戏场景绘制到屏幕。
 pub mod renderer;
+/// 渲染缓存模块 (`render_cache::RenderCache`)，保存跨帧复用的精灵批次和文字。
+pub mod render_cache;
 /// 游戏阶段定义模块 (`states::GameState`)，定义了如 `Menu`, `InGame`, `GameOver` 等游戏阶段。
-pub mod states;
\ No newline at end of file
+pub mod states;
+/// 玩家存档模块 (`profile::Profile`)，负责冒险模式进度的读取和保存。
+pub mod profile;
+/// 无尽模式排行榜模块 (`leaderboard::Leaderboard`)，负责无尽模式成绩的记录和保存。
+pub mod leaderboard;
+/// 内容注册表模块 (`registry`)，登记所有植物和僵尸的规格。
+pub mod registry;
+/// 数值调校模块 (`tuning::Tuning`)，负责平衡数值的读取、校验和重新加载。
+pub mod tuning;
@@ -0,0 +1,141 @@
+//! # 玩家存档模块 (`profile`)
+//!
+//! `profile` 模块负责记录玩家在冒险模式中的进度，包括已解锁的植物和已完成的关卡。
+//! 存档以 TOML 格式保存在用户数据目录下的 `profile.toml` 文件中。
+
+use crate::mechanics::campaign::{self, LevelDefinition, LevelStatus};
+use crate::plants::PlantType;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 存档文件的文件名。
+pub const PROFILE_FILE_NAME: &str = "profile.toml";
+/// 新存档的卡槽数量，即选卡时可以携带的卡片数量。
+pub const DEFAULT_SEED_SLOTS: usize = 6;
+
+/// 玩家的冒险模式进度。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// 玩家已解锁、可以带入关卡的植物。
+    pub unlocked_plants: Vec<PlantType>,
+    /// 已经通关的关卡标识 (`LevelDefinition::id`)。
+    pub completed_levels: Vec<String>,
+    /// 选卡时可以携带的卡片数量。旧存档中没有此字段时使用默认值。
+    #[serde(default = "default_seed_slots")]
+    pub seed_slots: usize,
+}
+
+/// 存档中缺少 `seed_slots` 字段时使用的默认卡槽数量。
+fn default_seed_slots() -> usize {
+    DEFAULT_SEED_SLOTS
+}
+
+impl Default for Profile {
+    /// 新玩家只拥有豌豆射手，且没有完成任何关卡。
+    fn default() -> Self {
+        Profile {
+            unlocked_plants: vec![PlantType::Peashooter],
+            completed_levels: Vec::new(),
+            seed_slots: DEFAULT_SEED_SLOTS,
+        }
+    }
+}
+
+impl Profile {
+    /// 从指定目录中读取存档。
+    ///
+    /// 如果存档文件不存在或无法解析，则返回一个新的默认存档。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - 存放存档文件的目录（通常是用户数据目录）。
+    ///
+    /// # Returns
+    ///
+    /// 返回读取到的 `Profile`。
+    pub fn load(dir: &Path) -> Profile {
+        let path = dir.join(PROFILE_FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(content) => match toml::from_str(&content) {
+                Ok(profile) => profile,
+                Err(e) => {
+                    println!("Warning: Failed to parse profile {}: {}", path.display(), e);
+                    Profile::default()
+                }
+            },
+            Err(_) => Profile::default(),
+        }
+    }
+
+    /// 将存档写入指定目录，目录不存在时会自动创建。
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - 存放存档文件的目录。
+    ///
+    /// # Returns
+    ///
+    /// 写入成功返回 `Ok(())`，否则返回对应的 I/O 错误。
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::create_dir_all(dir)?;
+        fs::write(dir.join(PROFILE_FILE_NAME), content)
+    }
+
+    /// 判断指定关卡是否已经通关。
+    pub fn is_completed(&self, level: &LevelDefinition) -> bool {
+        self.completed_levels.contains(&level.id)
+    }
+
+    /// 获取冒险模式中第 `index` 关的状态。
+    ///
+    /// 第一关始终解锁，其余关卡在前一关通关后解锁。
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - 关卡在当前关卡列表中的索引。
+    ///
+    /// # Returns
+    ///
+    /// 返回该关卡的 `LevelStatus`。
+    pub fn level_status(&self, index: usize) -> LevelStatus {
+        let level = match campaign::level(index) {
+            Some(level) => level,
+            None => return LevelStatus::Locked,
+        };
+
+        if self.is_completed(&level) {
+            LevelStatus::Completed
+        } else if index == 0 || campaign::level(index - 1).is_some_and(|previous| self.is_completed(&previous)) {
+            LevelStatus::Unlocked
+        } else {
+            LevelStatus::Locked
+        }
+    }
+
+    /// 记录关卡通关，并在首次通关时解锁关卡奖励的植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - 刚刚通关的关卡。
+    ///
+    /// # Returns
+    ///
+    /// 如果本次通关解锁了新的植物，返回 `Some(PlantType)`，否则返回 `None`。
+    pub fn complete_level(&mut self, level: &LevelDefinition) -> Option<PlantType> {
+        if !self.is_completed(level) {
+            self.completed_levels.push(level.id.clone());
+        }
+
+        match level.reward {
+            Some(plant) if !self.unlocked_plants.contains(&plant) => {
+                self.unlocked_plants.push(plant);
+                Some(plant)
+            }
+            _ => None,
+        }
+    }
+}
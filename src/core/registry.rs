@@ -0,0 +1,538 @@
+//! # 内容注册表模块 (`registry`)
+//!
+//! 游戏中所有的植物和僵尸都在这里注册一份规格 (`PlantSpec` / `ZombieSpec`)：
+//! 标识、显示名称、阳光花费、卡片冷却、生命值、贴图键、植物所在的层和构造函数等静态属性。
+//! 商店、植物与僵尸工厂以及资源加载器都从注册表读取这些信息；查询规格只复制一份 `Copy` 的数据，
+//! 不需要创建植物或僵尸实例。内置内容的数值只在这里登记一次，调校文件 (`core::tuning`) 可以在运行时覆盖它们。
+//!
+//! 规格中只记录贴图键，不记录帧数：每个贴图的帧序列、帧时长和动画片段由资源清单
+//! (`Resource/assets.toml`) 决定，运行时通过 `Resources::frame_count` 等方法按贴图键查询。
+//!
+//! 内置的植物和僵尸在第一次访问注册表时自动注册。下游 crate 可以在创建游戏之前调用
+//! [`register_plant`] 和 [`register_zombie`] 添加新的内容，得到的 `PlantType::Custom` /
+//! `ZombieType::Custom` 可以像内置类型一样放进商店、关卡和存档中。
+//!
+//! 注册的顺序决定了自定义类型的编号，因此每次启动时应以相同的顺序注册。
+
+use crate::plants::plant_trait::PlantTrait;
+use crate::plants::{
+    blover, cabbage_pult, cactus, chomper, flower_pot, kernel_pult, lily_pad, magnet_shroom, peashooter, plantern,
+    pumpkin, snow_pea, starfruit, sunflower, tangle_kelp, torchwood, umbrella_leaf, wallnut, PlantLayer, PlantType,
+};
+use crate::zombies::zombie_trait::ZombieTrait;
+use crate::core::tuning;
+use crate::zombies::{catapult_zombie, conehead_zombie, normal_zombie, ZombieType};
+use std::sync::{LazyLock, RwLock};
+
+/// 一种植物的规格。
+#[derive(Clone, Copy)]
+pub struct PlantSpec {
+    /// 唯一标识，例如 `"peashooter"`。
+    pub id: &'static str,
+    /// 显示名称。
+    pub name: &'static str,
+    /// 种植所需的阳光花费。
+    pub cost: i32,
+    /// 商店卡片在种植后的冷却时间（毫秒）。
+    pub recharge_ms: u64,
+    /// 初始生命值。
+    pub health: i32,
+    /// 植物在草坪上的贴图键，帧数由资源清单决定（见 `Resources::frame_count`）。
+    pub image: &'static str,
+    /// 植物在商店中的卡片贴图键。
+    pub card: &'static str,
+    /// 植物在网格单元格中占据的层。
+    pub layer: PlantLayer,
+    /// 是否为水生植物。水生植物只能种植在水面上。
+    pub aquatic: bool,
+    /// 创建植物行为实现的构造函数。
+    pub constructor: fn() -> Box<dyn PlantTrait>,
+}
+
+/// 一种僵尸的规格。
+#[derive(Clone, Copy)]
+pub struct ZombieSpec {
+    /// 唯一标识，例如 `"conehead"`。
+    pub id: &'static str,
+    /// 显示名称。
+    pub name: &'static str,
+    /// 初始生命值。
+    pub health: i32,
+    /// 移动速度（像素/毫秒）。
+    pub speed: f32,
+    /// 单次啃食造成的伤害。
+    pub attack_damage: i32,
+    /// 两次啃食之间的间隔（毫秒）。
+    pub attack_interval: u64,
+    /// 行走动画的贴图键。
+    pub walk: &'static str,
+    /// 啃食动画的贴图键。
+    pub attack: &'static str,
+    /// 在水面行中游泳时的贴图键。还没有对应素材时，游泳的僵尸绘制裁剪后的行走动画。
+    pub swim: &'static str,
+    /// 创建僵尸行为实现的构造函数。
+    pub constructor: fn() -> Box<dyn ZombieTrait>,
+}
+
+/// 所有已注册的植物和僵尸规格。
+pub struct ContentRegistry {
+    /// 已注册的植物，按注册顺序排列。
+    plants: Vec<(PlantType, PlantSpec)>,
+    /// 已注册的僵尸，按注册顺序排列。
+    zombies: Vec<(ZombieType, ZombieSpec)>,
+    /// 已注册的自定义植物数量，也是下一个自定义植物的编号。
+    custom_plants: u16,
+    /// 已注册的自定义僵尸数量，也是下一个自定义僵尸的编号。
+    custom_zombies: u16,
+}
+
+/// 全局注册表，第一次访问时注册内置内容。
+static REGISTRY: LazyLock<RwLock<ContentRegistry>> = LazyLock::new(|| RwLock::new(ContentRegistry::with_builtins()));
+
+impl ContentRegistry {
+    /// 创建一个只包含内置植物和僵尸的注册表。
+    fn with_builtins() -> Self {
+        let plants = vec![
+            (
+                PlantType::Peashooter,
+                PlantSpec {
+                    id: "peashooter",
+                    name: "豌豆射手",
+                    cost: 100,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "peashooter",
+                    card: "peashooter_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(peashooter::Peashooter::new()),
+                },
+            ),
+            (
+                PlantType::Sunflower,
+                PlantSpec {
+                    id: "sunflower",
+                    name: "向日葵",
+                    cost: 50,
+                    recharge_ms: 5000,
+                    health: 300,
+                    image: "sunflower",
+                    card: "sunflower_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(sunflower::Sunflower::new()),
+                },
+            ),
+            (
+                PlantType::WallNut,
+                PlantSpec {
+                    id: "wallnut",
+                    name: "坚果墙",
+                    cost: 50,
+                    recharge_ms: 25000,
+                    health: 5000,
+                    image: "wallnut",
+                    card: "wallnut_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(wallnut::WallNut::new()),
+                },
+            ),
+            (
+                PlantType::LilyPad,
+                PlantSpec {
+                    id: "lily_pad",
+                    name: "睡莲",
+                    cost: 25,
+                    recharge_ms: 5000,
+                    health: 300,
+                    image: "lily_pad",
+                    card: "lily_pad_card",
+                    layer: PlantLayer::Underlay,
+                    aquatic: true,
+                    constructor: || Box::new(lily_pad::LilyPad::new()),
+                },
+            ),
+            (
+                PlantType::TangleKelp,
+                PlantSpec {
+                    id: "tangle_kelp",
+                    name: "缠绕水草",
+                    cost: 25,
+                    recharge_ms: 25000,
+                    health: 300,
+                    image: "tangle_kelp",
+                    card: "tangle_kelp_card",
+                    layer: PlantLayer::Main,
+                    aquatic: true,
+                    constructor: || Box::new(tangle_kelp::TangleKelp::new()),
+                },
+            ),
+            (
+                PlantType::Pumpkin,
+                PlantSpec {
+                    id: "pumpkin",
+                    name: "南瓜头",
+                    cost: 125,
+                    recharge_ms: 25000,
+                    health: 4000,
+                    image: "pumpkin",
+                    card: "pumpkin_card",
+                    layer: PlantLayer::Shell,
+                    aquatic: false,
+                    constructor: || Box::new(pumpkin::Pumpkin::new()),
+                },
+            ),
+            (
+                PlantType::Plantern,
+                PlantSpec {
+                    id: "plantern",
+                    name: "路灯花",
+                    cost: 25,
+                    recharge_ms: 25000,
+                    health: 300,
+                    image: "plantern",
+                    card: "plantern_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(plantern::Plantern::new()),
+                },
+            ),
+            (
+                PlantType::Blover,
+                PlantSpec {
+                    id: "blover",
+                    name: "三叶草",
+                    cost: 100,
+                    recharge_ms: 25000,
+                    health: 300,
+                    image: "blover",
+                    card: "blover_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(blover::Blover),
+                },
+            ),
+            (
+                PlantType::FlowerPot,
+                PlantSpec {
+                    id: "flower_pot",
+                    name: "花盆",
+                    cost: 25,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "flower_pot",
+                    card: "flower_pot_card",
+                    layer: PlantLayer::Underlay,
+                    aquatic: false,
+                    constructor: || Box::new(flower_pot::FlowerPot::new()),
+                },
+            ),
+            (
+                PlantType::CabbagePult,
+                PlantSpec {
+                    id: "cabbage_pult",
+                    name: "卷心菜投手",
+                    cost: 100,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "cabbage_pult",
+                    card: "cabbage_pult_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(cabbage_pult::CabbagePult::new()),
+                },
+            ),
+            (
+                PlantType::KernelPult,
+                PlantSpec {
+                    id: "kernel_pult",
+                    name: "玉米投手",
+                    cost: 100,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "kernel_pult",
+                    card: "kernel_pult_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(kernel_pult::KernelPult::new()),
+                },
+            ),
+            (
+                PlantType::SnowPea,
+                PlantSpec {
+                    id: "snow_pea",
+                    name: "寒冰射手",
+                    cost: 175,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "snow_pea_shooter",
+                    card: "snow_pea_shooter_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(snow_pea::SnowPea::new()),
+                },
+            ),
+            (
+                PlantType::Torchwood,
+                PlantSpec {
+                    id: "torchwood",
+                    name: "火炬树桩",
+                    cost: 175,
+                    recharge_ms: 7500,
+                    health: 1000,
+                    image: "torchwood",
+                    card: "torchwood_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(torchwood::Torchwood::new()),
+                },
+            ),
+            (
+                PlantType::Chomper,
+                PlantSpec {
+                    id: "chomper",
+                    name: "大嘴花",
+                    cost: 150,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "chomper",
+                    card: "chomper_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(chomper::Chomper::new()),
+                },
+            ),
+            (
+                PlantType::Cactus,
+                PlantSpec {
+                    id: "cactus",
+                    name: "仙人掌",
+                    cost: 125,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "cactus",
+                    card: "cactus_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(cactus::Cactus::new()),
+                },
+            ),
+            (
+                PlantType::Starfruit,
+                PlantSpec {
+                    id: "starfruit",
+                    name: "杨桃",
+                    cost: 125,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "starfruit",
+                    card: "starfruit_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(starfruit::Starfruit::new()),
+                },
+            ),
+            (
+                PlantType::MagnetShroom,
+                PlantSpec {
+                    id: "magnet_shroom",
+                    name: "磁力菇",
+                    cost: 100,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "magnet_shroom",
+                    card: "magnet_shroom_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(magnet_shroom::MagnetShroom::new()),
+                },
+            ),
+            (
+                PlantType::UmbrellaLeaf,
+                PlantSpec {
+                    id: "umbrella_leaf",
+                    name: "叶子保护伞",
+                    cost: 100,
+                    recharge_ms: 7500,
+                    health: 300,
+                    image: "umbrella_leaf",
+                    card: "umbrella_leaf_card",
+                    layer: PlantLayer::Main,
+                    aquatic: false,
+                    constructor: || Box::new(umbrella_leaf::UmbrellaLeaf::new()),
+                },
+            ),
+        ];
+
+        let zombies = vec![
+            (
+                ZombieType::Normal,
+                ZombieSpec {
+                    id: "normal",
+                    name: "普通僵尸",
+                    health: 200,
+                    speed: 0.017,
+                    attack_damage: 100,
+                    attack_interval: 1000,
+                    walk: "zombie_walk",
+                    attack: "zombie_attack",
+                    swim: "zombie_swim",
+                    constructor: || Box::new(normal_zombie::NormalZombie::new()),
+                },
+            ),
+            (
+                ZombieType::Conehead,
+                ZombieSpec {
+                    id: "conehead",
+                    name: "路障僵尸",
+                    health: 370,
+                    speed: 0.017,
+                    attack_damage: 100,
+                    attack_interval: 1000,
+                    walk: "cone_zombie_walk",
+                    attack: "cone_zombie_attack",
+                    swim: "cone_zombie_swim",
+                    constructor: || Box::new(conehead_zombie::ConeheadZombie::new()),
+                },
+            ),
+            (
+                ZombieType::Catapult,
+                ZombieSpec {
+                    id: "catapult",
+                    name: "投篮车僵尸",
+                    health: 650,
+                    speed: 0.012,
+                    attack_damage: 100,
+                    attack_interval: 1000,
+                    walk: "catapult_zombie_walk",
+                    attack: "catapult_zombie_attack",
+                    swim: "catapult_zombie_swim",
+                    constructor: || Box::new(catapult_zombie::CatapultZombie::new()),
+                },
+            ),
+        ];
+
+        ContentRegistry { plants, zombies, custom_plants: 0, custom_zombies: 0 }
+    }
+}
+
+/// 注册一种新的植物。
+///
+/// 如果已经注册过相同标识的植物，则用新的规格替换它（可以用来修改内置植物），并返回原来的类型。
+///
+/// # Arguments
+///
+/// * `spec` - 植物的规格。
+///
+/// # Returns
+///
+/// 返回该植物的 `PlantType`。
+pub fn register_plant(spec: PlantSpec) -> PlantType {
+    let mut registry = REGISTRY.write().unwrap();
+    if let Some(entry) = registry.plants.iter_mut().find(|(_, existing)| existing.id == spec.id) {
+        println!("Warning: plant '{}' is already registered, replacing its spec", spec.id);
+        entry.1 = spec;
+        return entry.0;
+    }
+    let plant_type = PlantType::Custom(registry.custom_plants);
+    registry.custom_plants += 1;
+    registry.plants.push((plant_type, spec));
+    plant_type
+}
+
+/// 注册一种新的僵尸。
+///
+/// 如果已经注册过相同标识的僵尸，则用新的规格替换它，并返回原来的类型。
+///
+/// # Arguments
+///
+/// * `spec` - 僵尸的规格。
+///
+/// # Returns
+///
+/// 返回该僵尸的 `ZombieType`。
+pub fn register_zombie(spec: ZombieSpec) -> ZombieType {
+    let mut registry = REGISTRY.write().unwrap();
+    if let Some(entry) = registry.zombies.iter_mut().find(|(_, existing)| existing.id == spec.id) {
+        println!("Warning: zombie '{}' is already registered, replacing its spec", spec.id);
+        entry.1 = spec;
+        return entry.0;
+    }
+    let zombie_type = ZombieType::Custom(registry.custom_zombies);
+    registry.custom_zombies += 1;
+    registry.zombies.push((zombie_type, spec));
+    zombie_type
+}
+
+/// 查找一种植物的规格，未注册的类型返回 `None`。
+///
+/// 返回的规格已经应用了当前调校文件 (`tuning`) 中对该植物的覆盖。
+pub fn find_plant_spec(plant_type: PlantType) -> Option<PlantSpec> {
+    let mut spec = {
+        let registry = REGISTRY.read().unwrap();
+        registry.plants.iter().find(|(t, _)| *t == plant_type).map(|(_, spec)| *spec)?
+    };
+    tuning::current().apply_to_plant(&mut spec);
+    Some(spec)
+}
+
+/// 查找一种僵尸的规格，未注册的类型返回 `None`。
+///
+/// 返回的规格已经应用了当前调校文件 (`tuning`) 中对该僵尸的覆盖。
+pub fn find_zombie_spec(zombie_type: ZombieType) -> Option<ZombieSpec> {
+    let mut spec = {
+        let registry = REGISTRY.read().unwrap();
+        registry.zombies.iter().find(|(t, _)| *t == zombie_type).map(|(_, spec)| *spec)?
+    };
+    tuning::current().apply_to_zombie(&mut spec);
+    Some(spec)
+}
+
+/// 获取一种植物的规格。
+///
+/// # Panics
+///
+/// 如果该类型是尚未注册的自定义植物，则 panic。
+pub fn plant_spec(plant_type: PlantType) -> PlantSpec {
+    find_plant_spec(plant_type).unwrap_or_else(|| panic!("植物类型 {:?} 尚未注册", plant_type))
+}
+
+/// 获取一种僵尸的规格。
+///
+/// # Panics
+///
+/// 如果该类型是尚未注册的自定义僵尸，则 panic。
+pub fn zombie_spec(zombie_type: ZombieType) -> ZombieSpec {
+    find_zombie_spec(zombie_type).unwrap_or_else(|| panic!("僵尸类型 {:?} 尚未注册", zombie_type))
+}
+
+/// 根据标识查找植物类型。
+pub fn plant_by_id(id: &str) -> Option<PlantType> {
+    let registry = REGISTRY.read().unwrap();
+    registry.plants.iter().find(|(_, spec)| spec.id == id).map(|(t, _)| *t)
+}
+
+/// 根据标识查找僵尸类型。
+pub fn zombie_by_id(id: &str) -> Option<ZombieType> {
+    let registry = REGISTRY.read().unwrap();
+    registry.zombies.iter().find(|(_, spec)| spec.id == id).map(|(t, _)| *t)
+}
+
+/// 按注册顺序列出所有已注册的植物类型。
+pub fn plant_types() -> Vec<PlantType> {
+    REGISTRY.read().unwrap().plants.iter().map(|(t, _)| *t).collect()
+}
+
+/// 按注册顺序列出所有已注册的僵尸类型。
+pub fn zombie_types() -> Vec<ZombieType> {
+    REGISTRY.read().unwrap().zombies.iter().map(|(t, _)| *t).collect()
+}
+
+/// 列出所有已注册内容用到的贴图键，供资源加载器加载。
+pub fn sprite_keys() -> Vec<&'static str> {
+    let registry = REGISTRY.read().unwrap();
+    let plant_keys = registry.plants.iter().flat_map(|(_, spec)| [spec.image, spec.card]);
+    let zombie_keys = registry.zombies.iter().flat_map(|(_, spec)| [spec.walk, spec.attack, spec.swim]);
+    plant_keys.chain(zombie_keys).collect()
+}
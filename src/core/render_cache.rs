@@ -0,0 +1,209 @@
+//! # 渲染缓存模块 (`render_cache`)
+//!
+//! `render_cache` 模块保存渲染器跨帧复用的对象，避免每一帧为每个实体单独提交绘制或重新创建对象：
+//!
+//! - `SpriteBatcher` 把同一贴图同一帧的所有绘制合并为一个 `SpriteBatch`，
+//!   场上有几百个僵尸时，每种动画帧只需要一次绘制调用。批次对象在帧之间保留，只清空其中的实例。
+//! - `TextCache` 按内容、颜色和字号缓存 `Text`，阳光数量和各种提示文字只在内容变化时重新排版。
+//!
+//! 批次按第一次使用的顺序绘制，同一批次内按加入的顺序绘制，不同批次的实例之间没有前后关系。
+//! 需要保持前后遮挡关系的图层之间要调用 `flush`，例如渲染器在草坪的每一行结束时都会 `flush`。
+
+use crate::core::animation::Animator;
+use crate::core::resources::Resources;
+use ggez::graphics::spritebatch::SpriteBatch;
+use ggez::graphics::{self, Color, DrawParam, Text, TextFragment};
+use ggez::{Context, GameResult};
+use std::collections::HashMap;
+
+/// 一个贴图帧的批次。
+struct BatchSlot {
+    /// 批次对象，图像为对应的贴图帧。
+    batch: SpriteBatch,
+    /// 这个批次在本次 `flush` 之前是否已经加入了实例。
+    queued: bool,
+}
+
+/// 按贴图帧合并绘制的精灵批处理器。
+///
+/// 实体把绘制加入批处理器，渲染器在每个图层结束时调用 `flush` 统一提交。
+pub struct SpriteBatcher {
+    /// 所有创建过的批次。
+    slots: Vec<BatchSlot>,
+    /// 贴图名称和帧序号到 `slots` 下标的映射。
+    index: HashMap<String, Vec<Option<usize>>>,
+    /// 本次 `flush` 之前用到的批次，按第一次使用的顺序排列。
+    order: Vec<usize>,
+}
+
+impl SpriteBatcher {
+    /// 创建一个空的批处理器。
+    pub fn new() -> SpriteBatcher {
+        SpriteBatcher { slots: Vec::new(), index: HashMap::new(), order: Vec::new() }
+    }
+
+    /// 加入贴图的一帧。
+    ///
+    /// 每个批次在本次 `flush` 中第一次使用时会重新设置图像，热重载后的贴图因此会立即生效。
+    ///
+    /// # Arguments
+    ///
+    /// * `resources` - 游戏资源，用于获取帧图像。找不到的贴图使用棋盘格占位图。
+    /// * `key` - 贴图名称。
+    /// * `frame` - 帧序号，超出实际加载到的帧数时使用最后一帧。
+    /// * `param` - 绘制参数。
+    pub fn add(&mut self, resources: &Resources, key: &str, frame: usize, param: DrawParam) {
+        if !self.index.contains_key(key) {
+            self.index.insert(key.to_string(), Vec::new());
+        }
+        let frames = self.index.get_mut(key).unwrap();
+        if frames.len() <= frame {
+            frames.resize(frame + 1, None);
+        }
+
+        let image = resources.frame_at(key, frame);
+        let slot = *frames[frame].get_or_insert_with(|| {
+            self.slots.push(BatchSlot { batch: SpriteBatch::new(image.clone()), queued: false });
+            self.slots.len() - 1
+        });
+
+        let entry = &mut self.slots[slot];
+        if !entry.queued {
+            entry.queued = true;
+            entry.batch.set_image(image.clone());
+            self.order.push(slot);
+        }
+        entry.batch.add(param);
+    }
+
+    /// 加入动画播放器当前显示的帧。
+    ///
+    /// # Arguments
+    ///
+    /// * `resources` - 游戏资源。
+    /// * `animator` - 实体的动画播放器，其片段名称即贴图名称。
+    /// * `param` - 绘制参数。
+    pub fn add_animator(&mut self, resources: &Resources, animator: &Animator, param: DrawParam) {
+        self.add(resources, animator.clip_name(), animator.frame(), param);
+    }
+
+    /// 绘制所有已加入的实例并清空批次，批次对象留给后续的帧复用。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    ///
+    /// # Returns
+    ///
+    /// 如果绘制成功，返回 `GameResult<()>`。
+    pub fn flush(&mut self, ctx: &mut Context) -> GameResult {
+        for slot in self.order.drain(..) {
+            let entry = &mut self.slots[slot];
+            graphics::draw(ctx, &entry.batch, DrawParam::default())?;
+            entry.batch.clear();
+            entry.queued = false;
+        }
+        Ok(())
+    }
+}
+
+impl Default for SpriteBatcher {
+    fn default() -> Self {
+        SpriteBatcher::new()
+    }
+}
+
+/// 缓存文字的样式：RGBA 颜色和字号。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct TextStyle {
+    color: (u8, u8, u8, u8),
+    scale: u32,
+}
+
+/// 一段缓存的文字。
+struct CachedText {
+    /// 排版好的文字。
+    text: Text,
+    /// 自上一次 `end_frame` 以来是否用到过。
+    used: bool,
+}
+
+/// 跨帧复用的文字缓存。
+///
+/// 一帧内没有用到的文字会在 `end_frame` 时被丢弃，因此不断变化的数值（例如阳光数量）不会让缓存无限增长。
+pub struct TextCache {
+    /// 先按颜色和字号、再按内容索引的文字。内容用 `&str` 查找，命中缓存时不需要分配。
+    texts: HashMap<TextStyle, HashMap<String, CachedText>>,
+}
+
+impl TextCache {
+    /// 创建一个空的文字缓存。
+    pub fn new() -> TextCache {
+        TextCache { texts: HashMap::new() }
+    }
+
+    /// 获取指定内容、颜色和字号的文字，第一次使用时创建。
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - 文字内容。
+    /// * `color` - 文字颜色。
+    /// * `scale` - 字号。
+    ///
+    /// # Returns
+    ///
+    /// 返回缓存中的 `Text`，同一帧和之后的帧中可以重复使用其排版结果。
+    pub fn get(&mut self, content: &str, color: Color, scale: f32) -> &Text {
+        let style = TextStyle { color: color.to_rgba(), scale: scale.to_bits() };
+        let texts = self.texts.entry(style).or_default();
+        if !texts.contains_key(content) {
+            texts.insert(
+                content.to_string(),
+                CachedText { text: Text::new(TextFragment::new(content).color(color).scale(scale)), used: false },
+            );
+        }
+        let cached = texts.get_mut(content).unwrap();
+        cached.used = true;
+        &cached.text
+    }
+
+    /// 结束一帧：丢弃自上一次调用以来没有用到的文字。
+    pub fn end_frame(&mut self) {
+        for texts in self.texts.values_mut() {
+            texts.retain(|_, cached| std::mem::take(&mut cached.used));
+        }
+        self.texts.retain(|_, texts| !texts.is_empty());
+    }
+
+    /// 缓存中的文字数量。
+    pub fn len(&self) -> usize {
+        self.texts.values().map(HashMap::len).sum()
+    }
+
+    /// 缓存是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.texts.is_empty()
+    }
+}
+
+impl Default for TextCache {
+    fn default() -> Self {
+        TextCache::new()
+    }
+}
+
+/// 渲染器跨帧复用的全部缓存，由游戏状态持有并在每一帧传给 `Renderer`。
+#[derive(Default)]
+pub struct RenderCache {
+    /// 植物、投射物、僵尸和阳光的精灵批处理器。
+    pub sprites: SpriteBatcher,
+    /// 阳光数量、提示信息和按钮文字的缓存。
+    pub texts: TextCache,
+}
+
+impl RenderCache {
+    /// 创建空的渲染缓存。
+    pub fn new() -> RenderCache {
+        RenderCache::default()
+    }
+}
@@ -2,22 +2,84 @@
 //!
 //! `renderer` 模块负责游戏的所有渲染任务。
 //! 它包含了将游戏状态（如植物、僵尸、子弹、UI元素等）绘制到屏幕上的所有逻辑。
+//!
+//! 游戏画面中的植物、投射物、僵尸和阳光通过 `RenderCache` 中的精灵批处理器按贴图帧合并绘制，
+//! 每个图层结束时提交一次；阳光数量、提示信息和按钮文字从文字缓存中复用，只在内容变化时重新排版。
 
+use crate::core::render_cache::{RenderCache, TextCache};
 use crate::core::resources::Resources;
 use crate::ui::grid::Grid;
-use crate::plants::Plant;
-use crate::entities::pea::Pea;
+use crate::plants::{Plant, PlantLayer};
+use crate::ui::fog::Fog;
+use crate::entities::projectile::{Flight, Projectile};
 use crate::zombies::Zombie;
 use crate::entities::sun::Sun;
 use crate::ui::shop::Shop;
 use crate::ui::shovel::Shovel;
+use crate::ui::level_select::LevelSelect;
+use crate::core::profile::Profile;
+use crate::entities::reward::SeedPacketReward;
+use crate::ui::seed_chooser::{SeedChooser, LAWN_SCROLL_DISTANCE};
+use crate::ui::lawn::LawnGeometry;
+use crate::core::leaderboard::{EndlessStats, Leaderboard};
+use crate::zombies::ZombieType;
+use crate::mechanics::campaign::Environment;
 use ggez::{Context, GameResult};
-use ggez::graphics::{self, Color, DrawParam, Text, TextFragment};
+use ggez::graphics::{self, Color, DrawMode, DrawParam, Mesh, Rect, Text, TextFragment};
+
+/// 失败、胜利或暂停画面中的按钮：按钮文字和位置尺寸 `(x, y, w, h)`。
+pub type MenuButton<'a> = (&'a str, (f32, f32, f32, f32));
+
+/// 一帧游戏画面需要绘制的全部内容。
+///
+/// 由 `GameState::draw` 每帧从游戏状态中借用组装，传给 `Renderer::draw_game`。
+#[derive(Clone, Copy)]
+pub struct GameView<'a> {
+    /// 当前关卡的环境，夜晚时背景更暗。
+    pub environment: Environment,
+    /// 游戏网格，用于辅助定位、绘制墓碑和可能的调试绘制。
+    pub grid: &'a Grid,
+    /// 草坪上的迷雾，被遮挡的僵尸和投射物不会被绘制。
+    pub fog: &'a Fog,
+    /// 当前场景中所有植物。
+    pub plants: &'a [Plant],
+    /// 当前场景中所有投射物。
+    pub projectiles: &'a [Projectile],
+    /// 当前场景中所有僵尸。
+    pub zombies: &'a [Zombie],
+    /// 当前场景中所有阳光。
+    pub suns: &'a [Sun],
+    /// 游戏商店，用于绘制商店UI。
+    pub shop: &'a Shop,
+    /// 当前玩家拥有的阳光数量。
+    pub sun_count: i32,
+    /// 游戏是否已结束。
+    pub game_over: bool,
+    /// 关卡是否已经胜利。
+    pub victory: bool,
+    /// 是否显示"最后一波"提示。
+    pub show_final_wave: bool,
+    /// 当前的游戏阶段，暂停时显示暂停信息。
+    pub game_state: crate::core::states::GameState,
+    /// 暂停按钮的位置尺寸 `(x, y, w, h)`。
+    pub pause_button_rect: (f32, f32, f32, f32),
+    /// 铲子。
+    pub shovel: &'a Shovel,
+    /// 是否为关卡开始前的初始暂停，此时暂停按钮显示 "Start"。
+    pub is_initial_pause: bool,
+    /// 失败、胜利或暂停画面中需要显示的按钮。
+    pub menu_buttons: &'a [MenuButton<'a>],
+    /// 关卡胜利后掉落、尚未领取的奖励卡片。
+    pub reward_packet: Option<&'a SeedPacketReward>,
+    /// 无尽模式中本局的统计数据和排行榜，冒险模式中为 `None`。
+    pub endless: Option<(&'a EndlessStats, &'a Leaderboard)>,
+}
 
 /// 渲染器结构体，封装了所有与游戏场景绘制相关的操作。
 ///
 /// `Renderer` 是一个无状态的结构体，其方法通常接收绘图上下文 (`Context`)、
 /// 游戏资源 (`Resources`) 以及需要绘制的游戏实体和UI元素作为参数。
+/// 需要跨帧复用的批次和文字保存在调用者持有的 `RenderCache` 中。
 pub struct Renderer;
 
 impl Renderer {
@@ -31,67 +93,89 @@ impl Renderer {
     ///
     /// * `ctx` - ggez的上下文环境，用于绘图操作。
     /// * `resources` - 包含所有已加载图像资源的 `Resources` 实例。
-    /// * `grid` - 游戏网格，用于辅助定位和可能的调试绘制。
-    /// * `plants` - 当前场景中所有植物的切片。
-    /// * `peas` - 当前场景中所有豌豆的切片。
-    /// * `zombies` - 当前场景中所有僵尸的切片。
-    /// * `suns` - 当前场景中所有阳光的切片。
-    /// * `shop` - 游戏商店实例，用于绘制商店UI。
-    /// * `sun_count` - 当前玩家拥有的阳光数量。
-    /// * `game_over` - 一个布尔值，指示游戏是否已结束。
+    /// * `cache` - 跨帧复用的精灵批次和文字缓存。
+    /// * `view` - 本帧需要绘制的实体、界面元素和游戏状态。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    pub fn draw_game(
-        ctx: &mut Context, 
-        resources: &Resources,
-        grid: &Grid,
-        plants: &[Plant],
-        peas: &[Pea],
-        zombies: &[Zombie],
-        suns: &[Sun],
-        shop: &Shop,
-        sun_count: i32,
-        game_over: bool,
-        victory: bool,
-        show_final_wave: bool,
-        game_state: crate::core::states::GameState,
-        pause_button_rect: (f32, f32, f32, f32),
-        shovel: &Shovel,
-        is_initial_pause: bool
-    ) -> GameResult {
+    pub fn draw_game(ctx: &mut Context, resources: &Resources, cache: &mut RenderCache, view: &GameView) -> GameResult {
+        let GameView {
+            environment,
+            grid,
+            fog,
+            plants,
+            projectiles,
+            zombies,
+            suns,
+            shop,
+            sun_count,
+            game_over,
+            victory,
+            show_final_wave,
+            game_state,
+            pause_button_rect,
+            shovel,
+            is_initial_pause,
+            menu_buttons,
+            reward_packet,
+            endless,
+        } = *view;
+
         // 清空屏幕
         graphics::clear(ctx, Color::WHITE);
 
         // 绘制背景
-        Renderer::draw_background(ctx, resources)?;
+        Renderer::draw_background(ctx, resources, environment)?;
         
         // 绘制网格（调试用）
         grid.draw(ctx)?;
         
-        // 绘制植物
-        for plant in plants {
-            plant.draw(ctx, resources)?;
-        }
-        
-        // 绘制豌豆
-        for pea in peas {
-            pea.draw(ctx, resources)?;
+        // 从后往前逐行绘制植物、投射物和僵尸，跳过被迷雾遮挡的部分。
+        // 批次内的绘制顺序与加入顺序无关，因此每一行（以及每一行中的每个植物层）结束时都要 flush，
+        // 下方行的实体才能遮住上方行的实体
+        let sprites = &mut cache.sprites;
+        let geometry = grid.geometry();
+        let fog_mask = fog.mask(&geometry, plants);
+        let visible_projectiles = projectiles.iter().filter(|projectile| !fog_mask.hides(projectile.x, projectile.row));
+        let (lobbed, flat): (Vec<&Projectile>, Vec<&Projectile>) =
+            visible_projectiles.partition(|projectile| matches!(projectile.flight, Flight::Lobbed { .. }));
+        for row in 0..geometry.rows {
+            // 植物按层从下到上绘制，使南瓜头套在主层植物外面
+            for layer in [PlantLayer::Underlay, PlantLayer::Main, PlantLayer::Shell] {
+                for plant in plants.iter().filter(|plant| plant.grid_y == row && plant.get_plant_type().layer() == layer) {
+                    plant.draw(sprites, resources);
+                }
+                sprites.flush(ctx)?;
+            }
+
+            for projectile in flat.iter().filter(|projectile| projectile.row == row) {
+                projectile.draw(sprites, resources);
+            }
+            for zombie in zombies.iter().filter(|zombie| zombie.row == row && !fog_mask.hides(zombie.x, zombie.row)) {
+                zombie.draw(sprites, resources);
+            }
+            sprites.flush(ctx)?;
         }
-        
-        // 绘制僵尸
-        for zombie in zombies {
-            zombie.draw(ctx, resources)?;
+
+        // 抛物线投掷物从上方落下，绘制在僵尸之上
+        for projectile in lobbed {
+            projectile.draw(sprites, resources);
         }
+        sprites.flush(ctx)?;
+
+        // 在实体之上绘制迷雾
+        Fog::draw(ctx, &fog_mask)?;
         
         // 绘制阳光
         for sun in suns {
-            sun.draw(ctx, resources)?;
+            sun.draw(sprites, resources);
         }
+        sprites.flush(ctx)?;
         
         // 绘制UI元素
-        Renderer::draw_ui(ctx, resources, shop, sun_count)?;
+        let texts = &mut cache.texts;
+        Renderer::draw_ui(ctx, resources, texts, shop, sun_count)?;
         
         // 绘制铲子
         shovel.draw(ctx, resources)?;
@@ -106,25 +190,21 @@ impl Renderer {
         // 绘制按钮背景
         graphics::draw(
             ctx,
-            &resources.button_image,
+            resources.image("button"),
             DrawParam::default()
                 .dest([x, y])
-                .scale([w / resources.button_image.width() as f32, h / resources.button_image.height() as f32])
+                .scale([w / resources.image("button").width() as f32, h / resources.image("button").height() as f32])
         )?;
         
         // 绘制按钮文字
-        let button_text = Text::new(
-            TextFragment::new(button_text)
-                .color(Color::BLACK)
-                .scale(20.0)
-        );
+        let button_text = texts.get(button_text, Color::BLACK, 20.0);
         
         let text_width = button_text.width(ctx);
         let text_height = button_text.height(ctx);
         
         graphics::draw(
             ctx,
-            &button_text,
+            button_text,
             DrawParam::default().dest([
                 x + w / 2.0 - text_width / 2.0,
                 y + h / 2.0 - text_height / 2.0,
@@ -133,48 +213,85 @@ impl Renderer {
         
         // 如果游戏暂停，显示暂停信息
         if game_state == crate::core::states::GameState::Paused {
-            Renderer::draw_pause_message(ctx, is_initial_pause)?;
+            Renderer::draw_pause_message(ctx, texts, is_initial_pause)?;
         }
         
         // 如果显示最后一波信息
         if show_final_wave {
-            Renderer::draw_final_wave_message(ctx)?;
+            Renderer::draw_final_wave_message(ctx, texts)?;
+        }
+        
+        // 无尽模式中显示本局统计数据
+        if let Some((stats, _)) = endless {
+            Renderer::draw_endless_stats(ctx, texts, stats)?;
         }
         
         // 如果游戏结束，显示结束画面
         if game_over {
-            Renderer::draw_game_over(ctx)?;
+            Renderer::draw_game_over(ctx, texts)?;
+            
+            // 无尽模式结束时显示排行榜
+            if let Some((_, leaderboard)) = endless {
+                Renderer::draw_leaderboard(ctx, texts, leaderboard)?;
+            }
         }
         
         // 如果游戏胜利，显示胜利画面
         if victory {
-            Renderer::draw_victory_message(ctx)?;
+            Renderer::draw_victory_message(ctx, texts)?;
+        }
+        
+        // 失败、胜利或暂停时显示"重新开始"等按钮
+        for &(label, rect) in menu_buttons {
+            Renderer::draw_menu_button(ctx, resources, texts, label, rect)?;
         }
         
+        // 绘制尚未领取的奖励卡片
+        if let Some(packet) = reward_packet {
+            packet.draw(ctx, resources)?;
+        }
+        
+        // 本帧没有用到的文字不再保留
+        texts.end_frame();
+        
         // 呈现画面
         graphics::present(ctx)?;
         
         Ok(())
     }
     
+    /// 获取指定环境下绘制背景时使用的颜色，夜晚时使用偏蓝的暗色，屋顶使用偏红的暖色。
+    fn background_color(environment: Environment) -> Color {
+        match environment {
+            Environment::Day => Color::WHITE,
+            Environment::Night => Color::new(0.45, 0.45, 0.65, 1.0),
+            Environment::Roof => Color::new(1.0, 0.85, 0.75, 1.0),
+        }
+    }
+    
     /// 绘制游戏背景，包括主背景图和商店面板。
     ///
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境。
     /// * `resources` - 包含背景和商店图像的 `Resources` 实例。
+    /// * `environment` - 当前关卡的环境。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_background(ctx: &mut Context, resources: &Resources) -> GameResult {
+    fn draw_background(ctx: &mut Context, resources: &Resources, environment: Environment) -> GameResult {
         // 绘制主背景
-        graphics::draw(ctx, &resources.background, DrawParam::default())?;
+        graphics::draw(
+            ctx,
+            resources.image("background"),
+            DrawParam::default().color(Renderer::background_color(environment))
+        )?;
         
         // 绘制商店面板
         graphics::draw(
             ctx, 
-            &resources.shop_image, 
+            resources.image("shop"), 
             DrawParam::default().dest([250.0, 0.0])
         )?;
         
@@ -187,64 +304,72 @@ impl Renderer {
     ///
     /// * `ctx` - ggez的上下文环境。
     /// * `resources` - `Resources` 实例，主要用于商店绘制时传递给商店的 `draw` 方法。
+    /// * `texts` - 文字缓存，阳光数量只在变化时重新排版。
     /// * `shop` - 游戏商店实例。
     /// * `sun_count` - 当前玩家拥有的阳光数量，用于显示。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_ui(ctx: &mut Context, resources: &Resources, shop: &Shop, sun_count: i32) -> GameResult {
+    fn draw_ui(ctx: &mut Context, resources: &Resources, texts: &mut TextCache, shop: &Shop, sun_count: i32) -> GameResult {
         // 绘制商店卡片和选中植物预览
         shop.draw(ctx, resources)?;
         
         // 绘制阳光数量
-        let sun_text = Text::new(
-            TextFragment::new(format!("{}", sun_count))
-                .color(Color::BLACK)
-                .scale(25.0)
-        );
+        let sun_text = texts.get(&sun_count.to_string(), Color::BLACK, 25.0);
         
         graphics::draw(
             ctx,
-            &sun_text,
+            sun_text,
             DrawParam::default().dest([280.0, 65.0])
         )?;
         
         Ok(())
     }
     
-    /// 绘制游戏结束画面。
-    ///
-    /// 当游戏结束时，在屏幕中央显示 "GAME OVER" 文本。
+    /// 在屏幕中央绘制一段缓存的文字。
     ///
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
+    /// * `content` - 文字内容。
+    /// * `color` - 文字颜色。
+    /// * `scale` - 字号。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_game_over(ctx: &mut Context) -> GameResult {
-        let game_over_text = Text::new(
-            TextFragment::new("GAME OVER")
-                .color(Color::RED)
-                .scale(100.0)
-        );
-        
-        let text_width = game_over_text.width(ctx);
-        let text_height = game_over_text.height(ctx);
+    fn draw_centered_text(ctx: &mut Context, texts: &mut TextCache, content: &str, color: Color, scale: f32) -> GameResult {
+        let text = texts.get(content, color, scale);
+        let text_width = text.width(ctx);
+        let text_height = text.height(ctx);
         let screen_size = graphics::drawable_size(ctx);
         
         graphics::draw(
             ctx,
-            &game_over_text,
+            text,
             DrawParam::default().dest([
                 screen_size.0 / 2.0 - text_width / 2.0,
                 screen_size.1 / 2.0 - text_height / 2.0,
             ])
-        )?;
-        
-        Ok(())
+        )
+    }
+    
+    /// 绘制游戏结束画面。
+    ///
+    /// 当游戏结束时，在屏幕中央显示 "GAME OVER" 文本。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    fn draw_game_over(ctx: &mut Context, texts: &mut TextCache) -> GameResult {
+        Renderer::draw_centered_text(ctx, texts, "GAME OVER", Color::RED, 100.0)
     }
     
     /// 绘制最后一波来袭消息。
@@ -254,31 +379,13 @@ impl Renderer {
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_final_wave_message(ctx: &mut Context) -> GameResult {
-        let final_wave_text = Text::new(
-            TextFragment::new("The final wave is coming!")
-                .color(Color::RED)
-                .scale(70.0)
-        );
-        
-        let text_width = final_wave_text.width(ctx);
-        let text_height = final_wave_text.height(ctx);
-        let screen_size = graphics::drawable_size(ctx);
-        
-        graphics::draw(
-            ctx,
-            &final_wave_text,
-            DrawParam::default().dest([
-                screen_size.0 / 2.0 - text_width / 2.0,
-                screen_size.1 / 2.0 - text_height / 2.0,
-            ])
-        )?;
-        
-        Ok(())
+    fn draw_final_wave_message(ctx: &mut Context, texts: &mut TextCache) -> GameResult {
+        Renderer::draw_centered_text(ctx, texts, "The final wave is coming!", Color::RED, 70.0)
     }
     
     /// 绘制游戏胜利画面。
@@ -288,31 +395,13 @@ impl Renderer {
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_victory_message(ctx: &mut Context) -> GameResult {
-        let victory_text = Text::new(
-            TextFragment::new("you win!")
-                .color(Color::GREEN)
-                .scale(100.0)
-        );
-        
-        let text_width = victory_text.width(ctx);
-        let text_height = victory_text.height(ctx);
-        let screen_size = graphics::drawable_size(ctx);
-        
-        graphics::draw(
-            ctx,
-            &victory_text,
-            DrawParam::default().dest([
-                screen_size.0 / 2.0 - text_width / 2.0,
-                screen_size.1 / 2.0 - text_height / 2.0,
-            ])
-        )?;
-        
-        Ok(())
+    fn draw_victory_message(ctx: &mut Context, texts: &mut TextCache) -> GameResult {
+        Renderer::draw_centered_text(ctx, texts, "you win!", Color::GREEN, 100.0)
     }
     
     /// 绘制游戏暂停信息。
@@ -324,39 +413,263 @@ impl Renderer {
     /// # Arguments
     ///
     /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
     /// * `is_initial_pause` - 是否为初始暂停状态。
     ///
     /// # Returns
     ///
     /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    fn draw_pause_message(ctx: &mut Context, is_initial_pause: bool) -> GameResult {
-        let pause_text = if is_initial_pause {
-            Text::new(
-                TextFragment::new("Make sure the screen zoom ratio is 100%.\nClick Start in the upper right corner.")
-                    .color(Color::RED)
-                    .scale(60.0)
+    fn draw_pause_message(ctx: &mut Context, texts: &mut TextCache, is_initial_pause: bool) -> GameResult {
+        if is_initial_pause {
+            Renderer::draw_centered_text(
+                ctx,
+                texts,
+                "Make sure the screen zoom ratio is 100%.\nClick Start in the upper right corner.",
+                Color::RED,
+                60.0,
             )
         } else {
-            Text::new(
-                TextFragment::new("Game Paused")
-                    .color(Color::BLUE)
-                    .scale(80.0)
-            )
-        };
-        
-        let text_width = pause_text.width(ctx);
-        let text_height = pause_text.height(ctx);
+            Renderer::draw_centered_text(ctx, texts, "Game Paused", Color::BLUE, 80.0)
+        }
+    }
+    
+    /// 绘制选关界面并呈现到屏幕上。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 包含背景图像的 `Resources` 实例。
+    /// * `profile` - 玩家存档，用于显示关卡状态。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw_level_select(ctx: &mut Context, resources: &Resources, profile: &Profile) -> GameResult {
+        graphics::clear(ctx, Color::WHITE);
+        LevelSelect::draw(ctx, resources, profile)?;
+        graphics::present(ctx)
+    }
+    
+    /// 绘制资源加载画面并呈现到屏幕上。
+    ///
+    /// 加载画面不依赖任何贴图：黑色背景上居中显示 "Loading..." 文本和已加载数量与总数之比的进度条。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `progress` - `(已加载数量, 总数)`。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw_loading(ctx: &mut Context, progress: (usize, usize)) -> GameResult {
+        const BAR_WIDTH: f32 = 400.0;
+        const BAR_HEIGHT: f32 = 24.0;
+
+        graphics::clear(ctx, Color::BLACK);
+
+        let (loaded, total) = progress;
+        let fraction = if total == 0 { 1.0 } else { loaded as f32 / total as f32 };
         let screen_size = graphics::drawable_size(ctx);
+        let bar_x = screen_size.0 / 2.0 - BAR_WIDTH / 2.0;
+        let bar_y = screen_size.1 / 2.0;
+
+        let loading_text = Text::new(
+            TextFragment::new(format!("Loading... {}/{}", loaded, total))
+                .color(Color::WHITE)
+                .scale(32.0)
+        );
+        let text_width = loading_text.width(ctx);
+        let text_height = loading_text.height(ctx);
+        graphics::draw(
+            ctx,
+            &loading_text,
+            DrawParam::default().dest([screen_size.0 / 2.0 - text_width / 2.0, bar_y - text_height - 16.0])
+        )?;
+
+        if fraction > 0.0 {
+            let filled = Mesh::new_rectangle(
+                ctx,
+                DrawMode::fill(),
+                Rect::new(bar_x, bar_y, BAR_WIDTH * fraction.min(1.0), BAR_HEIGHT),
+                Color::from_rgb(120, 200, 60),
+            )?;
+            graphics::draw(ctx, &filled, DrawParam::default())?;
+        }
+
+        let outline = Mesh::new_rectangle(
+            ctx,
+            DrawMode::stroke(2.0),
+            Rect::new(bar_x, bar_y, BAR_WIDTH, BAR_HEIGHT),
+            Color::WHITE,
+        )?;
+        graphics::draw(ctx, &outline, DrawParam::default())?;
+
+        graphics::present(ctx)
+    }
+
+    /// 绘制关卡开始前的选卡界面并呈现到屏幕上。
+    ///
+    /// 草坪背景会随选卡界面的滚动进度向左移动，露出右侧街道上本关的僵尸阵容。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 游戏资源。
+    /// * `chooser` - 选卡界面的状态。
+    /// * `zombie_types` - 本关会出现的僵尸类型，用于展示僵尸阵容。
+    /// * `environment` - 本关的环境，夜晚时背景更暗。
+    /// * `geometry` - 本关草坪的几何信息，展示的僵尸按它的行排列。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw_seed_select(
+        ctx: &mut Context,
+        resources: &Resources,
+        chooser: &SeedChooser,
+        zombie_types: &[ZombieType],
+        environment: Environment,
+        geometry: &LawnGeometry,
+    ) -> GameResult {
+        graphics::clear(ctx, Color::BLACK);
+        
+        let offset = chooser.scroll_offset();
+        graphics::draw(
+            ctx,
+            resources.image("background"),
+            DrawParam::default()
+                .dest([-offset, 0.0])
+                .color(Renderer::background_color(environment))
+        )?;
+        
+        // 在街道上展示本关的僵尸阵容，每种僵尸站两只
+        let street_x = 1000.0 + LAWN_SCROLL_DISTANCE;
+        let mut index = 0;
+        for &zombie_type in zombie_types {
+            let walk = zombie_type.spec().walk;
+            for _ in 0..2 {
+                let row = (index * 2) % geometry.rows.max(1);
+                let x = street_x + (index % 2) as f32 * 90.0 - offset;
+                let y = geometry.row_y(row) - geometry.cell_height / 4.0;
+                graphics::draw(
+                    ctx,
+                    resources.image(walk),
+                    resources.draw_param(walk).dest([x, y]),
+                )?;
+                index += 1;
+            }
+        }
+        
+        // 商店面板固定在屏幕上方，作为卡槽
+        graphics::draw(ctx, resources.image("shop"), DrawParam::default().dest([250.0, 0.0]))?;
+        chooser.draw(ctx, resources)?;
+        
+        graphics::present(ctx)
+    }
+    
+    /// 在屏幕右上角显示无尽模式本局的统计数据。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
+    /// * `stats` - 本局的统计数据。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    fn draw_endless_stats(ctx: &mut Context, texts: &mut TextCache, stats: &EndlessStats) -> GameResult {
+        let stats_text = texts.get(
+            &format!(
+                "Flags: {}\nKills: {}\nSun: {}",
+                stats.flags_survived, stats.zombies_killed, stats.sun_produced
+            ),
+            Color::WHITE,
+            20.0,
+        );
+        
+        graphics::draw(ctx, stats_text, DrawParam::default().dest([1050.0, 10.0]))
+    }
+    
+    /// 绘制无尽模式的排行榜面板，显示在游戏结束画面的左侧。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `texts` - 文字缓存。
+    /// * `leaderboard` - 无尽模式的排行榜。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    fn draw_leaderboard(ctx: &mut Context, texts: &mut TextCache, leaderboard: &Leaderboard) -> GameResult {
+        let panel_rect = Rect::new(40.0, 80.0, 330.0, 330.0);
+        let panel = Mesh::new_rectangle(ctx, DrawMode::fill(), panel_rect, Color::new(0.0, 0.0, 0.0, 0.7))?;
+        graphics::draw(ctx, &panel, DrawParam::default())?;
+        
+        let title = texts.get("Best Runs", Color::YELLOW, 28.0);
+        graphics::draw(ctx, title, DrawParam::default().dest([panel_rect.x + 20.0, panel_rect.y + 15.0]))?;
+        
+        for (rank, entry) in leaderboard.entries.iter().enumerate() {
+            let line = texts.get(
+                &format!(
+                    "{:>2}. Flags {:<3} Kills {:<4} Sun {}",
+                    rank + 1, entry.flags_survived, entry.zombies_killed, entry.sun_produced
+                ),
+                Color::WHITE,
+                18.0,
+            );
+            graphics::draw(
+                ctx,
+                line,
+                DrawParam::default().dest([panel_rect.x + 20.0, panel_rect.y + 60.0 + rank as f32 * 26.0])
+            )?;
+        }
+        
+        Ok(())
+    }
+    
+    /// 绘制失败、胜利或暂停画面中的菜单按钮（如"重新开始"）。
+    ///
+    /// 按钮使用与暂停按钮相同的背景图片，显示在屏幕中央消息的下方。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 包含按钮图像的 `Resources` 实例。
+    /// * `texts` - 文字缓存。
+    /// * `label` - 按钮上显示的文字。
+    /// * `rect` - 按钮的位置和尺寸 (x, y, width, height)。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    fn draw_menu_button(ctx: &mut Context, resources: &Resources, texts: &mut TextCache, label: &str, rect: (f32, f32, f32, f32)) -> GameResult {
+        let (x, y, w, h) = rect;
         
         graphics::draw(
             ctx,
-            &pause_text,
+            resources.image("button"),
+            DrawParam::default()
+                .dest([x, y])
+                .scale([w / resources.image("button").width() as f32, h / resources.image("button").height() as f32])
+        )?;
+        
+        let label_text = texts.get(label, Color::BLACK, 24.0);
+        
+        let text_width = label_text.width(ctx);
+        let text_height = label_text.height(ctx);
+        
+        graphics::draw(
+            ctx,
+            label_text,
             DrawParam::default().dest([
-                screen_size.0 / 2.0 - text_width / 2.0,
-                screen_size.1 / 2.0 - text_height / 2.0,
+                x + w / 2.0 - text_width / 2.0,
+                y + h / 2.0 - text_height / 2.0,
             ])
         )?;
         
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -2,180 +2,716 @@
 //!
 //! `resources` 模块负责加载和管理游戏所需的各种资源，如图片、字体等。
 //! 它提供了一个统一的接口来访问这些资源，简化了游戏其他部分的资源处理逻辑。
+//!
+//! 要加载哪些图像由资源目录下的清单 `assets.toml` 决定（见 `asset_manifest` 模块）。
+//! 加载后的贴图按名称存储，动画的帧数以实际加载到的图像为准，播放帧率、绘制原点和缩放也来自清单。
+//! GIF 动画会被解码成帧序列，并按文件中每帧的延迟播放。
+//! 每个贴图加载后，实际的帧数和每帧时长会同步到同名的动画片段（见 `animation` 模块），实体通过 `animator_frame` 绘制当前帧。
+//!
+//! 贴图不必一次全部加载：调用者用 `Resources::request` 把要用的贴图放进队列，
+//! 再在每一帧调用 `Resources::load_pending` 在给定的时间预算内加载一部分，同时用 `progress` 绘制加载进度。
+//! 游戏启动时只加载界面和植物的贴图，各关卡的僵尸贴图在进入关卡时才加载。
+//!
+//! 开发模式下，改动过的图像文件和资源清单可以通过 `reload_file` 和 `reload_manifest` 在运行时重新加载。
+//!
+//! 找不到的贴图或帧以启动时生成的棋盘格图像代替，游戏不会因为缺少某张图片而崩溃。
+//! 每一批贴图加载完成后会汇总打印缺失的素材；只有清单中标记为 `required` 的素材缺失时，加载才会返回 `AssetError`。
 
-use ggez::graphics::Image;
-use ggez::{Context, GameResult};
+use crate::core::animation::{self, Animator};
+use crate::core::asset_manifest::{AssetManifest, PlaceholderShape, SpriteEntry};
+use crate::core::gif_animation;
+use crate::core::registry;
+use ggez::graphics::{DrawParam, Image};
+use ggez::{filesystem, Context, GameResult};
+use ggez::GameError;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
 use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// 资源目录，图像路径和资源清单都相对于此目录。
+pub const RESOURCE_DIR: &str = "Resource";
+
+/// 棋盘格占位图的边长（像素）。
+const CHECKERBOARD_SIZE: u16 = 64;
+/// 棋盘格每一格的边长（像素）。
+const CHECKERBOARD_CELL: u16 = 8;
+/// 棋盘格的两种颜色：醒目的品红和黑色，一眼就能看出素材缺失。
+const CHECKERBOARD_COLORS: [[u8; 4]; 2] = [[255, 0, 255, 255], [0, 0, 0, 255]];
+
+/// 一项缺失或无法加载的素材。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingAsset {
+    /// 用到该素材的贴图名称。
+    pub sprite: String,
+    /// 相对资源目录的文件路径。
+    pub path: String,
+    /// 缺失的原因，例如文件不存在或解码失败的错误信息。
+    pub reason: String,
+}
+
+impl fmt::Display for MissingAsset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.sprite, self.path, self.reason)
+    }
+}
+
+/// 加载游戏资源时的错误。
+#[derive(Debug)]
+pub enum AssetError {
+    /// 资源清单无法读取或不合法。
+    Manifest(GameError),
+    /// 清单中标记为 `required` 的素材缺失，附带所有缺失的必需素材。
+    RequiredMissing(Vec<MissingAsset>),
+    /// 图形后端无法创建图像。
+    Graphics(GameError),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::Manifest(e) => write!(f, "failed to load asset manifest: {}", e),
+            AssetError::RequiredMissing(missing) => {
+                write!(f, "{} required asset(s) missing", missing.len())?;
+                for asset in missing {
+                    write!(f, "; {}", asset)?;
+                }
+                Ok(())
+            }
+            AssetError::Graphics(e) => write!(f, "failed to create image: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+impl From<AssetError> for GameError {
+    fn from(e: AssetError) -> GameError {
+        GameError::ResourceLoadError(e.to_string())
+    }
+}
+
+/// 一个已加载的贴图：动画帧序列及其播放和绘制参数。
+pub struct Sprite {
+    /// 动画帧，单张图像存储为只有一帧的序列。缺失的帧以棋盘格占位图代替，因此至少有一帧。
+    pub frames: Vec<Image>,
+    /// 每帧的显示时长（毫秒），与 `frames` 一一对应。GIF 动画来自文件，其余按清单中的帧率匀速播放。
+    pub frame_durations: Vec<u64>,
+    /// 绘制原点，以图像宽高的比例表示。
+    pub origin: [f32; 2],
+    /// 在草坪上绘制时的缩放。
+    pub scale: [f32; 2],
+    /// 加载时读取（或尝试读取）的文件，相对资源目录，用于热重载时找出受影响的贴图。
+    pub sources: Vec<String>,
+}
+
+impl Sprite {
+    /// 使用默认播放和绘制参数包装从 `sources` 加载的一组帧。
+    fn with_frames(frames: Vec<Image>, sources: Vec<String>) -> Sprite {
+        let frame_durations = vec![animation::frame_duration(10.0); frames.len()];
+        Sprite { frames, frame_durations, origin: [0.0, 0.0], scale: [1.0, 1.0], sources }
+    }
+
+    /// 实际加载到的帧数。
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// 该贴图的绘制参数（原点和缩放），调用者再设置绘制位置。
+    pub fn draw_param(&self) -> DrawParam {
+        DrawParam::default().offset(self.origin).scale(self.scale)
+    }
+}
 
 /// 存储所有游戏资源，如图像和字体。
 ///
-/// 这个结构体在游戏启动时被实例化，并加载所有必要的视觉资源。
-/// 之后，游戏的其他部分可以通过这个结构体来访问这些资源。
+/// 这个结构体在游戏启动时被实例化，随后按资源清单分批加载视觉资源。
+/// 游戏的其他部分通过贴图名称（例如 `"peashooter"`、`PlantSpec::card`）访问这些资源，尚未加载的贴图显示为棋盘格。
 pub struct Resources {
-    // 背景和UI元素
-    /// 游戏主背景图片。
-    pub background: Image,
-    /// 商店界面的背景图片。
-    pub shop_image: Image,
-    /// 暂停按钮图片。
-    pub button_image: Image,
-    /// 太阳的动画帧序列。
-    pub sun_images: Vec<Image>,
-    /// 铲子图片。
-    pub shovel_image: Image,
-    /// 铲子框图片。
-    pub shovel_bank_image: Image,
-
-    // 植物相关图像
-    /// 豌豆射手的动画帧序列。
-    pub peashooter_images: Vec<Image>,
-    /// 向日葵的动画帧序列。
-    pub sunflower_images: Vec<Image>,
-    /// 坚果墙的动画帧序列。
-    pub wallnut_images: Vec<Image>,
-    /// 商店中豌豆射手的卡片图像。
-    pub peashooter_card: Image,
-    /// 商店中向日葵的卡片图像。
-    pub sunflower_card: Image,
-    /// 商店中坚果墙的卡片图像。
-    pub wallnut_card: Image,
-
-    // 僵尸相关图像
-    /// 普通僵尸行走动画帧序列。
-    pub zombies_walk1_images: Vec<Image>,
-    /// 普通僵尸攻击动画帧序列。
-    pub zombie_attack_images: Vec<Image>,
-    /// 普通僵尸死亡动画帧序列。
-    pub zombie_die_images: Vec<Image>,
-    /// 普通僵尸头部掉落动画帧序列。
-    pub zombie_head_images: Vec<Image>,
-    // 路障僵尸图像
-    /// 路障僵尸行走动画帧序列。
-    pub cone_zombie_walk_images: Vec<Image>,
-    /// 路障僵尸攻击动画帧序列。
-    pub cone_zombie_attack_images: Vec<Image>,
-
-    // 豌豆相关图像
-    /// 普通豌豆的图像。
-    pub pea_image: Image,
-    // /// 寒冰豌豆的图像。 (暂未使用)
-    // pub pea_snow_image: Image,
+    /// 按名称存储的已加载贴图，包括资源清单中的条目和注册表中的贴图键。
+    sprites: HashMap<String, Sprite>,
+    /// 资源清单。
+    manifest: AssetManifest,
+    /// 棋盘格占位图和目前为止缺失的素材。
+    loader: Loader,
+    /// 等待加载的贴图名称。
+    pending: VecDeque<String>,
+    /// 当前这一批要加载的贴图总数。
+    batch_total: usize,
+    /// `loader.missing` 中已经打印过报告的条目数。
+    reported: usize,
 
     // 字体 (可选, 如果需要自定义文本渲染)
     // pub font: Font,
 }
 
-/// 加载一系列动画帧的辅助函数。
+/// 加载资源时共享的状态：棋盘格占位图和已经发现的缺失素材。
+struct Loader {
+    /// 棋盘格占位图，代替缺失的贴图和帧。
+    checkerboard: Image,
+    /// 目前为止缺失或无法加载的素材。
+    missing: Vec<MissingAsset>,
+}
+
+impl Loader {
+    /// 记录一项缺失的素材。
+    fn report(&mut self, sprite: &str, path: &str, reason: impl ToString) {
+        self.missing.push(MissingAsset { sprite: sprite.to_string(), path: path.to_string(), reason: reason.to_string() });
+    }
+
+    /// 加载一张图像，文件不存在或无法加载时记录缺失并返回 `None`。
+    fn load_image(&mut self, ctx: &mut Context, sprite: &str, path: &str) -> Option<Image> {
+        if !resource_exists(path) {
+            self.report(sprite, path, "file not found");
+            return None;
+        }
+        match Image::new(ctx, path) {
+            Ok(img) => Some(img),
+            Err(e) => {
+                self.report(sprite, path, e);
+                None
+            }
+        }
+    }
+
+    /// 按顺序加载一组图像路径。
+    ///
+    /// 缺失的帧以棋盘格占位图代替，保持动画的帧数和节奏；全部缺失时只返回一帧棋盘格。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `paths` - 相对资源目录的图像路径。
+    /// * `sprite` - 贴图名称，用于缺失素材报告。
+    ///
+    /// # Returns
+    ///
+    /// 返回帧序列，至少包含一帧。
+    fn load_frames(&mut self, ctx: &mut Context, paths: &[String], sprite: &str) -> Vec<Image> {
+        let loaded: Vec<Option<Image>> = paths.iter().map(|path| self.load_image(ctx, sprite, path)).collect();
+        if loaded.iter().all(Option::is_none) {
+            return vec![self.checkerboard.clone()];
+        }
+        loaded.into_iter().map(|frame| frame.unwrap_or_else(|| self.checkerboard.clone())).collect()
+    }
+
+    /// 加载 GIF 动画，文件不存在或无法解码时记录缺失并返回一帧棋盘格。
+    fn load_gif(&mut self, ctx: &mut Context, path: &str, sprite: &str) -> (Vec<Image>, Vec<u64>) {
+        if !resource_exists(path) {
+            self.report(sprite, path, "file not found");
+            return (vec![self.checkerboard.clone()], Vec::new());
+        }
+        load_gif_frames(ctx, path).unwrap_or_else(|e| {
+            self.report(sprite, path, e);
+            (vec![self.checkerboard.clone()], Vec::new())
+        })
+    }
+
+    /// 按清单条目加载一个贴图的所有帧，占位条目会生成占位图像，GIF 条目会被解码。
+    fn load_entry(&mut self, ctx: &mut Context, name: &str, entry: &SpriteEntry) -> GameResult<Sprite> {
+        let (frames, delays) = match (entry.placeholder, &entry.path) {
+            (Some(placeholder), _) => (
+                vec![match placeholder.shape {
+                    PlaceholderShape::Plant => placeholder_plant_image(ctx, placeholder.color)?,
+                    PlaceholderShape::Card => placeholder_card_image(ctx, placeholder.color)?,
+                    PlaceholderShape::Projectile => placeholder_projectile_image(ctx, placeholder.color)?,
+                }],
+                Vec::new(),
+            ),
+            (None, Some(path)) if entry.is_gif() => self.load_gif(ctx, path, name),
+            (None, _) => (self.load_frames(ctx, &entry.frame_paths(), name), Vec::new()),
+        };
+        let frame_durations = if delays.len() == frames.len() {
+            delays
+        } else {
+            vec![animation::frame_duration(entry.fps); frames.len()]
+        };
+        Ok(Sprite {
+            frames,
+            frame_durations,
+            origin: entry.origin,
+            scale: entry.scale,
+            sources: entry.frame_paths(),
+        })
+    }
+
+    /// 加载资源清单中没有列出的注册表贴图键。
+    ///
+    /// 贴图键被视为 `Resource` 目录下的相对路径：优先加载目录 `{key}/` 中从 1 开始编号的 PNG 帧序列，
+    /// 其次是 GIF 动画 `{key}.gif`，最后是单张图像 `{key}.png`。都不存在时记录缺失并使用棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `key` - 贴图键。
+    ///
+    /// # Returns
+    ///
+    /// 返回加载到的贴图，至少包含一帧。
+    fn load_registered_sprite(&mut self, ctx: &mut Context, key: &str) -> Sprite {
+        let name = key;
+        let key = key.trim_start_matches('/');
+        let frame_dir = Path::new(RESOURCE_DIR).join(key);
+        let mut paths = Vec::new();
+        while frame_dir.join(format!("{}.png", paths.len() + 1)).exists() {
+            paths.push(format!("/{}/{}.png", key, paths.len() + 1));
+        }
+        if !paths.is_empty() {
+            let frames = self.load_frames(ctx, &paths, name);
+            return Sprite::with_frames(frames, paths);
+        }
+
+        let gif = format!("/{}.gif", key);
+        if resource_exists(&gif) {
+            let (frames, delays) = self.load_gif(ctx, &gif, name);
+            let sprite = Sprite::with_frames(frames, vec![gif]);
+            return if delays.len() == sprite.frames.len() { Sprite { frame_durations: delays, ..sprite } } else { sprite };
+        }
+
+        let single = format!("/{}.png", key);
+        let sources = vec![single];
+        Sprite::with_frames(self.load_frames(ctx, &sources, name), sources)
+    }
+}
+
+/// 判断相对资源目录的路径是否存在。
+fn resource_exists(path: &str) -> bool {
+    Path::new(RESOURCE_DIR).join(path.trim_start_matches('/')).exists()
+}
+
+/// 解码一个 GIF 动画并把每一帧上传为图像。
 ///
 /// # Arguments
 ///
 /// * `ctx` - ggez的上下文环境。
-/// * `path_pattern` - 图像文件的路径模式，其中 `{}` 会被帧号替换。
-/// * `frame_count` - 一个表示帧号范围的迭代器 (例如 `1..=10`)。
-/// * `asset_name` - 资源的名称，用于在加载失败时打印警告信息。
+/// * `path` - 相对资源目录的 GIF 路径。
 ///
 /// # Returns
 ///
-/// 返回一个 `GameResult`，其中包含一个 `Vec<Image>` (加载的动画帧) 或者一个错误。
-fn load_animation_frames(
-    ctx: &mut Context,
-    path_pattern: &str,
-    frame_count: std::ops::RangeInclusive<usize>,
-    asset_name: &str,
-) -> GameResult<Vec<Image>> {
-    let mut frames = Vec::new();
-    for i in frame_count {
-        let path = path_pattern.replace("{}", &i.to_string());
-        let full_path = Path::new("Resource").join(path.trim_start_matches('/'));
-        if full_path.exists() {
-            match Image::new(ctx, &path) {
-                Ok(img) => frames.push(img),
-                Err(e) => println!(
-                    "Warning: Failed to load {} image {}: {}",
-                    asset_name, path, e
-                ),
-            }
-        } else {
-            println!("Warning: {} image not found: {}", asset_name, path);
+/// 成功时返回帧序列和每帧的显示时长（毫秒）；文件无法打开或解码时返回错误。
+fn load_gif_frames(ctx: &mut Context, path: &str) -> GameResult<(Vec<Image>, Vec<u64>)> {
+    let file = filesystem::open(ctx, path)?;
+    let decoded = gif_animation::decode_gif(file, path)?;
+    let mut frames = Vec::with_capacity(decoded.len());
+    let mut delays = Vec::with_capacity(decoded.len());
+    for frame in decoded {
+        frames.push(Image::from_rgba8(ctx, frame.width, frame.height, &frame.rgba)?);
+        delays.push(frame.delay_ms);
+    }
+    Ok((frames, delays))
+}
+
+/// 生成棋盘格占位图的像素数据。
+fn checkerboard_pixels() -> Vec<u8> {
+    let size = CHECKERBOARD_SIZE as usize;
+    let cell = CHECKERBOARD_CELL as usize;
+    let mut pixels = Vec::with_capacity(size * size * 4);
+    for y in 0..size {
+        for x in 0..size {
+            pixels.extend_from_slice(&CHECKERBOARD_COLORS[(x / cell + y / cell) % 2]);
         }
     }
-    Ok(frames)
+    pixels
 }
 
+/// 生成棋盘格占位图，代替缺失的贴图和帧。
+fn checkerboard_image(ctx: &mut Context) -> GameResult<Image> {
+    Image::from_rgba8(ctx, CHECKERBOARD_SIZE, CHECKERBOARD_SIZE, &checkerboard_pixels())
+}
+
+/// 占位卡片的宽度，与现有卡片图片的尺寸接近。
+const PLACEHOLDER_CARD_WIDTH: u16 = 63;
+/// 占位卡片的高度。
+const PLACEHOLDER_CARD_HEIGHT: u16 = 70;
+/// 占位卡片的底色。
+const PLACEHOLDER_CARD_BACKGROUND: [u8; 4] = [230, 215, 160, 255];
+
+/// 生成占位图像的像素数据：在 `background` 底色上居中绘制一个实心椭圆。
+///
+/// # Arguments
+///
+/// * `width` - 图像宽度。
+/// * `height` - 图像高度。
+/// * `color` - 椭圆的 RGBA 颜色。
+/// * `background` - 底色，`None` 表示透明。
+///
+/// # Returns
+///
+/// 返回按行排列的 RGBA 像素数据。
+fn placeholder_pixels(width: u16, height: u16, color: [u8; 4], background: Option<[u8; 4]>) -> Vec<u8> {
+    let (w, h) = (width as f32, height as f32);
+    let (rx, ry) = (w * 0.4, h * 0.35);
+    let mut pixels = Vec::with_capacity(width as usize * height as usize * 4);
+
+    for y in 0..height {
+        for x in 0..width {
+            let dx = (x as f32 + 0.5 - w / 2.0) / rx;
+            let dy = (y as f32 + 0.5 - h / 2.0) / ry;
+            let pixel = if dx * dx + dy * dy <= 1.0 {
+                color
+            } else {
+                background.unwrap_or([0, 0, 0, 0])
+            };
+            pixels.extend_from_slice(&pixel);
+        }
+    }
+    pixels
+}
+
+/// 为尚无素材的植物生成占位图像：透明背景上的彩色椭圆。
+fn placeholder_plant_image(ctx: &mut Context, color: [u8; 4]) -> GameResult<Image> {
+    Image::from_rgba8(ctx, 64, 64, &placeholder_pixels(64, 64, color, None))
+}
+
+/// 为尚无素材的投掷物生成占位图像：透明背景上的小彩色椭圆。
+fn placeholder_projectile_image(ctx: &mut Context, color: [u8; 4]) -> GameResult<Image> {
+    Image::from_rgba8(ctx, 24, 24, &placeholder_pixels(24, 24, color, None))
+}
+
+/// 为尚无素材的植物生成占位卡片：卡片底色上的彩色椭圆。
+fn placeholder_card_image(ctx: &mut Context, color: [u8; 4]) -> GameResult<Image> {
+    let pixels = placeholder_pixels(
+        PLACEHOLDER_CARD_WIDTH,
+        PLACEHOLDER_CARD_HEIGHT,
+        color,
+        Some(PLACEHOLDER_CARD_BACKGROUND),
+    );
+    Image::from_rgba8(ctx, PLACEHOLDER_CARD_WIDTH, PLACEHOLDER_CARD_HEIGHT, &pixels)
+}
 
 impl Resources {
-    /// 创建并初始化 `Resources` 结构体，加载所有游戏资源。
+    /// 获取贴图名称对应的贴图及其播放和绘制参数。
     ///
     /// # Arguments
     ///
-    /// * `ctx` - ggez的上下文环境。
+    /// * `key` - 贴图名称。
     ///
     /// # Returns
     ///
-    /// 返回一个 `GameResult`，其中包含初始化完成的 `Resources` 实例或者一个错误。
-    pub fn new(ctx: &mut Context) -> GameResult<Resources> {
-        // Load background and UI
-        let background = Image::new(ctx, "/other_image/Background.png")?;
-        let shop_image = Image::new(ctx, "/other_image/Shop.png")?;
-        let button_image = Image::new(ctx, "/other_image/Button.png")?;
+    /// 如果该贴图已加载，返回 `Some(&Sprite)`，否则返回 `None`。
+    pub fn get(&self, key: &str) -> Option<&Sprite> {
+        self.sprites.get(key)
+    }
 
-        // 加载太阳动画帧
-        let sun_images = load_animation_frames(ctx, "/other_image/sun/Sun{}.png", 0..=21, "Sun")?;
+    /// 贴图是否已经加载了真正的素材：不是占位图像，也没有以棋盘格代替的缺失帧。
+    ///
+    /// 素材可选的贴图（例如僵尸的游泳动画）可以据此退回到其他绘制方式。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图名称。
+    pub fn has_artwork(&self, key: &str) -> bool {
+        self.sprites.contains_key(key)
+            && self.manifest.sprites.get(key).is_none_or(|entry| entry.placeholder.is_none())
+            && !self.loader.missing.iter().any(|asset| asset.sprite == key)
+    }
 
-        //加载植物图像
-        let peashooter_images = load_animation_frames(ctx, "/plants/Peashooter/{}.png", 1..=13, "Peashooter")?;
+    /// 获取贴图键对应的图像（动画的第一帧）。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图键，例如 `PlantSpec::card`。
+    ///
+    /// # Returns
+    ///
+    /// 如果该贴图键已加载，返回 `Some(&Image)`，否则返回 `None`。
+    pub fn sprite(&self, key: &str) -> Option<&Image> {
+        self.sprites.get(key).and_then(|sprite| sprite.frames.first())
+    }
 
-        let sunflower_images = load_animation_frames(ctx, "/plants/SunFlower/{}.png", 1..=18, "Sunflower")?;
+    /// 获取贴图键对应的全部动画帧。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图键，例如 `ZombieSpec::walk`。
+    ///
+    /// # Returns
+    ///
+    /// 如果该贴图键已加载，返回帧序列，否则返回 `None`。
+    pub fn animation(&self, key: &str) -> Option<&[Image]> {
+        self.sprites.get(key).map(|sprite| sprite.frames.as_slice())
+    }
 
-        let wallnut_images = load_animation_frames(ctx, "/plants/WallNut/WallnutFull/{}.png", 1..=16, "Wallnut")?;
+    /// 获取贴图实际加载到的帧数，未加载的贴图返回 0。
+    pub fn frame_count(&self, key: &str) -> usize {
+        self.sprites.get(key).map_or(0, Sprite::frame_count)
+    }
 
+    /// 获取贴图的第一帧，找不到时返回棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图名称，例如 `"background"`。
+    pub fn image(&self, key: &str) -> &Image {
+        self.sprite(key).unwrap_or(&self.loader.checkerboard)
+    }
 
-        // Load plant cards
-        let peashooter_card = Image::new(ctx, "/plants/Peashooter.png")?;
-        let sunflower_card = Image::new(ctx, "/plants/SunFlower.png")?;
-        let wallnut_card = Image::new(ctx, "/plants/WallNut.png")?;
+    /// 获取贴图对应的动画片段在播放了 `elapsed_ms` 毫秒时的帧。
+    ///
+    /// 帧序号由同名动画片段的每帧时长和播放方式计算。找不到贴图时返回棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图名称。
+    /// * `elapsed_ms` - 动画已经播放的时间（毫秒）。
+    pub fn frame(&self, key: &str, elapsed_ms: u64) -> &Image {
+        self.frame_at(key, animation::frame_at(key, elapsed_ms))
+    }
 
+    /// 获取动画播放器当前显示的帧，找不到贴图时返回棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `animator` - 实体的动画播放器，其片段名称即贴图名称。
+    pub fn animator_frame(&self, animator: &Animator) -> &Image {
+        self.frame_at(animator.clip_name(), animator.frame())
+    }
 
-        // 加载僵尸图像
-        let zombies_walk1_images = load_animation_frames(ctx, "/zombies/ZombieWalk1/{}.png", 1..=22, "Zombie walk")?;
-        let zombie_attack_images = load_animation_frames(ctx, "/zombies/ZombieAttack/{}.png", 1..=21, "Zombie attack")?;
-        let zombie_die_images = load_animation_frames(ctx, "/zombies/ZombieDie/{}.png", 1..=10, "Zombie die")?;
-        let zombie_head_images = load_animation_frames(ctx, "/zombies/ZombieHead/{}.png", 1..=12, "Zombie head fall")?;
+    /// 获取贴图的第 `index` 帧，超出实际加载到的帧数时使用最后一帧，找不到贴图时返回棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - 贴图名称。
+    /// * `index` - 帧序号。
+    pub fn frame_at(&self, key: &str, index: usize) -> &Image {
+        match self.sprites.get(key) {
+            Some(sprite) if !sprite.frames.is_empty() => &sprite.frames[index.min(sprite.frames.len() - 1)],
+            _ => &self.loader.checkerboard,
+        }
+    }
 
-        // 加载路障僵尸图像
-        let cone_zombie_walk_images = load_animation_frames(ctx, "/zombies/ConeZombieWalk/{}.png", 1..=21, "Cone Zombie walk")?;
-        let cone_zombie_attack_images = load_animation_frames(ctx, "/zombies/ConeZombieAttack/{}.png", 1..=11, "Cone Zombie attack")?;
+    /// 获取贴图在草坪上的绘制参数（清单中的原点和缩放），调用者再设置绘制位置。
+    pub fn draw_param(&self, key: &str) -> DrawParam {
+        self.sprites.get(key).map_or_else(DrawParam::default, Sprite::draw_param)
+    }
 
-        // 加载豌豆图像
-        let pea_image = Image::new(ctx, "/plants/Pea.png")?;
-        // let pea_snow_image = Image::new(ctx, "/plants/PeaSnow.png")?;
+    /// 获取目前为止缺失或无法加载的素材，这些素材以棋盘格占位图代替。
+    pub fn missing_assets(&self) -> &[MissingAsset] {
+        &self.loader.missing
+    }
 
-        // 加载铲子相关图像
-        let shovel_image = Image::new(ctx, "/other_image/Shovel.png")?;
-        let shovel_bank_image = Image::new(ctx, "/other_image/ShovelBank.png")?;
+    /// 列出资源清单和注册表中的所有贴图名称。
+    pub fn sprite_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.manifest.sprites.keys().cloned().collect();
+        for key in registry::sprite_keys() {
+            if !self.manifest.sprites.contains_key(key) {
+                names.push(key.to_string());
+            }
+        }
+        names
+    }
+
+    /// 获取当前这一批贴图的加载进度。
+    ///
+    /// # Returns
+    ///
+    /// 返回 `(已加载数量, 总数)`。没有等待加载的贴图时两者相等。
+    pub fn progress(&self) -> (usize, usize) {
+        (self.batch_total - self.pending.len(), self.batch_total)
+    }
+
+    /// 判断所有请求的贴图是否都已加载。
+    pub fn is_ready(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// 把贴图放进加载队列，已经加载或已在队列中的贴图会被忽略。
+    ///
+    /// 上一批贴图全部加载完成后再请求的贴图开始新的一批，进度从零开始计算。
+    ///
+    /// # Arguments
+    ///
+    /// * `names` - 要加载的贴图名称。
+    pub fn request<I, S>(&mut self, names: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        if self.pending.is_empty() {
+            self.batch_total = 0;
+        }
+        for name in names {
+            let name = name.as_ref();
+            if !self.sprites.contains_key(name) && !self.pending.iter().any(|pending| pending == name) {
+                self.pending.push_back(name.to_string());
+                self.batch_total += 1;
+            }
+        }
+    }
 
+    /// 在时间预算内加载队列中的贴图，每次调用至少加载一个。
+    ///
+    /// 一批贴图全部加载完成时打印这批中缺失的素材；其中有清单标记为 `required` 的素材时返回错误。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `budget` - 本次调用最多花费的时间。
+    ///
+    /// # Returns
+    ///
+    /// 加载成功返回 `Ok(())`；缺少必需素材或无法创建图像时返回对应的 `AssetError`。
+    pub fn load_pending(&mut self, ctx: &mut Context, budget: Duration) -> Result<(), AssetError> {
+        let start = Instant::now();
+        while let Some(name) = self.pending.pop_front() {
+            self.load_sprite(ctx, name)?;
+            if start.elapsed() >= budget {
+                break;
+            }
+        }
+
+        if self.pending.is_empty() {
+            self.finish_batch()?;
+        }
+        Ok(())
+    }
+
+    /// 按资源清单（清单中没有时按注册表的约定路径）加载一个贴图，替换已加载的同名贴图。
+    fn load_sprite(&mut self, ctx: &mut Context, name: String) -> Result<(), AssetError> {
+        let sprite = match self.manifest.sprites.get(&name) {
+            Some(entry) => self.loader.load_entry(ctx, &name, entry).map_err(AssetError::Graphics)?,
+            None => self.loader.load_registered_sprite(ctx, &name),
+        };
+        animation::set_frame_durations(&name, sprite.frame_durations.clone());
+        self.sprites.insert(name, sprite);
+        Ok(())
+    }
+
+    /// 立即重新加载一组已加载的贴图，并报告其中缺失的素材。
+    fn reload_sprites(&mut self, ctx: &mut Context, names: &[String]) -> Result<(), AssetError> {
+        // 之前报告过的缺失素材可能已经补上，这些贴图的缺失情况重新统计
+        let unreported = self.loader.missing.split_off(self.reported);
+        self.loader.missing.retain(|asset| !names.contains(&asset.sprite));
+        self.reported = self.loader.missing.len();
+        self.loader.missing.extend(unreported);
+
+        for name in names {
+            self.load_sprite(ctx, name.clone())?;
+        }
+        self.finish_batch()
+    }
+
+    /// 重新加载用到某个文件的所有已加载贴图，供开发模式下的热重载使用。
+    ///
+    /// 除了加载时读取过该文件的贴图，注册表贴图键的帧目录中新增的帧也会让对应的贴图重新加载。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `path` - 改动的文件，相对资源目录，例如 `"/plants/Peashooter/3.png"`。
+    ///
+    /// # Returns
+    ///
+    /// 返回重新加载的贴图名称；没有贴图用到该文件时返回空列表。缺少必需素材或无法创建图像时返回 `AssetError`。
+    pub fn reload_file(&mut self, ctx: &mut Context, path: &str) -> Result<Vec<String>, AssetError> {
+        let mut names: Vec<String> = self
+            .sprites
+            .iter()
+            .filter(|(name, sprite)| {
+                let frame_dir = format!("/{}/", name.trim_start_matches('/'));
+                sprite.sources.iter().any(|source| source == path)
+                    || (!self.manifest.sprites.contains_key(*name) && path.starts_with(&frame_dir))
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        self.reload_sprites(ctx, &names)?;
+        Ok(names)
+    }
+
+    /// 重新读取资源清单，并重新加载条目有变化的已加载贴图，供开发模式下的热重载使用。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    ///
+    /// # Returns
+    ///
+    /// 返回重新加载的贴图名称。新的清单无法读取或不合法时返回错误，并保留当前的清单和贴图。
+    pub fn reload_manifest(&mut self, ctx: &mut Context) -> Result<Vec<String>, AssetError> {
+        let manifest = AssetManifest::load(Path::new(RESOURCE_DIR)).map_err(AssetError::Manifest)?;
+        let mut names: Vec<String> = self
+            .sprites
+            .keys()
+            .filter(|name| self.manifest.sprites.get(*name) != manifest.sprites.get(*name))
+            .cloned()
+            .collect();
+        names.sort();
+        // 动画片段按新的清单重建，没有重新加载的贴图保留实际加载到的帧数和每帧时长
+        animation::install_manifest(&manifest);
+        for (name, sprite) in &self.sprites {
+            animation::set_frame_durations(name, sprite.frame_durations.clone());
+        }
+        self.manifest = manifest;
+        self.reload_sprites(ctx, &names)?;
+        Ok(names)
+    }
+
+    /// 打印这一批中缺失的素材，缺少必需素材时返回错误。
+    fn finish_batch(&mut self) -> Result<(), AssetError> {
+        let missing = &self.loader.missing[self.reported..];
+        self.reported = self.loader.missing.len();
+        if missing.is_empty() {
+            return Ok(());
+        }
+
+        println!("Warning: {} asset(s) missing, using placeholder textures:", missing.len());
+        for asset in missing {
+            println!("  - {}", asset);
+        }
+
+        let required: Vec<MissingAsset> = missing
+            .iter()
+            .filter(|asset| self.manifest.sprites.get(&asset.sprite).is_some_and(|entry| entry.required))
+            .cloned()
+            .collect();
+        if required.is_empty() {
+            Ok(())
+        } else {
+            Err(AssetError::RequiredMissing(required))
+        }
+    }
+
+    /// 创建还没有加载任何贴图的 `Resources`：读取资源清单并生成棋盘格占位图。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    ///
+    /// # Returns
+    ///
+    /// 资源清单无法读取或不合法、或者无法创建占位图时返回对应的 `AssetError`。
+    pub fn empty(ctx: &mut Context) -> Result<Resources, AssetError> {
+        let manifest = AssetManifest::load(Path::new(RESOURCE_DIR)).map_err(AssetError::Manifest)?;
+        let checkerboard = checkerboard_image(ctx).map_err(AssetError::Graphics)?;
+        animation::install_manifest(&manifest);
         Ok(Resources {
-            background,
-            shop_image,
-            button_image,
-            sun_images,
-            shovel_image,
-            shovel_bank_image,
-            peashooter_images,
-            sunflower_images,
-            wallnut_images,
-            peashooter_card,
-            sunflower_card,
-            wallnut_card,
-            zombies_walk1_images,
-            zombie_attack_images,
-            zombie_die_images,
-            zombie_head_images,
-            cone_zombie_walk_images,
-            cone_zombie_attack_images,
-            pea_image,
-            // pea_snow_image,
-            // Assign other potentially unloaded Vecs as empty or handle appropriately
+            sprites: HashMap::new(),
+            manifest,
+            loader: Loader { checkerboard, missing: Vec::new() },
+            pending: VecDeque::new(),
+            batch_total: 0,
+            reported: 0,
         })
     }
+
+    /// 创建并初始化 `Resources` 结构体，一次性加载资源清单和注册表中的所有贴图。
+    ///
+    /// 注册表中清单没有列出的贴图键按约定的路径加载。找不到的图像或帧以棋盘格占位图代替，
+    /// 所有缺失的素材会汇总打印为一份报告。需要显示加载进度时改用 `empty`、`request` 和 `load_pending`。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    ///
+    /// # Returns
+    ///
+    /// 返回初始化完成的 `Resources` 实例。资源清单无法读取或不合法、清单中标记为 `required` 的素材缺失，
+    /// 或者无法创建图像时返回对应的 `AssetError`。
+    pub fn new(ctx: &mut Context) -> Result<Resources, AssetError> {
+        let mut resources = Resources::empty(ctx)?;
+        resources.request(resources.sprite_names());
+        resources.load_pending(ctx, Duration::MAX)?;
+        Ok(resources)
+    }
 }
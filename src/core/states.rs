@@ -8,10 +8,22 @@
 /// 这个枚举用于控制游戏的主循环，根据当前状态执行不同的逻辑和渲染。
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameState {
+    /// 资源加载状态。
+    ///
+    /// 在此状态下分批加载贴图并显示加载进度条，加载完成后进入下一个阶段。
+    Loading,
     /// 游戏主菜单状态。
     ///
     /// 在此状态下，通常会显示游戏标题、开始游戏选项、设置等。
     Menu,
+    /// 冒险模式选关状态。
+    ///
+    /// 在此状态下显示所有关卡及其解锁、完成情况，玩家点击关卡后进入游戏。
+    LevelSelect,
+    /// 关卡开始前的选卡状态。
+    ///
+    /// 在此状态下玩家从已解锁的植物中挑选要带入关卡的卡片，同时草坪滚动展示本关的僵尸。
+    SeedSelect,
     /// 游戏进行中状态。
     ///
     /// 这是游戏的核心状态，玩家在此状态下进行植物的种植、抵御僵尸的进攻等操作。
@@ -0,0 +1,289 @@
+//! # 数值调校模块 (`tuning`)
+//!
+//! `tuning` 模块集中管理游戏的平衡数值：初始阳光、自然阳光的掉落节奏、豌豆的速度和伤害，
+//! 以及按注册表标识覆盖的植物和僵尸属性（生命值、花费、卡片冷却、动作间隔、移动速度和啃食属性）。
+//!
+//! 数值从资源目录下的 `tuning.toml` 读取，文件中没有写出的项使用编译时的默认值。
+//! 文件在读取后会经过校验，校验失败时保留当前数值并给出错误。调试时可以按 F5 重新读取文件，
+//! 策划无需修改代码即可调整平衡。
+//!
+//! 重新读取后，新的植物规格和僵尸规格会立即生效；已经在场上的植物和僵尸保留它们出生时的生命值和攻击属性。
+
+use crate::core::registry::{self, PlantSpec, ZombieSpec};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{LazyLock, RwLock, RwLockReadGuard};
+
+/// 调校文件的文件名。
+pub const TUNING_FILE_NAME: &str = "tuning.toml";
+
+/// 当前生效的调校数值，启动时为编译时的默认值。
+static TUNING: LazyLock<RwLock<Tuning>> = LazyLock::new(|| RwLock::new(Tuning::default()));
+
+/// 游戏的全部平衡数值。
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Tuning {
+    /// 无尽模式和默认游戏开局时的阳光数量。冒险模式的关卡使用各自定义的初始阳光。
+    pub initial_sun: i32,
+    /// 自然阳光的掉落节奏。
+    pub sun: SunTuning,
+    /// 普通豌豆（以及火焰豌豆的飞行速度）。
+    pub pea: PeaTuning,
+    /// 按植物标识 (`PlantSpec::id`) 覆盖的植物属性。
+    pub plants: BTreeMap<String, PlantTuning>,
+    /// 按僵尸标识 (`ZombieSpec::id`) 覆盖的僵尸属性。
+    pub zombies: BTreeMap<String, ZombieTuning>,
+}
+
+/// 自然阳光的掉落节奏，单位均为厘秒。
+///
+/// 第一份阳光在 `first_delay + rand(0, random_delay)` 之后掉落，
+/// 之后每份阳光的等待时间为 `min(已掉落数量 × delay_step + first_delay, max_delay) + rand(0, random_delay)`。
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SunTuning {
+    /// 基础等待时间。
+    pub first_delay: u32,
+    /// 每次等待额外增加的随机时间上限。
+    pub random_delay: u32,
+    /// 每掉落一份阳光，基础等待时间增加的量。
+    pub delay_step: u32,
+    /// 基础等待时间的上限。
+    pub max_delay: u32,
+}
+
+/// 普通豌豆的数值。
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PeaTuning {
+    /// 飞行速度（像素/毫秒），火焰豌豆也使用此速度。
+    pub speed: f32,
+    /// 对僵尸造成的伤害。
+    pub damage: i32,
+}
+
+/// 对一种植物的属性覆盖，未写出的项使用注册表中的规格。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlantTuning {
+    /// 初始生命值。
+    pub health: Option<i32>,
+    /// 种植所需的阳光花费。
+    pub cost: Option<i32>,
+    /// 商店卡片在种植后的冷却时间（毫秒）。
+    pub recharge_ms: Option<u64>,
+    /// 植物动作（射击、产生阳光等）的间隔（毫秒），覆盖 `PlantTrait::get_cooldown`。
+    pub action_cooldown_ms: Option<u64>,
+}
+
+/// 对一种僵尸的属性覆盖，未写出的项使用注册表中的规格。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ZombieTuning {
+    /// 初始生命值。
+    pub health: Option<i32>,
+    /// 移动速度（像素/毫秒）。
+    pub speed: Option<f32>,
+    /// 单次啃食造成的伤害。
+    pub attack_damage: Option<i32>,
+    /// 两次啃食之间的间隔（毫秒）。
+    pub attack_interval: Option<u64>,
+}
+
+impl Default for Tuning {
+    /// 编译时的默认数值，与没有调校文件时的游戏行为一致。
+    fn default() -> Self {
+        Tuning {
+            initial_sun: 50,
+            sun: SunTuning::default(),
+            pea: PeaTuning::default(),
+            plants: BTreeMap::new(),
+            zombies: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for SunTuning {
+    fn default() -> Self {
+        SunTuning { first_delay: 425, random_delay: 275, delay_step: 10, max_delay: 950 }
+    }
+}
+
+impl Default for PeaTuning {
+    fn default() -> Self {
+        PeaTuning { speed: 0.3, damage: 20 }
+    }
+}
+
+/// 读取或校验调校文件时的错误。
+#[derive(Debug)]
+pub enum TuningError {
+    /// 无法读取调校文件。
+    Io(io::Error),
+    /// 调校文件不是合法的 TOML，或包含未知的字段。
+    Parse(toml::de::Error),
+    /// 调校文件中的数值不合法，附带说明。
+    Invalid(String),
+}
+
+impl fmt::Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TuningError::Io(e) => write!(f, "failed to read tuning file: {}", e),
+            TuningError::Parse(e) => write!(f, "failed to parse tuning file: {}", e),
+            TuningError::Invalid(reason) => write!(f, "invalid tuning value: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for TuningError {}
+
+/// 校验条件不满足时返回 `TuningError::Invalid`。
+fn check(condition: bool, reason: impl FnOnce() -> String) -> Result<(), TuningError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(TuningError::Invalid(reason()))
+    }
+}
+
+impl Tuning {
+    /// 从 TOML 文本解析调校数值并进行校验。
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - 调校文件的内容。
+    ///
+    /// # Returns
+    ///
+    /// 解析并校验成功返回 `Tuning`，否则返回对应的 `TuningError`。
+    pub fn from_toml(content: &str) -> Result<Tuning, TuningError> {
+        let tuning: Tuning = toml::from_str(content).map_err(TuningError::Parse)?;
+        tuning.validate()?;
+        Ok(tuning)
+    }
+
+    /// 检查所有数值是否在合理范围内，以及覆盖的植物和僵尸标识是否已经注册。
+    ///
+    /// # Returns
+    ///
+    /// 全部合法返回 `Ok(())`，否则返回描述第一个问题的 `TuningError::Invalid`。
+    pub fn validate(&self) -> Result<(), TuningError> {
+        check(self.initial_sun >= 0, || format!("initial_sun must not be negative (got {})", self.initial_sun))?;
+        check(self.sun.first_delay > 0, || "sun.first_delay must be positive".to_string())?;
+        check(self.sun.max_delay >= self.sun.first_delay, || {
+            format!("sun.max_delay ({}) must not be below sun.first_delay ({})", self.sun.max_delay, self.sun.first_delay)
+        })?;
+        check(self.pea.speed.is_finite() && self.pea.speed > 0.0, || {
+            format!("pea.speed must be positive (got {})", self.pea.speed)
+        })?;
+        check(self.pea.damage > 0, || format!("pea.damage must be positive (got {})", self.pea.damage))?;
+
+        for (id, plant) in &self.plants {
+            check(registry::plant_by_id(id).is_some(), || format!("unknown plant '{}'", id))?;
+            if let Some(health) = plant.health {
+                check(health > 0, || format!("plants.{}.health must be positive (got {})", id, health))?;
+            }
+            if let Some(cost) = plant.cost {
+                check(cost >= 0, || format!("plants.{}.cost must not be negative (got {})", id, cost))?;
+            }
+            if let Some(recharge) = plant.recharge_ms {
+                check(recharge > 0, || format!("plants.{}.recharge_ms must be positive", id))?;
+            }
+            if let Some(cooldown) = plant.action_cooldown_ms {
+                check(cooldown > 0, || format!("plants.{}.action_cooldown_ms must be positive", id))?;
+            }
+        }
+
+        for (id, zombie) in &self.zombies {
+            check(registry::zombie_by_id(id).is_some(), || format!("unknown zombie '{}'", id))?;
+            if let Some(health) = zombie.health {
+                check(health > 0, || format!("zombies.{}.health must be positive (got {})", id, health))?;
+            }
+            if let Some(speed) = zombie.speed {
+                check(speed.is_finite() && speed >= 0.0, || {
+                    format!("zombies.{}.speed must not be negative (got {})", id, speed)
+                })?;
+            }
+            if let Some(damage) = zombie.attack_damage {
+                check(damage >= 0, || format!("zombies.{}.attack_damage must not be negative (got {})", id, damage))?;
+            }
+            if let Some(interval) = zombie.attack_interval {
+                check(interval > 0, || format!("zombies.{}.attack_interval must be positive", id))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 将对该植物的覆盖应用到注册表规格上。
+    pub fn apply_to_plant(&self, spec: &mut PlantSpec) {
+        if let Some(plant) = self.plants.get(spec.id) {
+            spec.health = plant.health.unwrap_or(spec.health);
+            spec.cost = plant.cost.unwrap_or(spec.cost);
+            spec.recharge_ms = plant.recharge_ms.unwrap_or(spec.recharge_ms);
+        }
+    }
+
+    /// 将对该僵尸的覆盖应用到注册表规格上。
+    pub fn apply_to_zombie(&self, spec: &mut ZombieSpec) {
+        if let Some(zombie) = self.zombies.get(spec.id) {
+            spec.health = zombie.health.unwrap_or(spec.health);
+            spec.speed = zombie.speed.unwrap_or(spec.speed);
+            spec.attack_damage = zombie.attack_damage.unwrap_or(spec.attack_damage);
+            spec.attack_interval = zombie.attack_interval.unwrap_or(spec.attack_interval);
+        }
+    }
+
+    /// 获取某种植物被覆盖的动作间隔（毫秒），没有覆盖时返回 `None`。
+    pub fn action_cooldown(&self, plant_id: &str) -> Option<u64> {
+        self.plants.get(plant_id).and_then(|plant| plant.action_cooldown_ms)
+    }
+}
+
+/// 获取当前生效的调校数值。
+///
+/// 返回的读锁应尽快释放，不要在持有期间调用 [`install`] 或 [`load`]。
+pub fn current() -> RwLockReadGuard<'static, Tuning> {
+    TUNING.read().unwrap()
+}
+
+/// 校验并启用一组调校数值。
+///
+/// # Arguments
+///
+/// * `tuning` - 新的调校数值。
+///
+/// # Returns
+///
+/// 校验成功后返回 `Ok(())`；否则返回错误，当前数值保持不变。
+pub fn install(tuning: Tuning) -> Result<(), TuningError> {
+    tuning.validate()?;
+    *TUNING.write().unwrap() = tuning;
+    Ok(())
+}
+
+/// 从指定目录读取 `tuning.toml` 并启用其中的数值。
+///
+/// 文件不存在时恢复编译时的默认值。读取、解析或校验失败时保留当前数值。
+///
+/// # Arguments
+///
+/// * `dir` - 存放调校文件的目录（通常是资源目录 `Resource`）。
+///
+/// # Returns
+///
+/// 启用成功返回 `Ok(())`，否则返回对应的 `TuningError`。
+pub fn load(dir: &Path) -> Result<(), TuningError> {
+    let path = dir.join(TUNING_FILE_NAME);
+    if !path.exists() {
+        return install(Tuning::default());
+    }
+    let content = fs::read_to_string(&path).map_err(TuningError::Io)?;
+    install(Tuning::from_toml(&content)?)
+}
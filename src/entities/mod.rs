@@ -4,10 +4,13 @@
 //! 这些实体通常具有自己的行为、属性和视觉表现。
 //!
 //! ## 主要实体类型：
-//! - **豌豆 (`pea`)**: 由豌豆射手发射的子弹，用于攻击僵尸。
+//! - **投射物 (`projectile`)**: 由植物发射、用于攻击僵尸的豌豆、尖刺、星星以及沿抛物线飞行的投掷物等，共用同一套更新和碰撞逻辑。
 //! - **阳光 (`sun`)**: 游戏中的主要资源，用于购买植物。阳光可以自然生成，也可以由向日葵产生。
+//! - **奖励卡片 (`reward`)**: 关卡胜利后掉落的种子包，领取后解锁新植物。
 
-/// 豌豆实体模块，定义了豌豆的属性和行为。
-pub mod pea;
+/// 投射物模块，定义了所有投射物共用的数据模型和行为。
+pub mod projectile;
 /// 阳光实体模块，定义了阳光的属性和行为。
-pub mod sun;
\ No newline at end of file
+pub mod sun;
+/// 奖励卡片实体模块，定义了关卡胜利后掉落的种子包。
+pub mod reward;
\ No newline at end of file
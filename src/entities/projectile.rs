@@ -0,0 +1,513 @@
+//! # 投射物模块 (`projectile`)
+//!
+//! 定义了游戏中所有投射物（豌豆、寒冰豌豆、火焰豌豆、尖刺、星星、投手类植物的抛物线投掷物以及僵尸投出的篮球）
+//! 共用的数据模型。每个投射物由以下属性描述：
+//! - **类型 (`ProjectileKind`)**: 决定默认的速度、伤害、穿透次数、溅射半径、命中效果和图像。
+//! - **飞行方式 (`Flight`)**: 沿行直线飞行、按速度向量自由飞行，或沿抛物线飞向落点。
+//! - **命中效果 (`HitEffect`)**: 减速、燃烧、定身或打掉僵尸的护甲。
+//!
+//! 所有投射物共用同一套更新逻辑（`Projectile::update`）和碰撞逻辑
+//! （`CollisionManager::handle_projectile_collision`）。
+
+use ggez::graphics::{Image, Rect};
+use crate::core::render_cache::SpriteBatcher;
+use crate::core::resources::Resources;
+use crate::core::tuning;
+use crate::ui::lawn::LawnGeometry;
+use crate::zombies::{HitKind, Zombie};
+
+/// 直线飞行的投射物能越过的最大地形升高（像素），超过时投射物会撞上屋顶的斜坡。
+const SLOPE_CLEARANCE: f32 = 30.0;
+/// 抛物线投掷物从发射到落地的飞行时间（毫秒）。
+pub const LOB_FLIGHT_TIME: u64 = 1200;
+/// 抛物线最高点相对于起点和终点连线的高度（像素）。
+const LOB_ARC_HEIGHT: f32 = 120.0;
+/// 黄油定身僵尸的时间（毫秒）。
+pub const BUTTER_STUN_DURATION: u64 = 4000;
+/// 寒冰豌豆使僵尸减速的时间（毫秒）。
+pub const SNOW_SLOW_DURATION: u64 = 10000;
+/// 僵尸碰撞矩形中心相对于僵尸X坐标的偏移，抛物线投掷物瞄准这个位置。
+const ZOMBIE_AIM_OFFSET_X: f32 = 50.0;
+/// 直线飞行的投射物的碰撞矩形边长（像素）。
+const PROJECTILE_SIZE: f32 = 20.0;
+
+/// 投射物命中僵尸后附加的效果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitEffect {
+    /// 使僵尸减速一段时间（毫秒）。
+    Slow(u64),
+    /// 使僵尸被定身一段时间（毫秒）。
+    Stun(u64),
+    /// 燃烧，会解除僵尸身上的减速效果。
+    Burn,
+    /// 打掉僵尸的护甲（例如路障）。
+    KnockOffArmor,
+}
+
+/// 投射物的飞行方式。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flight {
+    /// 沿所在行直线飞行，只会命中同一行的僵尸，会撞上屋顶的斜坡。
+    Lane,
+    /// 按速度向量自由飞行，会命中途经的任何一行中的僵尸。
+    Free,
+    /// 沿抛物线从 `start` 飞向 `target`，只在落地时判定命中，会越过僵尸的正面护盾和屋顶的斜坡。
+    Lobbed {
+        /// 发射点。
+        start: (f32, f32),
+        /// 落点（目标僵尸的预测位置）。
+        target: (f32, f32),
+        /// 已经飞行的时间（毫秒）。
+        elapsed: u64,
+    },
+}
+
+/// 投射物的类型，决定投射物的默认属性和图像。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectileKind {
+    /// 普通豌豆，具有标准的飞行速度和伤害。
+    Pea,
+    /// 寒冰豌豆，命中后使僵尸减速。
+    SnowPea,
+    /// 火焰豌豆，伤害加倍并溅射周围的僵尸，会解除僵尸的减速。
+    FirePea,
+    /// 尖刺，可以穿透多个僵尸。
+    Spike,
+    /// 星星，按速度向量自由飞行。
+    Star,
+    /// 卷心菜，沿抛物线飞行，伤害较高。
+    Cabbage,
+    /// 玉米粒，沿抛物线飞行，伤害较低。
+    Kernel,
+    /// 黄油，沿抛物线飞行，造成伤害并使目标僵尸被定身。
+    Butter,
+    /// 篮球，由投篮车僵尸沿抛物线投向植物，会被保护伞类植物挡住。
+    Basketball,
+}
+
+impl ProjectileKind {
+    /// 该类型投射物的飞行速度（像素/毫秒）。抛物线投掷物按固定的飞行时间飞行，速度为 0。
+    pub fn speed(&self) -> f32 {
+        match self {
+            ProjectileKind::Pea | ProjectileKind::FirePea => tuning::current().pea.speed,
+            ProjectileKind::SnowPea => 0.3,
+            ProjectileKind::Spike => 0.35,
+            ProjectileKind::Star => 0.35,
+            ProjectileKind::Cabbage | ProjectileKind::Kernel | ProjectileKind::Butter | ProjectileKind::Basketball => 0.0,
+        }
+    }
+
+    /// 该类型投射物对僵尸造成的伤害。
+    pub fn damage(&self) -> i32 {
+        match self {
+            ProjectileKind::Pea => tuning::current().pea.damage,
+            ProjectileKind::SnowPea => 20,
+            ProjectileKind::FirePea => 40,
+            ProjectileKind::Spike | ProjectileKind::Star => 20,
+            ProjectileKind::Cabbage | ProjectileKind::Butter => 40,
+            ProjectileKind::Kernel => 20,
+            ProjectileKind::Basketball => 75,
+        }
+    }
+
+    /// 该类型投射物在消失前还能额外穿透的僵尸数量。
+    pub fn pierce(&self) -> u32 {
+        match self {
+            ProjectileKind::Spike => 2,
+            _ => 0,
+        }
+    }
+
+    /// 该类型投射物命中时的溅射半径（像素），半径内的其他僵尸也会受到伤害。0 表示没有溅射。
+    pub fn splash_radius(&self) -> f32 {
+        match self {
+            ProjectileKind::FirePea => 40.0,
+            _ => 0.0,
+        }
+    }
+
+    /// 该类型投射物命中僵尸后附加的效果。
+    pub fn effects(&self) -> Vec<HitEffect> {
+        match self {
+            ProjectileKind::SnowPea => vec![HitEffect::Slow(SNOW_SLOW_DURATION)],
+            ProjectileKind::FirePea => vec![HitEffect::Burn],
+            ProjectileKind::Butter => vec![HitEffect::Stun(BUTTER_STUN_DURATION)],
+            _ => Vec::new(),
+        }
+    }
+
+    /// 该类型投射物在资源清单中的贴图名称。
+    pub fn sprite_key(&self) -> &'static str {
+        match self {
+            ProjectileKind::Pea => "pea",
+            ProjectileKind::SnowPea => "snow_pea",
+            ProjectileKind::FirePea => "fire_pea",
+            ProjectileKind::Spike => "spike",
+            ProjectileKind::Star => "star",
+            ProjectileKind::Cabbage => "cabbage",
+            ProjectileKind::Kernel => "kernel",
+            ProjectileKind::Butter => "butter",
+            ProjectileKind::Basketball => "basketball",
+        }
+    }
+
+    /// 获取该类型投射物的图像。
+    ///
+    /// # Arguments
+    ///
+    /// * `resources` - 游戏资源实例的引用。
+    ///
+    /// # Returns
+    ///
+    /// 返回投射物图像的引用。
+    pub fn sprite<'a>(&self, resources: &'a Resources) -> &'a Image {
+        resources.image(self.sprite_key())
+    }
+}
+
+/// 飞行中的投射物。
+pub struct Projectile {
+    /// 投射物当前的x轴坐标。
+    pub x: f32,
+    /// 投射物当前的y轴坐标。
+    pub y: f32,
+    /// 投射物所在的行索引。自由飞行的投射物会随y坐标更新所在行。
+    pub row: usize,
+    /// 投射物的类型。
+    pub kind: ProjectileKind,
+    /// 速度向量（像素/毫秒），抛物线投掷物不使用。
+    pub velocity: (f32, f32),
+    /// 投射物的飞行方式。
+    pub flight: Flight,
+    /// 投射物对僵尸造成的伤害值。
+    pub damage: i32,
+    /// 还能额外穿透的僵尸数量，为 0 时命中下一个僵尸后消失。
+    pub pierce: u32,
+    /// 命中时的溅射半径（像素）。
+    pub splash_radius: f32,
+    /// 命中僵尸后附加的效果。
+    pub effects: Vec<HitEffect>,
+    /// 标记投射物是否处于活动状态。非活动的投射物将被移除或不再参与碰撞检测。
+    pub active: bool,
+    /// 是否为僵尸投出的投射物。敌方投射物只会伤害植物，落在保护伞类植物周围时会被挡住。
+    pub hostile: bool,
+    /// 已经命中过的僵尸，穿透的投射物不会重复命中同一个僵尸。
+    hit_zombies: Vec<u64>,
+    /// 投射物最近一次飞入的网格单元格，每经过一个单元格只会被其中的植物转换一次。
+    last_cell: Option<(usize, usize)>,
+    /// 投射物所在草坪的几何信息，决定投射物何时飞出草坪以及沿途的地形高度。
+    geometry: LawnGeometry,
+    /// 发射位置的地形高度，沿途地形比它高出太多时直线飞行的投射物会撞上斜坡。
+    launch_height: f32,
+}
+
+impl Projectile {
+    /// 创建一个新的投射物，伤害、穿透次数、溅射半径和命中效果取该类型的默认值。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - 投射物的类型。
+    /// * `x` - 投射物的初始x坐标。
+    /// * `y` - 投射物的初始y坐标。
+    /// * `row` - 投射物所在的行。
+    /// * `velocity` - 速度向量（像素/毫秒）。
+    /// * `flight` - 投射物的飞行方式。
+    /// * `geometry` - 草坪的几何信息。
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 `Projectile` 实例。
+    pub fn new(
+        kind: ProjectileKind,
+        x: f32,
+        y: f32,
+        row: usize,
+        velocity: (f32, f32),
+        flight: Flight,
+        geometry: LawnGeometry,
+    ) -> Self {
+        Projectile {
+            x,
+            y,
+            row,
+            kind,
+            velocity,
+            flight,
+            damage: kind.damage(),
+            pierce: kind.pierce(),
+            splash_radius: kind.splash_radius(),
+            effects: kind.effects(),
+            active: true,
+            hostile: false,
+            hit_zombies: Vec::new(),
+            last_cell: None,
+            geometry,
+            launch_height: geometry.terrain_height_at(x),
+        }
+    }
+
+    /// 在标准草坪上创建一个沿行向右直线飞行的投射物。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 投射物的初始x坐标。
+    /// * `y` - 投射物的初始y坐标。
+    /// * `row` - 投射物所在的行。
+    /// * `kind` - 投射物的类型。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Projectile` 实例，飞出标准草坪后被移除。
+    pub fn straight(x: f32, y: f32, row: usize, kind: ProjectileKind) -> Self {
+        Projectile::straight_on_lawn(x, y, row, kind, LawnGeometry::STANDARD)
+    }
+
+    /// 在指定布局的草坪上创建一个沿行向右直线飞行的投射物。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 投射物的初始x坐标。
+    /// * `y` - 投射物的初始y坐标。
+    /// * `row` - 投射物所在的行。
+    /// * `kind` - 投射物的类型。
+    /// * `geometry` - 草坪的几何信息，用于决定投射物何时飞出草坪以及是否撞上斜坡。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Projectile` 实例。
+    pub fn straight_on_lawn(x: f32, y: f32, row: usize, kind: ProjectileKind, geometry: LawnGeometry) -> Self {
+        Projectile::new(kind, x, y, row, (kind.speed(), 0.0), Flight::Lane, geometry)
+    }
+
+    /// 创建一个沿抛物线从 `start` 飞向 `target` 的投掷物。
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - 发射点的屏幕坐标。
+    /// * `target` - 落点的屏幕坐标。
+    /// * `row` - 目标所在的行，落地时只会命中这一行的僵尸。
+    /// * `kind` - 投掷物的类型。
+    /// * `geometry` - 草坪的几何信息。
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 `Projectile` 实例。
+    pub fn lobbed(start: (f32, f32), target: (f32, f32), row: usize, kind: ProjectileKind, geometry: LawnGeometry) -> Self {
+        let flight = Flight::Lobbed { start, target, elapsed: 0 };
+        Projectile::new(kind, start.0, start.1, row, (0.0, 0.0), flight, geometry)
+    }
+
+    /// 创建一个由僵尸投出、沿抛物线飞向植物的敌方投掷物。
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - 投出点的屏幕坐标。
+    /// * `target` - 落点（目标植物所在单元格的中心）。
+    /// * `row` - 目标植物所在的行。
+    /// * `kind` - 投掷物的类型。
+    /// * `geometry` - 草坪的几何信息。
+    ///
+    /// # Returns
+    ///
+    /// 返回 `hostile` 为 `true` 的 `Projectile` 实例。
+    pub fn hostile_lobbed(start: (f32, f32), target: (f32, f32), row: usize, kind: ProjectileKind, geometry: LawnGeometry) -> Self {
+        Projectile { hostile: true, ..Projectile::lobbed(start, target, row, kind, geometry) }
+    }
+
+    /// 从指定位置向同一行中最近的僵尸发射抛物线投掷物。
+    ///
+    /// 目标是发射点右侧最靠左的存活僵尸，落点是该僵尸在飞行时间结束时的预测位置。
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - 发射点的屏幕坐标。
+    /// * `row` - 发射植物所在的行。
+    /// * `kind` - 投掷物的类型。
+    /// * `zombies` - 候选僵尸，可以是场上所有僵尸，也可以是植物上下文查询到的一部分僵尸。
+    /// * `geometry` - 草坪的几何信息，用于计算落点的Y坐标。
+    ///
+    /// # Returns
+    ///
+    /// 如果这一行中有可以瞄准的僵尸，返回 `Some(投掷物)`，否则返回 `None`。
+    pub fn aimed_at_zombie<'z>(
+        start: (f32, f32),
+        row: usize,
+        kind: ProjectileKind,
+        zombies: impl IntoIterator<Item = &'z Zombie>,
+        geometry: &LawnGeometry,
+    ) -> Option<Self> {
+        let target = zombies
+            .into_iter()
+            .filter(|zombie| !zombie.is_dying && zombie.row == row && zombie.x + ZOMBIE_AIM_OFFSET_X > start.0)
+            .min_by(|a, b| a.x.total_cmp(&b.x))?;
+
+        let target_x = target.predicted_x(LOB_FLIGHT_TIME) + ZOMBIE_AIM_OFFSET_X;
+        let target_y = geometry.row_y(row) + geometry.cell_height / 2.0;
+        Some(Projectile::lobbed(start, (target_x, target_y), row, kind, *geometry))
+    }
+
+    /// 投射物命中僵尸的方式。抛物线投掷物从上方落下，其余投射物都是直线攻击。
+    pub fn hit_kind(&self) -> HitKind {
+        match self.flight {
+            Flight::Lobbed { .. } => HitKind::Lobbed,
+            Flight::Lane | Flight::Free => HitKind::Straight,
+        }
+    }
+
+    /// 抛物线投掷物的落点，其他飞行方式返回 `None`。
+    pub fn target(&self) -> Option<(f32, f32)> {
+        match self.flight {
+            Flight::Lobbed { target, .. } => Some(target),
+            Flight::Lane | Flight::Free => None,
+        }
+    }
+
+    /// 抛物线投掷物是否已经落地。其他飞行方式总是返回 `false`。
+    pub fn has_landed(&self) -> bool {
+        matches!(self.flight, Flight::Lobbed { elapsed, .. } if elapsed >= LOB_FLIGHT_TIME)
+    }
+
+    /// 投射物是否已经命中过指定的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_id` - 僵尸的唯一标识。
+    pub fn has_hit(&self, zombie_id: u64) -> bool {
+        self.hit_zombies.contains(&zombie_id)
+    }
+
+    /// 记录一次对僵尸的命中。没有剩余穿透次数时投射物被标记为非活动状态，
+    /// 抛物线投掷物命中后总是消失。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_id` - 被命中的僵尸的唯一标识。
+    pub fn register_hit(&mut self, zombie_id: u64) {
+        self.hit_zombies.push(zombie_id);
+        if self.pierce == 0 || matches!(self.flight, Flight::Lobbed { .. }) {
+            self.active = false;
+        } else {
+            self.pierce -= 1;
+        }
+    }
+
+    /// 将投射物转换为另一种类型（例如被火炬树桩点燃），伤害、溅射半径和命中效果改为新类型的默认值，
+    /// 位置、速度和剩余穿透次数保持不变。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - 转换后的投射物类型。
+    pub fn convert_to(&mut self, kind: ProjectileKind) {
+        self.kind = kind;
+        self.damage = kind.damage();
+        self.splash_radius = kind.splash_radius();
+        self.effects = kind.effects();
+    }
+
+    /// 沿行飞行的投射物当前所在的网格单元格，自由飞行和抛物线飞行的投射物不经过单元格，返回 `None`。
+    pub fn lane_cell(&self) -> Option<(usize, usize)> {
+        if self.flight != Flight::Lane {
+            return None;
+        }
+        let rect = self.get_rect();
+        let col_x = rect.x + rect.w / 2.0 - self.geometry.origin_x;
+        if col_x < 0.0 {
+            return None;
+        }
+        let col = (col_x / self.geometry.cell_width) as usize;
+        if col < self.geometry.cols {
+            Some((col, self.row))
+        } else {
+            None
+        }
+    }
+
+    /// 记录投射物飞入了某个单元格。
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - 投射物当前所在的单元格。
+    ///
+    /// # Returns
+    ///
+    /// 如果这是投射物第一次飞入该单元格，返回 `true`；仍停留在上一次记录的单元格中时返回 `false`。
+    pub fn enter_cell(&mut self, cell: (usize, usize)) -> bool {
+        if self.last_cell == Some(cell) {
+            return false;
+        }
+        self.last_cell = Some(cell);
+        true
+    }
+
+    /// 更新投射物的位置。
+    ///
+    /// - 直线飞行的投射物按速度移动，飞出草坪右侧或撞上比发射位置高出太多的斜坡（屋顶关卡）时被标记为非活动状态。
+    /// - 自由飞行的投射物按速度向量移动，所在行随y坐标更新，飞出草坪范围时被标记为非活动状态。
+    /// - 抛物线投掷物沿抛物线飞向落点，落地后由碰撞逻辑处理。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    pub fn update(&mut self, dt: u64) {
+        match &mut self.flight {
+            Flight::Lane => {
+                self.x += self.velocity.0 * dt as f32;
+
+                // 如果投射物飞出草坪，将其设置为非活动状态
+                if self.x > self.geometry.projectile_cull_x() {
+                    self.active = false;
+                }
+
+                // 屋顶上从斜坡低处发射的投射物会撞上前方的斜坡
+                if self.geometry.terrain_height_at(self.x) - self.launch_height > SLOPE_CLEARANCE {
+                    self.active = false;
+                }
+            }
+            Flight::Free => {
+                self.x += self.velocity.0 * dt as f32;
+                self.y += self.velocity.1 * dt as f32;
+
+                let geometry = &self.geometry;
+                let outside_x = self.x < geometry.origin_x - geometry.cell_width || self.x > geometry.projectile_cull_x();
+                let outside_y = self.y < geometry.origin_y - geometry.cell_height || self.y > geometry.bottom();
+                if outside_x || outside_y {
+                    self.active = false;
+                } else {
+                    let row = ((self.y - geometry.origin_y) / geometry.cell_height).max(0.0) as usize;
+                    self.row = row.min(geometry.rows - 1);
+                }
+            }
+            Flight::Lobbed { start, target, elapsed } => {
+                *elapsed = (*elapsed + dt).min(LOB_FLIGHT_TIME);
+                let t = *elapsed as f32 / LOB_FLIGHT_TIME as f32;
+
+                self.x = start.0 + (target.0 - start.0) * t;
+                self.y = start.1 + (target.1 - start.1) * t - LOB_ARC_HEIGHT * 4.0 * t * (1.0 - t);
+            }
+        }
+    }
+
+    /// 绘制投射物。
+    ///
+    /// 按资源清单中的原点和缩放绘制：直线和自由飞行的投射物以左上角为锚点缩小绘制，抛物线投掷物以中心为锚点绘制。
+    ///
+    /// # Arguments
+    ///
+    /// * `batcher` - 精灵批处理器，由渲染器在图层结束时统一绘制。
+    /// * `resources` - 游戏资源，用于获取投射物图像。
+    pub fn draw(&self, batcher: &mut SpriteBatcher, resources: &Resources) {
+        // 缩放和原点来自资源清单，抛物线投掷物在清单中以图像中心为原点
+        let key = self.kind.sprite_key();
+        batcher.add(resources, key, 0, resources.draw_param(key).dest([self.x, self.y]));
+    }
+
+    /// 获取投射物的碰撞检测矩形区域。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `Rect`，代表投射物在游戏世界中的碰撞边界。
+    pub fn get_rect(&self) -> Rect {
+        // 投射物碰撞范围，根据实际图片大小调整 TODO：调整
+        Rect::new(self.x, self.y, PROJECTILE_SIZE, PROJECTILE_SIZE)
+    }
+}
@@ -0,0 +1,110 @@
+//! # 奖励卡片实体模块
+//!
+//! 定义了关卡胜利后掉落的植物卡片（种子包）。
+//! 卡片从最后的位置缓缓落下并上下浮动，玩家点击后即可领取新植物。
+
+use ggez::{Context, GameResult};
+use ggez::graphics::{self, DrawParam, Rect};
+use crate::core::resources::Resources;
+use crate::plants::PlantType;
+
+/// 奖励卡片的宽度。
+const PACKET_WIDTH: f32 = 50.0;
+/// 奖励卡片的高度。
+const PACKET_HEIGHT: f32 = 70.0;
+/// 奖励卡片的绘制缩放比例。
+const PACKET_SCALE: f32 = 1.2;
+
+/// 关卡胜利后掉落的种子包。
+pub struct SeedPacketReward {
+    /// 种子包中包含的植物类型。
+    pub plant_type: PlantType,
+    /// 种子包当前的x轴坐标。
+    x: f32,
+    /// 种子包当前的y轴坐标。
+    y: f32,
+    /// 种子包下落的目标y坐标。
+    target_y: f32,
+    /// 种子包已存在的时间（毫秒），用于浮动动画。
+    lifetime: u64,
+    /// 标记种子包是否已经被玩家领取。
+    pub collected: bool,
+}
+
+impl SeedPacketReward {
+    /// 创建一个新的奖励种子包。
+    ///
+    /// # Arguments
+    ///
+    /// * `plant_type` - 奖励的植物类型。
+    /// * `x` - 种子包的初始x坐标。
+    /// * `y` - 种子包的初始y坐标。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `SeedPacketReward` 实例。
+    pub fn new(plant_type: PlantType, x: f32, y: f32) -> Self {
+        SeedPacketReward {
+            plant_type,
+            x,
+            y,
+            target_y: y + 80.0,
+            lifetime: 0,
+            collected: false,
+        }
+    }
+
+    /// 更新种子包的下落动画。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    pub fn update(&mut self, dt: u64) {
+        self.lifetime += dt;
+        if self.y < self.target_y {
+            self.y = (self.y + 0.1 * dt as f32).min(self.target_y);
+        }
+    }
+
+    /// 获取种子包的点击区域。
+    fn get_rect(&self) -> Rect {
+        Rect::new(self.x, self.y, PACKET_WIDTH * PACKET_SCALE, PACKET_HEIGHT * PACKET_SCALE)
+    }
+
+    /// 检查给定的屏幕坐标是否落在种子包上。
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        self.get_rect().contains([x, y])
+    }
+
+    /// 绘制种子包，落地后会轻微上下浮动。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 游戏资源，用于获取植物卡片图像。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
+        let card_image = match resources.sprite(self.plant_type.spec().card) {
+            Some(image) => image,
+            None => return Ok(()),
+        };
+
+        // 落地后以正弦曲线上下浮动
+        let bob = if self.y >= self.target_y {
+            (self.lifetime as f32 / 300.0).sin() * 5.0
+        } else {
+            0.0
+        };
+
+        graphics::draw(
+            ctx,
+            card_image,
+            DrawParam::default()
+                .dest([self.x, self.y + bob])
+                .scale([PACKET_SCALE, PACKET_SCALE]),
+        )
+    }
+}
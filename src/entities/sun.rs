@@ -2,10 +2,13 @@
 //!
 //! 定义了游戏中阳光的行为和属性，阳光是用于购买植物的主要资源。
 
-use ggez::{Context, GameResult};
-use ggez::graphics::{self, DrawParam};
+use crate::core::animation::Animator;
+use crate::core::render_cache::SpriteBatcher;
 use crate::core::resources::Resources;
 
+/// 收集一个阳光获得的阳光数量。
+pub const SUN_VALUE: i32 = 25;
+
 /// 阳光的生成类型枚举。
 ///
 /// 用于区分阳光是自然从天上掉落还是由向日葵等植物产生。
@@ -29,12 +32,8 @@ pub struct Sun {
     target_y: f32,
     /// 阳光（特指自然掉落类型）的下落速度。
     speed: f32,
-    /// 阳光已存在的时间，可用于实现例如超时消失等逻辑 (当前未使用)。
-    lifetime: u64,
-    /// 当前显示的阳光动画帧的索引。
-    animation_frame: usize,
-    /// 用于控制阳光动画帧切换的计时器。
-    animation_timer: u64,
+    /// 阳光旋转动画的播放器。
+    animator: Animator,
     /// 阳光的生成类型（自然掉落或向日葵产生）。
     sun_type: SunType,
     /// 向日葵阳光的初始y坐标，用于跳跃动画
@@ -67,9 +66,7 @@ impl Sun {
             y,
             target_y,
             speed: 0.06,
-            lifetime: 0,
-            animation_frame: 0,
-            animation_timer: 0,
+            animator: Animator::new("sun"),
             sun_type: gen_sun_type,
             initial_y: y,
             jump_height: 20.0, // 跳跃高度，稍微增加使动画更明显
@@ -128,36 +125,20 @@ impl Sun {
         }
 
 
-        self.lifetime += dt;
-        
-        // 动画帧更新逻辑保
-        self.animation_timer += dt;
-        if self.animation_timer > 50 {  // 缩短切换时间使动画更流畅
-            self.animation_frame = (self.animation_frame + 1) % 22;  // 在22帧之间循环
-            self.animation_timer = 0;
-        }
+        // 阳光动画更新
+        self.animator.update(dt);
     }
 
     /// 绘制单个阳光到屏幕上。
     ///
-    /// 使用 `sun_images` 中的当前动画帧进行绘制。
+    /// 把资源清单中 `sun` 动画的当前帧加入精灵批处理器。
     ///
     /// # Arguments
     ///
-    /// * `ctx` - ggez的上下文环境。
+    /// * `batcher` - 精灵批处理器，由渲染器在图层结束时统一绘制。
     /// * `resources` - 游戏资源，用于获取阳光的动画帧图像。
-    ///
-    /// # Returns
-    ///
-    /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
-        graphics::draw(
-            ctx,
-            &resources.sun_images[self.animation_frame],  // 使用当前动画帧
-            DrawParam::default()
-                .dest([self.x, self.y])
-                .scale([0.6, 0.6]),
-        )
+    pub fn draw(&self, batcher: &mut SpriteBatcher, resources: &Resources) {
+        batcher.add_animator(resources, &self.animator, resources.draw_param("sun").dest([self.x, self.y]))
     }
 
     /// 检测给定的点坐标是否在阳光的可点击范围内。
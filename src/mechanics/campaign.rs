@@ -0,0 +1,367 @@
+//! # 冒险模式模块 (`campaign`)
+//!
+//! 定义了冒险模式中按顺序排列的关卡列表。
+//! 每个关卡描述了自己的环境（白天或夜晚）、波数、初始阳光、僵尸组成，以及通关后可能奖励的新植物卡片。
+//! 关卡的解锁与完成情况由 `core::profile::Profile` 记录并保存。
+//!
+//! 关卡列表从资源目录下的 `levels.toml` 读取。编译时会嵌入同一个文件作为内置的关卡列表，
+//! 因此资源目录中没有该文件时游戏仍然可以运行。文件在读取后会经过校验，校验失败时保留当前的关卡列表；
+//! 调试构建中修改文件后会自动重新读取。
+
+use crate::plants::PlantType;
+use crate::zombies::ZombieType;
+use crate::ui::lawn::{LawnGeometry, LawnLayout, TileType};
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{LazyLock, RwLock};
+
+/// 关卡文件的文件名。
+pub const LEVELS_FILE_NAME: &str = "levels.toml";
+
+/// 编译时嵌入的关卡文件内容。
+const BUILTIN_LEVELS: &str = include_str!("../../Resource/levels.toml");
+
+/// 当前生效的关卡列表，启动时为内置的关卡列表。
+static CAMPAIGN: LazyLock<RwLock<Vec<LevelDefinition>>> = LazyLock::new(|| RwLock::new(builtin()));
+
+/// 关卡所处的环境。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Environment {
+    /// 白天：天上会自然掉落阳光。
+    Day,
+    /// 夜晚：没有自然掉落的阳光，背景更暗，草坪上会出现墓碑。
+    Night,
+    /// 屋顶：白天，会自然掉落阳光，所有植物都必须种在花盆上。
+    Roof,
+}
+
+impl Environment {
+    /// 该环境下是否会自然掉落阳光。
+    pub fn has_natural_sun(&self) -> bool {
+        *self != Environment::Night
+    }
+}
+
+/// 冒险模式中单个关卡的定义。
+///
+/// 关卡定义从关卡文件中读取，开始一局游戏时会复制一份，之后重新读取关卡文件不会影响正在进行的关卡。
+/// `LevelController` 和 `Shop` 会根据它来配置当前关卡。
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LevelDefinition {
+    /// 关卡的唯一标识，用于在存档中记录完成情况。
+    pub id: String,
+    /// 在选关界面中显示的关卡名称。
+    pub name: String,
+    /// 关卡所处的环境。
+    pub environment: Environment,
+    /// 关卡的草坪布局（行列数、位置和每一行的地形）。文件中可以使用布局名称（见 [`layout_by_name`]），
+    /// 也可以用一个表直接写出布局（见 [`InlineLayout`]）。
+    #[serde(deserialize_with = "deserialize_layout")]
+    pub layout: LawnLayout,
+    /// 关卡开始时草坪上随机放置的墓碑数量。最后一波时僵尸会从墓碑中爬出。
+    #[serde(default)]
+    pub graves: usize,
+    /// 被迷雾覆盖的列数（从最右侧一列向左计算），0 表示没有迷雾。
+    #[serde(default)]
+    pub fog_columns: usize,
+    /// 本关卡的总波数。
+    pub total_waves: usize,
+    /// 从第几波（从0开始）开始出现路障僵尸。`None` 表示本关没有路障僵尸。
+    #[serde(default)]
+    pub conehead_from_wave: Option<usize>,
+    /// 从第几波（从0开始）开始出现投篮车僵尸。`None` 表示本关没有投篮车僵尸。
+    #[serde(default)]
+    pub catapult_from_wave: Option<usize>,
+    /// 关卡开始时玩家拥有的阳光数量。
+    pub initial_sun: i32,
+    /// 首次通关时奖励的植物卡片。`None` 表示没有奖励。
+    #[serde(default)]
+    pub reward: Option<PlantType>,
+}
+
+impl LevelDefinition {
+    /// 获取本关卡会出现的全部僵尸类型，用于在选卡界面展示僵尸阵容。
+    ///
+    /// # Returns
+    ///
+    /// 返回本关卡出现的 `ZombieType` 列表，不包含重复项。
+    pub fn zombie_types(&self) -> Vec<ZombieType> {
+        let mut types = vec![ZombieType::Normal];
+        if self.conehead_from_wave.is_some() {
+            types.push(ZombieType::Conehead);
+        }
+        if self.catapult_from_wave.is_some() {
+            types.push(ZombieType::Catapult);
+        }
+        types
+    }
+}
+
+
+/// 关卡在选关界面中的状态。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelStatus {
+    /// 关卡尚未解锁，无法进入。
+    Locked,
+    /// 关卡已解锁但尚未通关。
+    Unlocked,
+    /// 关卡已经通关。
+    Completed,
+}
+
+/// 关卡文件的结构：按游玩顺序排列的 `[[levels]]` 条目。
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CampaignFile {
+    levels: Vec<LevelDefinition>,
+}
+
+/// 根据关卡文件中使用的名称获取草坪布局。
+///
+/// # Arguments
+///
+/// * `name` - 布局名称：`"standard"`、`"tutorial"`、`"pool"` 或 `"roof"`。
+///
+/// # Returns
+///
+/// 名称有效时返回对应的 `LawnLayout`，否则返回 `None`。
+pub fn layout_by_name(name: &str) -> Option<LawnLayout> {
+    match name {
+        "standard" => Some(LawnLayout::standard()),
+        "tutorial" => Some(LawnLayout::tutorial()),
+        "pool" => Some(LawnLayout::pool()),
+        "roof" => Some(LawnLayout::roof()),
+        _ => None,
+    }
+}
+
+/// 关卡文件中直接写出的草坪布局，例如
+/// `layout = { rows = 4, cols = 9, origin = [255.0, 180.0], cell_size = [80.0, 100.0], tiles = ["grass", "water", "water", "grass"] }`。
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InlineLayout {
+    /// 行数。
+    pub rows: usize,
+    /// 列数。
+    pub cols: usize,
+    /// 草坪左上角的屏幕坐标 `[x, y]`。
+    pub origin: [f32; 2],
+    /// 单元格大小 `[宽, 高]`。
+    pub cell_size: [f32; 2],
+    /// 从上到下每一行的地形，缺少的行视为草地。
+    #[serde(default)]
+    pub tiles: Vec<TileType>,
+    /// 从最左侧一列开始的斜坡列数，默认 0。
+    #[serde(default)]
+    pub slope_columns: usize,
+    /// 斜坡上每向右一列地形升高的高度（像素），默认 0。
+    #[serde(default)]
+    pub slope_step: f32,
+}
+
+impl From<InlineLayout> for LawnLayout {
+    fn from(inline: InlineLayout) -> Self {
+        LawnLayout {
+            geometry: LawnGeometry {
+                rows: inline.rows,
+                cols: inline.cols,
+                origin_x: inline.origin[0],
+                origin_y: inline.origin[1],
+                cell_width: inline.cell_size[0],
+                cell_height: inline.cell_size[1],
+                slope_columns: inline.slope_columns,
+                slope_step: inline.slope_step,
+            },
+            row_tiles: inline.tiles,
+        }
+    }
+}
+
+/// 读取关卡文件中的 `layout` 字段：布局名称字符串，或直接写出布局的表。
+struct LayoutVisitor;
+
+impl<'de> Visitor<'de> for LayoutVisitor {
+    type Value = LawnLayout;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a layout name or a layout table")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<LawnLayout, E> {
+        layout_by_name(name).ok_or_else(|| E::custom(format!("未知的草坪布局: {}", name)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<LawnLayout, A::Error> {
+        InlineLayout::deserialize(de::value::MapAccessDeserializer::new(map)).map(LawnLayout::from)
+    }
+}
+
+fn deserialize_layout<'de, D: Deserializer<'de>>(deserializer: D) -> Result<LawnLayout, D::Error> {
+    deserializer.deserialize_any(LayoutVisitor)
+}
+
+/// 读取或校验关卡文件时的错误。
+#[derive(Debug)]
+pub enum CampaignError {
+    /// 无法读取关卡文件。
+    Io(io::Error),
+    /// 关卡文件不是合法的 TOML，或包含未知的字段、布局或植物。
+    Parse(toml::de::Error),
+    /// 关卡文件中的关卡不合法，附带说明。
+    Invalid(String),
+}
+
+impl fmt::Display for CampaignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CampaignError::Io(e) => write!(f, "failed to read levels file: {}", e),
+            CampaignError::Parse(e) => write!(f, "failed to parse levels file: {}", e),
+            CampaignError::Invalid(reason) => write!(f, "invalid level: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for CampaignError {}
+
+/// 校验条件不满足时返回 `CampaignError::Invalid`。
+fn check(condition: bool, reason: impl FnOnce() -> String) -> Result<(), CampaignError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(CampaignError::Invalid(reason()))
+    }
+}
+
+/// 从 TOML 文本解析关卡列表并进行校验。
+///
+/// # Arguments
+///
+/// * `content` - 关卡文件的内容。
+///
+/// # Returns
+///
+/// 解析并校验成功返回按游玩顺序排列的关卡列表，否则返回对应的 `CampaignError`。
+pub fn from_toml(content: &str) -> Result<Vec<LevelDefinition>, CampaignError> {
+    let file: CampaignFile = toml::from_str(content).map_err(CampaignError::Parse)?;
+    validate(&file.levels)?;
+    Ok(file.levels)
+}
+
+/// 检查关卡列表是否可以游玩：至少有一关、标识不重复、每关至少一波，迷雾和特殊僵尸的设置在关卡范围内。
+///
+/// # Arguments
+///
+/// * `levels` - 要检查的关卡列表。
+///
+/// # Returns
+///
+/// 全部合法返回 `Ok(())`，否则返回描述第一个问题的 `CampaignError::Invalid`。
+pub fn validate(levels: &[LevelDefinition]) -> Result<(), CampaignError> {
+    check(!levels.is_empty(), || "the campaign must contain at least one level".to_string())?;
+
+    let mut ids = HashSet::new();
+    for level in levels {
+        check(ids.insert(level.id.as_str()), || format!("duplicate level id '{}'", level.id))?;
+        check(level.total_waves > 0, || format!("level '{}' must have at least one wave", level.id))?;
+        check(level.initial_sun >= 0, || {
+            format!("level '{}' initial_sun must not be negative (got {})", level.id, level.initial_sun)
+        })?;
+        let geometry = &level.layout.geometry;
+        check(geometry.rows > 0 && geometry.cols > 0, || {
+            format!("level '{}' lawn must have at least one row and one column", level.id)
+        })?;
+        check(geometry.cell_width > 0.0 && geometry.cell_height > 0.0, || {
+            format!("level '{}' lawn cell size must be positive", level.id)
+        })?;
+        check(level.layout.row_tiles.len() <= geometry.rows, || {
+            format!(
+                "level '{}' lists {} row tiles for a lawn with {} rows",
+                level.id, level.layout.row_tiles.len(), geometry.rows
+            )
+        })?;
+        check(level.fog_columns <= level.layout.geometry.cols, || {
+            format!(
+                "level '{}' fog_columns ({}) exceeds the lawn width ({})",
+                level.id, level.fog_columns, level.layout.geometry.cols
+            )
+        })?;
+        let special_waves = [
+            ("conehead_from_wave", level.conehead_from_wave),
+            ("catapult_from_wave", level.catapult_from_wave),
+        ];
+        for (name, from_wave) in special_waves {
+            if let Some(wave) = from_wave {
+                check(wave < level.total_waves, || {
+                    format!("level '{}' {} ({}) is past the last wave", level.id, name, wave)
+                })?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 获取编译时嵌入的内置关卡列表。
+///
+/// # Panics
+///
+/// 内置的关卡文件无效时 panic；`tests/test_campaign.rs` 会检查这一点。
+pub fn builtin() -> Vec<LevelDefinition> {
+    from_toml(BUILTIN_LEVELS).unwrap_or_else(|e| panic!("built-in levels file is invalid: {}", e))
+}
+
+/// 获取当前关卡列表的副本，按游玩顺序排列。
+pub fn levels() -> Vec<LevelDefinition> {
+    CAMPAIGN.read().unwrap().clone()
+}
+
+/// 获取当前关卡列表中第 `index` 关的副本，索引超出范围时返回 `None`。
+pub fn level(index: usize) -> Option<LevelDefinition> {
+    CAMPAIGN.read().unwrap().get(index).cloned()
+}
+
+/// 获取当前关卡列表中的关卡数量，至少为 1。
+pub fn level_count() -> usize {
+    CAMPAIGN.read().unwrap().len()
+}
+
+/// 校验并启用一组关卡。
+///
+/// # Arguments
+///
+/// * `levels` - 新的关卡列表，按游玩顺序排列。
+///
+/// # Returns
+///
+/// 校验成功后返回 `Ok(())`；否则返回错误，当前关卡列表保持不变。
+pub fn install(levels: Vec<LevelDefinition>) -> Result<(), CampaignError> {
+    validate(&levels)?;
+    *CAMPAIGN.write().unwrap() = levels;
+    Ok(())
+}
+
+/// 从指定目录读取 `levels.toml` 并启用其中的关卡。
+///
+/// 文件不存在时恢复内置的关卡列表。读取、解析或校验失败时保留当前的关卡列表。
+///
+/// # Arguments
+///
+/// * `dir` - 存放关卡文件的目录（通常是资源目录 `Resource`）。
+///
+/// # Returns
+///
+/// 启用成功返回 `Ok(())`，否则返回对应的 `CampaignError`。
+pub fn load(dir: &Path) -> Result<(), CampaignError> {
+    let path = dir.join(LEVELS_FILE_NAME);
+    if !path.exists() {
+        return install(builtin());
+    }
+    let content = fs::read_to_string(&path).map_err(CampaignError::Io)?;
+    install(from_toml(&content)?)
+}
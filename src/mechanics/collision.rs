@@ -1,12 +1,17 @@
 //! # 碰撞检测模块 (`collision`)
 //!
 //! 本模块负责处理游戏中不同实体之间的碰撞检测及相应的逻辑响应。
-//! 例如，豌豆与僵尸的碰撞会导致僵尸受伤和豌豆消失；僵尸与植物的碰撞会导致植物受损以及僵尸进入攻击状态。
+//! 例如，投射物与僵尸的碰撞会导致僵尸受伤和投射物消失；僵尸与植物的碰撞会导致植物受损以及僵尸进入攻击状态。
+//! 所有投射物共用同一条碰撞路径，其中抛物线投掷物只在落地时判定命中，会越过僵尸的正面护盾。
 
-use crate::entities::pea::Pea;
+use crate::entities::projectile::{Flight, Projectile};
 use crate::plants::Plant;
+use crate::ui::grid::Grid;
+use crate::ui::lawn::LawnGeometry;
 use crate::zombies::Zombie;
-use ggez::{Context, timer};
+
+/// 抛物线投掷物落地时，落点距离僵尸碰撞矩形多远以内仍然算作命中（像素）。
+const LOB_HIT_TOLERANCE: f32 = 30.0;
 
 /// 碰撞管理器结构体。
 ///
@@ -14,68 +19,209 @@ use ggez::{Context, timer};
 pub struct CollisionManager;
 
 impl CollisionManager {
-    /// 处理豌豆与僵尸之间的碰撞。
+    /// 处理所有投射物与僵尸（以及敌方投射物与植物）之间的碰撞。
     ///
-    /// 遍历所有活动的豌豆和未处于死亡动画中的僵尸：
-    /// 1. 检查它们是否在同一行。
-    /// 2. 检查豌豆的x坐标是否已达到或超过僵尸的x坐标（粗略检测）。
-    /// 3. 如果满足以上条件，则获取两者的精确碰撞矩形并检查是否重叠。
-    /// 4. 如果发生碰撞，僵尸受到伤害，豌豆被标记为非活动状态并记录下来。
+    /// 对每个活动的投射物：
+    /// 1. 直线飞行的投射物命中同一行中与其碰撞矩形重叠的僵尸，自由飞行的投射物命中任何一行中重叠的僵尸。
+    ///    已经命中过的僵尸不会被同一个投射物再次命中。
+    /// 2. 抛物线投掷物只在落地时判定，命中落点附近、同一行中最近的存活僵尸，以抛物线方式造成伤害（越过正面护盾）。
+    ///    落地后的投掷物无论是否命中都会被移除。
+    /// 3. 命中时僵尸受到伤害并被施加投射物的命中效果，溅射半径内的其他僵尸受到同样的伤害和效果。
+    ///    投射物还有穿透次数时继续飞行，否则被标记为非活动状态。
+    /// 4. 敌方抛物线投掷物如果落在保护伞类植物周围一格内则被挡住，否则伤害落点单元格中最外层的植物。
     ///
-    /// 完成遍历后，移除所有非活动的豌豆，并移除所有死亡动画已完成的僵尸。
+    /// 完成遍历后，移除所有非活动的投射物，并移除所有死亡动画已完成的僵尸。
     ///
     /// # Arguments
     ///
-    /// * `peas` - 一个可变的豌豆向量引用，包含游戏中所有的豌豆。
+    /// * `projectiles` - 一个可变的投射物向量引用，包含游戏中所有的投射物。
     /// * `zombies` - 一个可变的僵尸向量引用，包含游戏中所有的僵尸。
-    pub fn handle_pea_zombie_collision(peas: &mut Vec<Pea>, zombies: &mut Vec<Zombie>) {
-        let mut inactive_peas = Vec::new();
-    
-        // 检测豌豆和僵尸的碰撞
-        for (pea_idx, pea) in peas.iter_mut().enumerate() {
-            if !pea.active {
-                inactive_peas.push(pea_idx);
+    /// * `plants` - 场上所有植物，用于判断保护伞和承受敌方投射物的伤害。
+    /// * `geometry` - 草坪的几何信息，用于将落点转换为单元格。
+    ///
+    /// # Returns
+    ///
+    /// 返回本次被投射物击杀的僵尸数量。
+    pub fn handle_projectile_collision(
+        projectiles: &mut Vec<Projectile>,
+        zombies: &mut Vec<Zombie>,
+        plants: &mut [Plant],
+        geometry: &LawnGeometry,
+    ) -> usize {
+        let mut kills = 0;
+
+        for projectile in projectiles.iter_mut().filter(|projectile| projectile.active) {
+            if projectile.hostile {
+                if projectile.has_landed() {
+                    projectile.active = false;
+                    Self::land_on_plants(projectile, plants, geometry);
+                }
                 continue;
             }
-    
-            for zombie in zombies.iter_mut() {
-                // 如果僵尸已经在死亡动画中，跳过碰撞检测
-                if zombie.is_dying {
-                    continue;
+
+            let (target, impact) = match projectile.flight {
+                Flight::Lobbed { .. } => {
+                    if !projectile.has_landed() {
+                        continue;
+                    }
+                    // 落地的投掷物无论是否命中都会消失
+                    projectile.active = false;
+                    let (x, y) = projectile.target().unwrap_or((projectile.x, projectile.y));
+                    (Self::find_landing_target(projectile, zombies, x), (x, y))
                 }
-                
-                // 如果不在同一行，跳过检测
-                if pea.row != zombie.row {
-                    continue;
+                Flight::Lane | Flight::Free => {
+                    let rect = projectile.get_rect();
+                    let target = zombies
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, zombie)| !zombie.is_dying && !projectile.has_hit(zombie.id()))
+                        .filter(|(_, zombie)| projectile.flight == Flight::Free || zombie.row == projectile.row)
+                        .filter(|(_, zombie)| rect.overlaps(&zombie.get_rect()))
+                        .min_by(|(_, a), (_, b)| a.x.total_cmp(&b.x))
+                        .map(|(i, _)| i);
+                    (target, (rect.x + rect.w / 2.0, rect.y + rect.h / 2.0))
                 }
-        
-                // 如果豌豆位置超过僵尸位置，可能发生碰撞
-                if pea.x + 20.0 >= zombie.x {
-                    // 检查碰撞
-                    let zombie_rect = zombie.get_rect();
-                    let pea_rect = pea.get_rect();
-            
-                    if pea_rect.overlaps(&zombie_rect) {
-                        // 碰撞发生，僵尸受伤
-                        zombie.take_damage(pea.damage);
-                
-                        // 豌豆击中后消失
-                        pea.active = false;
-                        inactive_peas.push(pea_idx);
-                        break; // 一个豌豆只能击中一个僵尸
+            };
+
+            let index = match target {
+                Some(index) => index,
+                None => continue,
+            };
+
+            let zombie_id = zombies[index].id();
+            if Self::hit_zombie(projectile, &mut zombies[index]) {
+                kills += 1;
+            }
+
+            // 溅射伤害落点周围的其他僵尸
+            if projectile.splash_radius > 0.0 {
+                for (_, zombie) in zombies.iter_mut().enumerate().filter(|(i, zombie)| *i != index && !zombie.is_dying) {
+                    let rect = zombie.get_rect();
+                    let dx = rect.x + rect.w / 2.0 - impact.0;
+                    let dy = rect.y + rect.h / 2.0 - impact.1;
+                    if (dx * dx + dy * dy).sqrt() <= projectile.splash_radius + rect.w / 2.0 && Self::hit_zombie(projectile, zombie) {
+                        kills += 1;
                     }
                 }
             }
+
+            projectile.register_hit(zombie_id);
         }
-        
-        // 移除已经无效的豌豆
-        inactive_peas.sort_by(|a, b| b.cmp(a));
-        for idx in inactive_peas {
-            peas.remove(idx);
-        }
-        
+
+        projectiles.retain(|projectile| projectile.active);
+
         // 移除死亡动画已完成的僵尸
         zombies.retain(|zombie| !zombie.death_animation_complete);
+
+        kills
+    }
+
+    /// 处理沿行飞行的投射物与所经过单元格中植物的交互。
+    ///
+    /// 投射物每飞入一个新的单元格，都会检查其中的植物能否转换它（例如火炬树桩把豌豆点燃成火焰豌豆，
+    /// 把寒冰豌豆融化成普通豌豆）。同一个单元格只会转换一次，因此连续经过两个火炬树桩的寒冰豌豆
+    /// 会先变成普通豌豆，再变成火焰豌豆。敌方投射物和不沿行飞行的投射物不受影响。
+    ///
+    /// # Arguments
+    ///
+    /// * `projectiles` - 一个可变的投射物向量引用。
+    /// * `plants` - 场上所有植物。
+    pub fn handle_projectile_plant_interaction(projectiles: &mut [Projectile], plants: &[Plant]) {
+        for projectile in projectiles.iter_mut().filter(|projectile| projectile.active && !projectile.hostile) {
+            let cell = match projectile.lane_cell() {
+                Some(cell) => cell,
+                None => continue,
+            };
+            if !projectile.enter_cell(cell) {
+                continue;
+            }
+
+            let converted = plants
+                .iter()
+                .filter(|plant| !plant.is_dead && (plant.grid_x, plant.grid_y) == cell)
+                .find_map(|plant| plant.convert_projectile(projectile.kind));
+            if let Some(kind) = converted {
+                projectile.convert_to(kind);
+            }
+        }
+    }
+
+    /// 找到落地的抛物线投掷物命中的僵尸：落点附近、同一行中最近的存活僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `projectile` - 刚落地的投掷物。
+    /// * `zombies` - 场上所有僵尸。
+    /// * `x` - 落点的X坐标。
+    ///
+    /// # Returns
+    ///
+    /// 返回被命中的僵尸在列表中的索引，没有命中任何僵尸时返回 `None`。
+    fn find_landing_target(projectile: &Projectile, zombies: &[Zombie], x: f32) -> Option<usize> {
+        let distance = |zombie: &Zombie| {
+            let rect = zombie.get_rect();
+            (rect.x + rect.w / 2.0 - x).abs()
+        };
+
+        zombies
+            .iter()
+            .enumerate()
+            .filter(|(_, zombie)| !zombie.is_dying && zombie.row == projectile.row)
+            .filter(|(_, zombie)| {
+                let rect = zombie.get_rect();
+                x >= rect.x - LOB_HIT_TOLERANCE && x <= rect.x + rect.w + LOB_HIT_TOLERANCE
+            })
+            .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+            .map(|(i, _)| i)
+    }
+
+    /// 对僵尸造成投射物的伤害并施加命中效果。
+    ///
+    /// # Arguments
+    ///
+    /// * `projectile` - 命中僵尸的投射物。
+    /// * `zombie` - 被命中的僵尸。
+    ///
+    /// # Returns
+    ///
+    /// 如果僵尸因此次命中而死亡，返回 `true`。
+    fn hit_zombie(projectile: &Projectile, zombie: &mut Zombie) -> bool {
+        for &effect in &projectile.effects {
+            zombie.apply_effect(effect);
+        }
+
+        zombie.take_hit(projectile.damage, projectile.hit_kind())
+    }
+
+    /// 处理落地的敌方投射物：被保护伞类植物挡住，或伤害落点单元格中最外层的植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `projectile` - 落地的敌方投射物。
+    /// * `plants` - 场上所有植物。
+    /// * `geometry` - 草坪的几何信息，用于将落点转换为单元格。
+    fn land_on_plants(projectile: &Projectile, plants: &mut [Plant], geometry: &LawnGeometry) {
+        let (x, y) = projectile.target().unwrap_or((projectile.x, projectile.y));
+        let (col, row) = match geometry.cell_at(x, y) {
+            Some(cell) => cell,
+            None => return,
+        };
+
+        // 保护伞类植物挡住落在周围一格内的敌方投掷物
+        let blocked = plants.iter().any(|plant| {
+            !plant.is_dead && plant.blocks_lobbed() && plant.grid_x.abs_diff(col) <= 1 && plant.grid_y.abs_diff(row) <= 1
+        });
+        if blocked {
+            return;
+        }
+
+        if let Some(plant) = plants
+            .iter_mut()
+            .filter(|plant| !plant.is_dead && plant.grid_x == col && plant.grid_y == row)
+            .max_by_key(|plant| plant.get_plant_type().layer())
+        {
+            plant.take_damage(projectile.damage);
+        }
     }
 
     /// 处理僵尸与植物之间的交互（主要是攻击）。
@@ -85,16 +231,23 @@ impl CollisionManager {
     /// 2. 检查僵尸是否在其正前方（同一行且x坐标接近）遇到植物。
     /// 3. 如果遇到，则将僵尸设置为攻击状态，记录攻击目标，并使僵尸对植物造成伤害。
     /// 4. 检查植物在受到伤害后是否死亡，如果死亡则标记。
-    /// 5. 一个僵尸同时只能攻击一个植物，一旦找到目标则停止对当前僵尸的植物搜索。
+    /// 5. 一个僵尸同时只能攻击一个植物；同一单元格中有多层植物时，先攻击最外面的一层。
     ///
-    /// 完成遍历后，更新所有僵尸的攻击状态，并移除所有已死亡的植物。
+    /// 完成遍历后，更新所有僵尸的攻击状态，移除所有已死亡的植物并清空它们在网格中占据的层。
     ///
     /// # Arguments
     ///
     /// * `zombies` - 一个可变的僵尸向量引用。
     /// * `plants` - 一个可变的植物向量引用。
-    /// * `ctx` - ggez的上下文环境，主要用于获取时间增量以计算攻击伤害。
-    pub fn handle_zombie_plant_interaction(zombies: &mut Vec<Zombie>, plants: &mut Vec<Plant>, ctx: &mut Context) {
+    /// * `grid` - 可变的网格引用，植物死亡后对应的层会被清空，可以重新种植。
+    /// * `dt` - 自上次更新以来的时间增量（毫秒），用于计算攻击伤害。
+    ///
+    /// # Returns
+    ///
+    /// 返回本次被植物杀死的僵尸数量（例如被缠绕水草拖入水下）。
+    pub fn handle_zombie_plant_interaction(zombies: &mut [Zombie], plants: &mut Vec<Plant>, grid: &mut Grid, dt: u64) -> usize {
+        let mut kills = 0;
+
         // 遍历所有僵尸
         for zombie in zombies {
             // 如果僵尸已经死亡，跳过
@@ -105,28 +258,34 @@ impl CollisionManager {
             let mut is_attacking = false;
             let mut target_index = None;
             
-            // 检查是否有植物在僵尸前方
-            for (i, plant) in plants.iter_mut().enumerate() {
-                // 如果植物已经死亡，跳过
-                if plant.is_dead {
-                    continue;
-                }
-                
-                // 检查僵尸是否可以攻击这个植物
-                if zombie.has_plant_in_front(plant.grid_x, plant.grid_y) {
+            // 检查是否有植物在僵尸前方，同一单元格中先攻击最外面的一层（南瓜头 > 主层植物 > 睡莲）
+            let target = plants
+                .iter()
+                .enumerate()
+                .filter(|(_, plant)| !plant.is_dead && zombie.has_plant_in_front(plant.grid_x, plant.grid_y))
+                .max_by_key(|(_, plant)| plant.get_plant_type().layer())
+                .map(|(i, _)| i);
+
+            if let Some(i) = target {
+                let plant = &mut plants[i];
+
+                if plant.grabs_zombie() {
+                    // 缠绕水草把僵尸拖入水下，自己也随之消失
+                    zombie.drown();
+                    plant.is_dead = true;
+                    kills += 1;
+                } else {
                     // 设置僵尸为攻击状态
                     is_attacking = true;
                     target_index = Some(i);
-                    
+
                     // 僵尸攻击植物
-                    zombie.attack_plant(&mut plant.health, timer::delta(ctx).as_millis() as u64);
-                    
+                    zombie.attack_plant(&mut plant.health, dt);
+
                     // 检查植物是否死亡
                     if plant.health <= 0 {
                         plant.is_dead = true;
                     }
-                    
-                    break; // 一个僵尸同时只能攻击一个植物
                 }
             }
             
@@ -134,7 +293,12 @@ impl CollisionManager {
             zombie.set_attacking(is_attacking, target_index);
         }
         
-        // 移除死亡的植物
+        // 移除死亡的植物，并清空它们在网格中占据的层
+        for plant in plants.iter().filter(|plant| plant.is_dead) {
+            grid.remove(plant.grid_x, plant.grid_y, plant.get_plant_type());
+        }
         plants.retain(|plant| !plant.is_dead);
+
+        kills
     }
 }
\ No newline at end of file
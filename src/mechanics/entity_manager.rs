@@ -7,6 +7,9 @@
 use crate::zombies::{Zombie, ZombieType};
 use crate::entities::sun::{Sun, SunType};
 use crate::mechanics::level_controller::{LevelController, ZombieSpawnInfo};
+use crate::mechanics::campaign::LevelDefinition;
+use crate::core::tuning;
+use crate::ui::lawn::{LawnLayout, TileType};
 use rand::Rng;
 
 /// 实体管理器结构体。
@@ -22,6 +25,12 @@ pub struct EntityManager {
     
     /// 下一次自然阳光生成的倒计时（厘秒）
     next_sun_countdown: u32,
+
+    /// 是否会自然掉落阳光，夜间关卡中为 `false`
+    natural_sun: bool,
+
+    /// 当前关卡的草坪布局，新生成的僵尸位于这块草坪上，在水面行中会游泳
+    layout: LawnLayout,
 }
 
 impl EntityManager {
@@ -34,13 +43,49 @@ impl EntityManager {
     ///
     /// 返回一个新的 `EntityManager` 实例。
     pub fn new() -> Self {
-        // 初始阳光倒计时设置为 425 + 随机数(0-275)厘秒
-        let initial_countdown = 425 + rand::thread_rng().gen_range(0..=275);
+        // 初始阳光倒计时设置为 基础等待时间 + 随机数(0-随机上限)厘秒，默认为 425 + rand(0, 275)
+        let sun = tuning::current().sun;
+        let initial_countdown = sun.first_delay + rand::thread_rng().gen_range(0..=sun.random_delay);
         
         EntityManager {
             level_controller: LevelController::new(),
             fallen_sun_count: 0,
             next_sun_countdown: initial_countdown,
+            natural_sun: true,
+            layout: LawnLayout::standard(),
+        }
+    }
+
+    /// 为冒险模式中的指定关卡创建 `EntityManager`。
+    ///
+    /// 内部的 `LevelController` 会按照关卡定义配置波数和僵尸组成，
+    /// 夜间关卡不会自然掉落阳光。
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - 当前关卡的定义。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `EntityManager` 实例。
+    pub fn for_level(level: &LevelDefinition) -> Self {
+        EntityManager {
+            level_controller: LevelController::for_level(level),
+            natural_sun: level.environment.has_natural_sun(),
+            layout: level.layout.clone(),
+            ..EntityManager::new()
+        }
+    }
+
+    /// 为无尽模式创建 `EntityManager`。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个内部使用无尽模式 `LevelController` 的 `EntityManager` 实例。
+    pub fn endless() -> Self {
+        EntityManager {
+            level_controller: LevelController::endless(),
+            ..EntityManager::new()
         }
     }
 
@@ -70,7 +115,12 @@ impl EntityManager {
     /// # Returns
     ///
     /// 如果当前应该生成阳光，则返回 `true`，否则返回 `false`。
+    /// 不会自然掉落阳光的关卡（如夜间关卡）始终返回 `false`。
     pub fn check_natural_sun_spawn(&mut self, dt: u64) -> bool {
+        if !self.natural_sun {
+            return false;
+        }
+
         // 将毫秒转换为厘秒
         let dt_centiseconds = dt * 100 / 1000;
         self.should_spawn_natural_sun(dt_centiseconds)
@@ -85,9 +135,32 @@ impl EntityManager {
     ///
     /// # Returns
     ///
-    /// 返回一个新创建的 `Zombie` 实例。
+    /// 返回一个新创建的 `Zombie` 实例，位于当前关卡的草坪上。
+    /// 如果所在行是水面，僵尸会处于游泳状态。
     pub fn spawn_zombie(&self, zombie_type: ZombieType, row: usize) -> Zombie {
-        Zombie::new(zombie_type, row)
+        let mut zombie = Zombie::on_lawn(zombie_type, row, self.layout.geometry);
+        zombie.set_swimming(self.layout.tile(row) == TileType::Water);
+        zombie
+    }
+
+    /// 在每个墓碑的位置生成一个从墓碑中爬出的普通僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `graves` - 墓碑的网格坐标 `(grid_x, grid_y)` 列表。
+    ///
+    /// # Returns
+    ///
+    /// 返回新生成的僵尸，每个墓碑一个，僵尸的x坐标与墓碑所在单元格对齐。
+    pub fn spawn_grave_zombies(&self, graves: &[(usize, usize)]) -> Vec<Zombie> {
+        graves
+            .iter()
+            .map(|&(grid_x, grid_y)| {
+                let mut zombie = self.spawn_zombie(ZombieType::Normal, grid_y);
+                zombie.x = self.layout.geometry.column_x(grid_x);
+                zombie
+            })
+            .collect()
     }
 
     /// 生成一个自然掉落的阳光实例。
@@ -120,10 +193,11 @@ impl EntityManager {
             // 倒计时结束，应当生成阳光
             self.fallen_sun_count += 1;
             
-            // 计算新的等待时间
-            // min{已掉落阳光数量 × 10 + 425, 950} + rand(0, 275)
-            let base_time = std::cmp::min(self.fallen_sun_count * 10 + 425, 950);
-            let random_addition = rand::thread_rng().gen_range(0..=275);
+            // 计算新的等待时间，数值来自调校文件
+            // 默认为 min{已掉落阳光数量 × 10 + 425, 950} + rand(0, 275)
+            let sun = tuning::current().sun;
+            let base_time = std::cmp::min(self.fallen_sun_count * sun.delay_step + sun.first_delay, sun.max_delay);
+            let random_addition = rand::thread_rng().gen_range(0..=sun.random_delay);
             self.next_sun_countdown = base_time + random_addition;
             
             true
@@ -6,7 +6,19 @@
 use crate::zombies::{ZombieType, Zombie};
 use std::time::{Duration, Instant};
 use rand::Rng;
-use crate::ui::grid::GRID_HEIGHT;
+use crate::ui::lawn::LawnLayout;
+use crate::mechanics::campaign::LevelDefinition;
+
+/// 无尽模式中每面旗帜包含的波数，每面旗帜的最后一波是一大波僵尸。
+pub const ENDLESS_WAVES_PER_FLAG: usize = 5;
+/// 无尽模式中每坚持多少面旗帜后进入一次休整，玩家可以重新选卡。
+pub const ENDLESS_FLAGS_PER_BREAK: usize = 2;
+/// 无尽模式休整期的最长时间，超时后使用当前的卡片自动继续。
+pub const ENDLESS_BREAK_DURATION: Duration = Duration::from_secs(20);
+/// 无尽模式中会出现的僵尸类型，第一种是剩余预算不足以生成其他僵尸时使用的后备类型。
+///
+/// 无尽模式的波次组成和选卡界面展示的僵尸阵容（以及进入无尽模式时预先加载的贴图）都来自这个列表。
+pub const ENDLESS_ZOMBIES: &[ZombieType] = &[ZombieType::Normal, ZombieType::Conehead, ZombieType::Catapult];
 
 /// 定义了生成僵尸时所需的信息。
 ///
@@ -43,6 +55,24 @@ pub struct LevelController {
     all_zombies_spawned: bool,
     /// 是否正在等待开始第一波。
     waiting_for_first_wave: bool,
+    /// 从第几波（从0开始）开始出现路障僵尸，`None` 表示不出现。
+    conehead_from_wave: Option<usize>,
+    /// 从第几波（从0开始）开始出现投篮车僵尸，`None` 表示不出现。
+    catapult_from_wave: Option<usize>,
+    /// 是否为无尽模式。无尽模式会不断生成新的旗帜波次，永远不会胜利。
+    endless: bool,
+    /// 无尽模式中当前波次尚未生成的僵尸，按预算预先组成。
+    pending_spawns: Vec<ZombieType>,
+    /// 无尽模式中已经坚持过的旗帜数量。
+    flags_completed: usize,
+    /// 无尽模式中是否处于两面旗帜之间的休整期。
+    on_break: bool,
+    /// 本次休整期开始的时间点。
+    break_started: Instant,
+    /// 当前旗帜是否已经计入坚持过的旗帜数量，避免清场后重复计数。
+    flag_counted: bool,
+    /// 僵尸可以出现的行，由关卡的草坪布局决定。
+    spawn_rows: Vec<usize>,
 }
 
 impl LevelController {
@@ -66,8 +96,189 @@ impl LevelController {
             final_wave_announced: false,
             all_zombies_spawned: false,
             waiting_for_first_wave: true,
+            conehead_from_wave: Some(3), // 第四波开始出现路障僵尸
+            catapult_from_wave: None,
+            endless: false,
+            pending_spawns: Vec::new(),
+            flags_completed: 0,
+            on_break: false,
+            break_started: Instant::now(),
+            flag_counted: false,
+            spawn_rows: LawnLayout::standard().spawn_rows(),
+        }
+    }
+
+    /// 根据冒险模式中的关卡定义创建 `LevelController`。
+    ///
+    /// 波数、路障僵尸和投篮车僵尸的出现时机以及僵尸可以出现的行来自关卡定义，其余节奏与 `new` 相同。
+    /// 如果草坪布局中没有任何僵尸可以出现的行（例如全部是泥地），僵尸会从所有行中出现。
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - 当前关卡的定义。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个按关卡配置的 `LevelController` 实例。
+    pub fn for_level(level: &LevelDefinition) -> Self {
+        let mut spawn_rows = level.layout.spawn_rows();
+        if spawn_rows.is_empty() {
+            println!("Warning: level {} has no rows where zombies can spawn, using every row", level.id);
+            spawn_rows = (0..level.layout.geometry.rows.max(1)).collect();
+        }
+
+        LevelController {
+            total_waves: level.total_waves.max(1),
+            conehead_from_wave: level.conehead_from_wave,
+            catapult_from_wave: level.catapult_from_wave,
+            spawn_rows,
+            ..LevelController::new()
+        }
+    }
+
+    /// 创建一个无尽模式的 `LevelController`。
+    ///
+    /// 无尽模式没有总波数，每一波的僵尸按预算组成，预算随波次和旗帜数递增。
+    /// 每坚持 `ENDLESS_FLAGS_PER_BREAK` 面旗帜后会进入休整期，直到调用 `resume`
+    /// 或经过 `ENDLESS_BREAK_DURATION` 后自动继续。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个无尽模式的 `LevelController` 实例。
+    pub fn endless() -> Self {
+        let mut controller = LevelController {
+            total_waves: usize::MAX,
+            endless: true,
+            ..LevelController::new()
+        };
+        controller.prepare_endless_wave();
+        controller
+    }
+
+    /// 计算无尽模式中第 `wave` 波（从0开始）的僵尸预算。
+    ///
+    /// 预算随波次线性增长，每坚持一面旗帜额外增加，
+    /// 每面旗帜的最后一波（一大波僵尸）预算翻倍。
+    ///
+    /// # Arguments
+    ///
+    /// * `wave` - 波次索引，从0开始。
+    ///
+    /// # Returns
+    ///
+    /// 返回该波次可以用来生成僵尸的预算点数。
+    pub fn wave_budget(wave: usize) -> u32 {
+        let flag = (wave / ENDLESS_WAVES_PER_FLAG) as u32;
+        let budget = 2 + wave as u32 + flag * 3;
+        if wave % ENDLESS_WAVES_PER_FLAG == ENDLESS_WAVES_PER_FLAG - 1 {
+            budget * 2
+        } else {
+            budget
+        }
+    }
+
+    /// 获取一种僵尸在无尽模式中占用的预算点数。
+    pub fn spawn_cost(zombie_type: ZombieType) -> u32 {
+        match zombie_type {
+            ZombieType::Normal => 1,
+            ZombieType::Conehead => 2,
+            ZombieType::Catapult => 4,
+            // 自定义僵尸按生命值折算，每相当于一个普通僵尸的生命值占用一点
+            ZombieType::Custom(_) => (zombie_type.spec().health / ZombieType::Normal.spec().health).max(1) as u32,
+        }
+    }
+
+    /// 按预算从 `ENDLESS_ZOMBIES` 中随机组成一波僵尸，所有僵尸的预算点数之和恰好等于 `budget`。
+    ///
+    /// # Arguments
+    ///
+    /// * `budget` - 本波的预算点数。
+    ///
+    /// # Returns
+    ///
+    /// 返回本波需要生成的僵尸类型列表。
+    pub fn compose_wave(budget: u32) -> Vec<ZombieType> {
+        let mut rng = rand::thread_rng();
+        let mut remaining = budget;
+        let mut wave = Vec::new();
+
+        while remaining > 0 {
+            // 从阵容末尾开始，预算足够时以一定几率选中，都没有选中时使用后备类型
+            let zombie_type = ENDLESS_ZOMBIES[1..]
+                .iter()
+                .rev()
+                .copied()
+                .find(|&zombie_type| remaining >= LevelController::spawn_cost(zombie_type) && rng.gen_bool(0.4))
+                .unwrap_or(ENDLESS_ZOMBIES[0]);
+            remaining -= LevelController::spawn_cost(zombie_type);
+            wave.push(zombie_type);
+        }
+
+        wave
+    }
+
+    /// 冒险模式中决定是否生成一种特殊僵尸：达到 `from_wave` 后出现，之后每一波的几率增加 10%。
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - 随机数生成器。
+    /// * `from_wave` - 这种僵尸开始出现的波次，`None` 表示本关不出现。
+    /// * `wave` - 当前波次，从0开始。
+    fn rolls_special(rng: &mut impl Rng, from_wave: Option<usize>, wave: usize) -> bool {
+        match from_wave {
+            Some(from_wave) if wave >= from_wave => rng.gen_range(0..10) < (wave - from_wave + 1),
+            _ => false,
+        }
+    }
+
+    /// 为无尽模式的当前波次组成僵尸，并设置本波的生成节奏。
+    fn prepare_endless_wave(&mut self) {
+        self.pending_spawns = LevelController::compose_wave(LevelController::wave_budget(self.current_wave));
+        self.zombies_per_wave = self.pending_spawns.len();
+        self.zombies_spawned_in_wave = 0;
+
+        // 一大波僵尸时缩短生成间隔
+        self.spawn_interval = if self.current_wave % ENDLESS_WAVES_PER_FLAG == ENDLESS_WAVES_PER_FLAG - 1 {
+            Duration::from_secs(1)
+        } else {
+            Duration::from_secs(2)
+        };
+    }
+
+    /// 僵尸可以出现的行。
+    pub fn spawn_rows(&self) -> &[usize] {
+        &self.spawn_rows
+    }
+
+    /// 是否为无尽模式。
+    pub fn is_endless(&self) -> bool {
+        self.endless
+    }
+
+    /// 获取无尽模式中已经坚持过的旗帜数量。
+    pub fn flags_completed(&self) -> usize {
+        self.flags_completed
+    }
+
+    /// 无尽模式中是否处于休整期。休整期间不会生成僵尸。
+    pub fn is_on_break(&self) -> bool {
+        self.on_break
+    }
+
+    /// 获取无尽模式休整期的剩余时间，不在休整期时返回 `None`。
+    pub fn break_remaining(&self) -> Option<Duration> {
+        if self.on_break {
+            Some(ENDLESS_BREAK_DURATION.saturating_sub(self.break_started.elapsed()))
+        } else {
+            None
         }
     }
+
+    /// 结束休整期，继续生成下一面旗帜的僵尸。
+    pub fn resume(&mut self) {
+        self.on_break = false;
+        self.last_spawn_time = Instant::now();
+    }
     
     /// 获取当前是否已经宣布最后一波来袭。
     ///
@@ -137,8 +348,13 @@ impl LevelController {
         let mut spawns = Vec::new();
         let now = Instant::now();
         
+        // 休整期超时后自动继续
+        if self.break_remaining() == Some(Duration::ZERO) {
+            self.resume();
+        }
+
         // 如果已经生成了所有僵尸，直接返回
-        if self.all_zombies_spawned {
+        if self.all_zombies_spawned || self.on_break {
             return spawns;
         }
         
@@ -158,10 +374,15 @@ impl LevelController {
             if self.zombies_spawned_in_wave < self.zombies_per_wave {
                 // 在波次内生成一个僵尸
                 let mut rng = rand::thread_rng();
-                let row = rng.gen_range(0..GRID_HEIGHT);
+                let row = self.spawn_rows[rng.gen_range(0..self.spawn_rows.len())];
                 
-                // 根据波次决定僵尸类型，只有第四波开始才会出现路障僵尸
-                let zombie_type = if self.current_wave >= 3 && rng.gen_range(0..10) < (self.current_wave - 2) {
+                // 无尽模式按预先组成的队列生成；
+                // 冒险模式根据波次决定僵尸类型，只有达到关卡配置的波次后才会出现路障僵尸和投篮车僵尸
+                let zombie_type = if self.endless {
+                    self.pending_spawns.pop().unwrap_or(ZombieType::Normal)
+                } else if LevelController::rolls_special(&mut rng, self.catapult_from_wave, self.current_wave) {
+                    ZombieType::Catapult
+                } else if LevelController::rolls_special(&mut rng, self.conehead_from_wave, self.current_wave) {
                     ZombieType::Conehead
                 } else {
                     ZombieType::Normal
@@ -179,10 +400,32 @@ impl LevelController {
             } 
             // 如果当前波次中的僵尸已经全部生成完毕，并且场上没有存活的僵尸，考虑进入下一波
             else if self.is_wave_cleared(zombies) {
+                // 无尽模式：一面旗帜的最后一波被清空时立即计为坚持了一面旗帜，每隔几面旗帜进入休整期
+                if self.endless
+                    && !self.flag_counted
+                    && (self.current_wave + 1).is_multiple_of(ENDLESS_WAVES_PER_FLAG)
+                {
+                    self.flag_counted = true;
+                    self.flags_completed += 1;
+                    if self.flags_completed.is_multiple_of(ENDLESS_FLAGS_PER_BREAK) {
+                        self.on_break = true;
+                        self.break_started = now;
+                        return spawns;
+                    }
+                }
+
                 // 波次间隔为6秒
                 if now.duration_since(self.last_spawn_time) >= Duration::from_secs(6) {
                     // 进入下一波
                     self.current_wave += 1;
+
+                    if self.endless {
+                        self.flag_counted = false;
+                        self.prepare_endless_wave();
+                        println!("无尽模式波次 {} 已开始，僵尸数量: {}", self.current_wave + 1, self.zombies_per_wave);
+                        self.last_spawn_time = now;
+                        return spawns;
+                    }
                     
                     // 判断是否所有波次已完成
                     if self.current_wave >= self.total_waves {
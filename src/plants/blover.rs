@@ -0,0 +1,31 @@
+//! # 三叶草模块 (`blover`)
+//!
+//! 实现了迷雾关卡中的一次性植物——三叶草。
+//! 三叶草种下后会旋转片刻，然后吹散草坪上的全部迷雾并消失。迷雾会在一段时间后重新出现。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+use crate::plants::world_effect::PlantEvent;
+
+/// 三叶草植物的结构体。
+///
+/// 三叶草没有特有状态，吹散迷雾前的等待时间由 `COOLDOWN` 控制。
+pub struct Blover;
+
+/// 三叶草种下后旋转多久才吹散迷雾（毫秒）。
+const COOLDOWN: u64 = 1000;
+
+impl PlantTrait for Blover {
+    /// 获取三叶草吹散迷雾前的等待时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 等待时间结束后，三叶草发出吹散迷雾的事件并移除自身。
+    ///
+    /// 实际的迷雾状态由游戏主循环在处理 `PlantEvent::BlowFog` 时更新。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        world.emit(PlantEvent::BlowFog);
+        world.remove_self();
+    }
+}
@@ -0,0 +1,55 @@
+//! # 卷心菜投手模块 (`cabbage_pult`)
+//!
+//! 实现了游戏中的投手类攻击单位——卷心菜投手。
+//! 卷心菜投手会周期性地向所在行中最近的僵尸投出卷心菜，卷心菜沿抛物线飞向僵尸的预测位置，
+//! 可以越过僵尸的正面护盾和屋顶的斜坡。
+
+use crate::entities::projectile::{Projectile, ProjectileKind};
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 卷心菜投手植物的结构体。
+///
+/// 卷心菜投手没有特有状态，投掷间隔由 `COOLDOWN` 控制。
+#[derive(Default)]
+pub struct CabbagePult;
+
+impl CabbagePult {
+    /// 创建一个新的 `CabbagePult` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `CabbagePult` 实例。
+    pub fn new() -> Self {
+        CabbagePult
+    }
+}
+
+/// 卷心菜投手投掷的冷却时间（毫秒）。
+const COOLDOWN: u64 = 3000;
+
+impl PlantTrait for CabbagePult {
+    /// 获取卷心菜投手投掷的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 向所在行中最近的僵尸投出一颗卷心菜。
+    ///
+    /// 如果所在行中没有僵尸，则不投掷。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 卷心菜投手的世界上下文，用于选择所在行的目标僵尸并投出卷心菜。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        let geometry = *world.geometry();
+        let start = (
+            geometry.column_x(world.grid_x()) + geometry.cell_width * 0.5,
+            geometry.row_y(world.grid_y()) + geometry.cell_height * 0.2,
+        );
+        let targets = world.zombies_in_lane(world.grid_y());
+        if let Some(lob) = Projectile::aimed_at_zombie(start, world.grid_y(), ProjectileKind::Cabbage, targets, &geometry) {
+            world.spawn_projectile(lob);
+        }
+    }
+}
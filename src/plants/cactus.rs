@@ -0,0 +1,49 @@
+//! # 仙人掌模块 (`cactus`)
+//!
+//! 实现了游戏中的穿透攻击单位——仙人掌。
+//! 仙人掌会周期性地向其所在行的前方发射尖刺，尖刺可以穿透多个僵尸。
+
+use crate::entities::projectile::{Projectile, ProjectileKind};
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 仙人掌植物的结构体。
+///
+/// 仙人掌没有特有状态，发射间隔由 `COOLDOWN` 控制。
+#[derive(Default)]
+pub struct Cactus;
+
+impl Cactus {
+    /// 创建一个新的 `Cactus` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Cactus` 实例。
+    pub fn new() -> Self {
+        Cactus
+    }
+}
+
+/// 仙人掌发射尖刺的冷却时间（毫秒）。
+const COOLDOWN: u64 = 1400;
+
+impl PlantTrait for Cactus {
+    /// 获取仙人掌发射尖刺的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 如果所在行前方有僵尸，向前发射一根尖刺。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 仙人掌的世界上下文，用于检查前方是否有僵尸并发射尖刺。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        if world.zombies_ahead().next().is_some() {
+            let geometry = *world.geometry();
+            let x = geometry.column_x(world.grid_x()) + geometry.cell_width * 0.8;
+            let y = geometry.row_y(world.grid_y()) + geometry.cell_height * 0.3;
+            world.spawn_projectile(Projectile::straight_on_lawn(x, y, world.grid_y(), ProjectileKind::Spike, geometry));
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! # 大嘴花模块 (`chomper`)
+//!
+//! 实现了游戏中的近战秒杀单位——大嘴花。
+//! 大嘴花会一口吞掉前方约一格内的第一个僵尸（巨人僵尸等无法被吞下的僵尸除外），
+//! 之后需要花费较长时间咀嚼消化，消化期间无法再次吞食，容易被其他僵尸啃食。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 大嘴花植物的结构体。
+#[derive(Default)]
+pub struct Chomper {
+    /// 消化剩余的时间（毫秒），为 0 时大嘴花可以再次吞食僵尸。
+    digest_remaining: u64,
+}
+
+impl Chomper {
+    /// 创建一个新的 `Chomper` 实例，初始时可以立即吞食僵尸。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Chomper` 实例。
+    pub fn new() -> Self {
+        Chomper { digest_remaining: 0 }
+    }
+
+    /// 大嘴花是否正在消化。
+    pub fn is_digesting(&self) -> bool {
+        self.digest_remaining > 0
+    }
+}
+
+/// 大嘴花检查前方僵尸的间隔（毫秒）。
+const COOLDOWN: u64 = 100;
+/// 吞下一个僵尸后消化所需的时间（毫秒）。
+pub const DIGEST_DURATION: u64 = 42000;
+/// 大嘴花能咬到的距离（单元格数），从自身所在单元格的右边缘算起。
+const BITE_RANGE_CELLS: f32 = 1.0;
+
+impl PlantTrait for Chomper {
+    /// 获取大嘴花检查前方僵尸的间隔。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 消化期间按经过的时间推进消化计时；否则吞掉前方一格内最近的可以被吞下的僵尸，并开始消化。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 大嘴花的世界上下文，用于查找能咬到的僵尸并吞掉它。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        if self.is_digesting() {
+            self.digest_remaining = self.digest_remaining.saturating_sub(world.elapsed());
+            return;
+        }
+
+        let near = world.geometry().column_x(world.grid_x());
+        let far = world.geometry().column_x(world.grid_x() + 1) + world.geometry().cell_width * BITE_RANGE_CELLS;
+        let target = world
+            .zombies_in_range(world.grid_y(), near, far)
+            .filter(|zombie| zombie.can_be_swallowed())
+            .min_by(|a, b| a.x.total_cmp(&b.x))
+            .map(|zombie| zombie.id());
+
+        if let Some(zombie_id) = target {
+            world.swallow_zombie(zombie_id);
+            self.digest_remaining = DIGEST_DURATION;
+        }
+    }
+
+    /// 消化期间播放咀嚼状态的动画片段。
+    fn animation_clip(&self) -> Option<&'static str> {
+        if self.is_digesting() {
+            Some("chomper_digest")
+        } else {
+            None
+        }
+    }
+}
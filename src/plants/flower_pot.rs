@@ -0,0 +1,40 @@
+//! # 花盆模块 (`flower_pot`)
+//!
+//! 实现了屋顶关卡中的底座植物——花盆。
+//! 花盆可以种在草地或屋顶上，本身没有任何主动能力，
+//! 但屋顶上的植物都必须种在花盆上。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 花盆植物的结构体。
+///
+/// 花盆没有特有状态。
+#[derive(Default)]
+pub struct FlowerPot;
+
+impl FlowerPot {
+    /// 创建一个新的 `FlowerPot` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `FlowerPot` 实例。
+    pub fn new() -> Self {
+        FlowerPot
+    }
+}
+
+/// 花盆没有主动动作，冷却时间为0表示从不触发 `update_action`。
+const COOLDOWN: u64 = 0;
+
+impl PlantTrait for FlowerPot {
+    /// 获取花盆的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 花盆没有主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 花盆没有主动动作
+    }
+}
@@ -0,0 +1,63 @@
+//! # 玉米投手模块 (`kernel_pult`)
+//!
+//! 实现了游戏中的投手类控制单位——玉米投手。
+//! 玉米投手通常投出伤害较低的玉米粒，但有一定几率投出黄油，
+//! 黄油命中后会使僵尸被定身一段时间，期间僵尸既不能移动也不能啃食植物。
+
+use crate::entities::projectile::{Projectile, ProjectileKind};
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+use rand::Rng;
+
+/// 玉米投手植物的结构体。
+///
+/// 玉米投手没有特有状态，每次投掷时随机决定投出玉米粒还是黄油。
+#[derive(Default)]
+pub struct KernelPult;
+
+impl KernelPult {
+    /// 创建一个新的 `KernelPult` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `KernelPult` 实例。
+    pub fn new() -> Self {
+        KernelPult
+    }
+}
+
+/// 玉米投手投掷的冷却时间（毫秒）。
+const COOLDOWN: u64 = 3000;
+/// 每次投掷投出黄油的几率。
+const BUTTER_CHANCE: f64 = 0.25;
+
+impl PlantTrait for KernelPult {
+    /// 获取玉米投手投掷的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 向所在行中最近的僵尸投出一颗玉米粒，或者有一定几率投出黄油。
+    ///
+    /// 如果所在行中没有僵尸，则不投掷。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 玉米投手的世界上下文，用于选择所在行的目标僵尸并投出玉米粒或黄油。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        let lob_type = if rand::thread_rng().gen_bool(BUTTER_CHANCE) {
+            ProjectileKind::Butter
+        } else {
+            ProjectileKind::Kernel
+        };
+        let geometry = *world.geometry();
+        let start = (
+            geometry.column_x(world.grid_x()) + geometry.cell_width * 0.5,
+            geometry.row_y(world.grid_y()) + geometry.cell_height * 0.2,
+        );
+        let targets = world.zombies_in_lane(world.grid_y());
+        if let Some(lob) = Projectile::aimed_at_zombie(start, world.grid_y(), lob_type, targets, &geometry) {
+            world.spawn_projectile(lob);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! # 睡莲模块 (`lily_pad`)
+//!
+//! 实现了泳池关卡中的水生植物——睡莲。
+//! 睡莲只能种植在水面上，本身没有任何主动能力，
+//! 但种下睡莲之后，其他陆地植物就可以种植在它上面。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 睡莲植物的结构体。
+///
+/// 睡莲没有特有状态。
+#[derive(Default)]
+pub struct LilyPad;
+
+impl LilyPad {
+    /// 创建一个新的 `LilyPad` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `LilyPad` 实例。
+    pub fn new() -> Self {
+        LilyPad
+    }
+}
+
+/// 睡莲没有主动动作，冷却时间为0表示从不触发 `update_action`。
+const COOLDOWN: u64 = 0;
+
+impl PlantTrait for LilyPad {
+    /// 获取睡莲的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 睡莲没有主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 睡莲没有主动动作
+    }
+}
@@ -0,0 +1,77 @@
+//! # 磁力菇模块 (`magnet_shroom`)
+//!
+//! 实现了游戏中的辅助单位——磁力菇。
+//! 磁力菇会吸走附近一个僵尸的护甲（例如路障），之后需要一段时间才能再次使用。
+
+use crate::entities::projectile::HitEffect;
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 磁力菇植物的结构体。
+#[derive(Default)]
+pub struct MagnetShroom {
+    /// 再次可用前剩余的时间（毫秒），为 0 时磁力菇可以吸走下一个僵尸的护甲。
+    recharge_remaining: u64,
+}
+
+impl MagnetShroom {
+    /// 创建一个新的 `MagnetShroom` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个可以立即使用的 `MagnetShroom` 实例。
+    pub fn new() -> Self {
+        MagnetShroom { recharge_remaining: 0 }
+    }
+
+    /// 磁力菇是否正在充能。
+    pub fn is_recharging(&self) -> bool {
+        self.recharge_remaining > 0
+    }
+}
+
+/// 磁力菇检查附近僵尸的间隔（毫秒）。
+const COOLDOWN: u64 = 100;
+/// 吸走一次护甲后再次可用所需的时间（毫秒）。
+pub const RECHARGE_DURATION: u64 = 15000;
+/// 磁力菇的作用半径（单元格数），从自身所在单元格的中心算起。
+const RANGE_CELLS: f32 = 2.5;
+
+impl PlantTrait for MagnetShroom {
+    /// 获取磁力菇检查附近僵尸的间隔。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 充能期间按经过的时间推进充能计时；否则打掉作用半径内最近的、还有护甲的僵尸的护甲，并开始充能。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 磁力菇的世界上下文，用于查找附近有护甲的僵尸并对它施加打掉护甲的效果。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        if self.is_recharging() {
+            self.recharge_remaining = self.recharge_remaining.saturating_sub(world.elapsed());
+            return;
+        }
+
+        let geometry = *world.geometry();
+        let center_x = geometry.column_x(world.grid_x()) + geometry.cell_width / 2.0;
+        let center_y = geometry.row_y(world.grid_y()) + geometry.cell_height / 2.0;
+        let distance = |rect: ggez::graphics::Rect| {
+            let dx = rect.x + rect.w / 2.0 - center_x;
+            let dy = rect.y + rect.h / 2.0 - center_y;
+            (dx * dx + dy * dy).sqrt()
+        };
+        let range = geometry.cell_width * RANGE_CELLS;
+        let target = world
+            .zombies()
+            .filter(|zombie| zombie.has_armor() && distance(zombie.get_rect()) <= range)
+            .min_by(|a, b| distance(a.get_rect()).total_cmp(&distance(b.get_rect())))
+            .map(|zombie| zombie.id());
+
+        if let Some(zombie_id) = target {
+            world.affect_zombie(zombie_id, HitEffect::KnockOffArmor);
+            self.recharge_remaining = RECHARGE_DURATION;
+        }
+    }
+}
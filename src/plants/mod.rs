@@ -3,20 +3,22 @@
 //! 本模块定义了游戏中所有类型的植物，包括它们的行为、属性、以及与游戏世界的交互方式。
 //!
 //! ## 主要组成部分：
-//! - **具体植物实现 (`peashooter`, `sunflower`, `wallnut`)**: 每个子模块实现了特定植物的逻辑，例如豌豆射手的攻击、向日葵产生阳光、坚果墙的防御等。
+//! - **具体植物实现 (`peashooter`, `sunflower`, `wallnut`, `lily_pad`, `tangle_kelp`, `pumpkin`, `plantern`, `blover`, `flower_pot`, `cabbage_pult`, `kernel_pult`, `snow_pea`, `torchwood`, `chomper`, `cactus`, `starfruit`, `magnet_shroom`, `umbrella_leaf`)**: 每个子模块实现了特定植物的逻辑，例如豌豆射手的攻击、向日葵产生阳光、坚果墙的防御、睡莲作为水面上的底座等。
 //! - **植物特征 (`plant_trait`)**: 定义了一个 `PlantTrait`，所有具体植物都需要实现此特征，以提供统一的接口供游戏逻辑调用（如获取生命值、冷却时间、执行动作等）。
+//! - **植物上下文 (`plant_context`)**: 定义了 `PlantContext`，植物在更新时通过它查询僵尸，并向命令缓冲区写入 `WorldEffect`（发射投射物、产生阳光、伤害僵尸、移除自身等），由游戏主循环统一执行。
 //! - **植物工厂 (`plant_factory`)**: 提供了一个工厂模式 (`PlantFactory`)，用于根据植物类型 (`PlantType` 枚举) 创建具体的植物实例。
 //!
 //! ## 通用植物结构 (`Plant`):
 //! 此模块还定义了一个通用的 `Plant` 结构体，它封装了所有植物共有的属性（如网格位置、生命值、动画状态等），
 //! 并持有一个实现了 `PlantTrait` 的具体植物实例 (`Box<dyn PlantTrait>`)，通过这种方式实现多态。
 
-use ggez::{Context, GameResult};
-use ggez::graphics::{self, DrawParam};
+use crate::core::animation::Animator;
+use crate::core::registry::PlantSpec;
+use crate::core::render_cache::SpriteBatcher;
 use crate::core::resources::Resources;
-use crate::ui::grid::{GRID_START_X, GRID_START_Y, GRID_CELL_HEIGHT, GRID_CELL_WIDTH};
-use crate::entities::sun::Sun;
-use crate::entities::pea::Pea;
+use crate::core::tuning;
+use crate::ui::lawn::LawnGeometry;
+use crate::entities::projectile::ProjectileKind;
 use crate::zombies::Zombie; 
 
 // 声明子模块
@@ -26,13 +28,49 @@ pub mod peashooter;
 pub mod sunflower;
 /// 坚果墙植物的实现。
 pub mod wallnut;
+/// 睡莲植物的实现。
+pub mod lily_pad;
+/// 缠绕水草植物的实现。
+pub mod tangle_kelp;
+/// 南瓜头植物的实现。
+pub mod pumpkin;
+/// 路灯花植物的实现。
+pub mod plantern;
+/// 三叶草植物的实现。
+pub mod blover;
+/// 花盆植物的实现。
+pub mod flower_pot;
+/// 卷心菜投手植物的实现。
+pub mod cabbage_pult;
+/// 玉米投手植物的实现。
+pub mod kernel_pult;
+/// 寒冰射手植物的实现。
+pub mod snow_pea;
+/// 火炬树桩植物的实现。
+pub mod torchwood;
+/// 大嘴花植物的实现。
+pub mod chomper;
+/// 仙人掌植物的实现。
+pub mod cactus;
+/// 杨桃植物的实现。
+pub mod starfruit;
+/// 磁力菇植物的实现。
+pub mod magnet_shroom;
+/// 叶子保护伞植物的实现。
+pub mod umbrella_leaf;
+/// 植物对游戏世界产生的效果 (`WorldEffect`) 及其执行对象 (`WorldState`)。
+pub mod world_effect;
+/// 植物更新时使用的世界上下文 (`PlantContext`)。
+pub mod plant_context;
 /// 定义了所有植物应共享的行为特征 (`PlantTrait`)。
 pub mod plant_trait;
 /// 植物工厂，用于创建不同类型的植物实例。
 pub mod plant_factory;
 
 // 从工厂模块中重新导出植物类型枚举和工厂本身
-pub use plant_factory::{PlantType, PlantFactory};
+pub use plant_factory::{PlantType, PlantLayer, PlantFactory};
+pub use world_effect::{PlantEvent, WorldEffect, WorldState};
+pub use plant_context::PlantContext;
 
 /// 通用植物结构体，代表游戏中的一个已种植的植物。
 ///
@@ -46,10 +84,8 @@ pub struct Plant {
     pub grid_y: usize,
     /// 植物当前的生命值。
     pub health: i32,
-    /// 当前显示的动画帧索引。
-    animation_frame: usize,
-    /// 动画帧切换的计时器。
-    animation_timer: u64,
+    /// 植物的动画播放器，片段由具体植物的 `animation_clip` 决定。
+    animator: Animator,
     /// 植物特殊动作（如攻击、产生阳光）的冷却计时器。
     cooldown_timer: u64,
     /// 标记植物是否已经死亡。
@@ -58,13 +94,19 @@ pub struct Plant {
     plant_impl: Box<dyn plant_trait::PlantTrait>,
     /// 植物的类型，用于区分不同种类的植物。
     plant_type: PlantType,
+    /// 创建植物时从注册表读取的规格，调校文件重新加载后通过 `refresh_spec` 更新。
+    spec: PlantSpec,
+    /// 调校文件覆盖的动作间隔（毫秒），没有覆盖时为 `None`，与 `spec` 一起缓存。
+    tuned_cooldown: Option<u64>,
+    /// 植物所在草坪的几何信息，用于计算屏幕坐标。
+    geometry: LawnGeometry,
 }
 
 impl Plant {
     /// 创建一个新的 `Plant` 实例。
     ///
     /// 使用 `PlantFactory` 根据指定的 `plant_type` 创建具体的植物实现 (`plant_impl`)，
-    /// 并初始化其生命值等通用属性。
+    /// 并按注册表中的规格初始化其生命值等通用属性。
     ///
     /// # Arguments
     ///
@@ -74,100 +116,111 @@ impl Plant {
     ///
     /// # Returns
     ///
-    /// 返回一个新创建的 `Plant` 实例。
+    /// 返回一个新创建的 `Plant` 实例，位于标准草坪上。
     pub fn new(plant_type: PlantType, grid_x: usize, grid_y: usize) -> Self {
+        Plant::on_lawn(plant_type, grid_x, grid_y, LawnGeometry::STANDARD)
+    }
+
+    /// 在指定布局的草坪上创建一个新的 `Plant` 实例。
+    ///
+    /// # Arguments
+    ///
+    /// * `plant_type` - 要创建的植物的类型 (`PlantType`)。
+    /// * `grid_x` - 植物放置的网格x坐标。
+    /// * `grid_y` - 植物放置的网格y坐标。
+    /// * `geometry` - 草坪的几何信息。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新创建的 `Plant` 实例。
+    pub fn on_lawn(plant_type: PlantType, grid_x: usize, grid_y: usize, geometry: LawnGeometry) -> Self {
         // 使用工厂创建具体植物实现
         let plant_impl = PlantFactory::create_plant(plant_type);
-        let health = plant_impl.get_initial_health();
+        let spec = plant_type.spec();
+        let animator = Animator::new(plant_impl.animation_clip().unwrap_or(spec.image));
 
         Plant {
             grid_x,
             grid_y,
-            health,
-            animation_frame: 0,
-            animation_timer: 0,
+            health: spec.health,
+            animator,
             cooldown_timer: 0,
             is_dead: false,
             plant_impl,
             plant_type,
+            spec,
+            tuned_cooldown: tuning::current().action_cooldown(spec.id),
+            geometry,
         }
     }
 
+    /// 重新读取注册表中的规格和调校文件覆盖的动作间隔。
+    ///
+    /// 规格在创建植物时缓存，更新和绘制时不再访问注册表，因此调校文件重新加载后需要调用此方法。
+    /// 当前生命值保持不变，只是不会超过新的初始生命值。
+    pub fn refresh_spec(&mut self) {
+        self.spec = self.plant_type.spec();
+        self.tuned_cooldown = tuning::current().action_cooldown(self.spec.id);
+        self.health = self.health.min(self.spec.health);
+    }
+
     /// 更新植物的状态，包括动画、冷却和执行特定动作。
     ///
     /// 如果植物未死亡：
-    /// 1. 更新动画计时器和动画帧。
+    /// 1. 按具体植物的 `animation_clip` 切换动画片段并推进动画。
     /// 2. 更新冷却计时器；如果冷却完成，则调用具体植物实现的 `update_action` 方法，
-    ///    并重置冷却计时器。`update_action` 可能会产生新的阳光或豌豆。
+    ///    并重置冷却计时器。`update_action` 可能会产生新的阳光、投射物等世界效果。
     /// 3. 调用具体植物实现的 `special_effect` 方法（如果存在）。
     ///
     /// # Arguments
     ///
     /// * `dt` - 自上次更新以来的时间增量（毫秒）。
-    /// * `suns` - 一个可变向量的引用，用于收集由向日葵等植物产生的阳光。
-    /// * `peas` - 一个可变向量的引用，用于收集由豌豆射手等植物发射的豌豆。
-    /// * `zombies` - 一个不可变的引用，代表当前场上所有僵尸的状态。
-    pub fn update(&mut self, dt: u64, suns: &mut Vec<Sun>, peas: &mut Vec<Pea>, zombies: &Vec<Zombie>) {
+    /// * `zombies` - 当前场上所有僵尸的状态。
+    /// * `effects` - 命令缓冲区，用于收集植物对游戏世界产生的效果，由调用者通过 `WorldEffect::apply` 执行。
+    pub fn update(&mut self, dt: u64, zombies: &[Zombie], effects: &mut Vec<WorldEffect>) {
         if self.is_dead {
             return; // 如果植物已经死亡，跳过更新
         }
 
-        // 动画更新
-        self.animation_timer += dt;
-        if self.animation_timer > 100 { // 每100ms更新一次帧动画
-            // 获取植物动画帧数
-            let frame_count = self.plant_impl.get_frame_count();
-            if frame_count > 0 {
-                self.animation_frame = (self.animation_frame + 1) % frame_count;
-            }
-            self.animation_timer = 0;
-        }
+        // 动画更新，植物的状态变化时切换片段
+        self.animator.play(self.plant_impl.animation_clip().unwrap_or(self.spec.image));
+        self.animator.update(dt);
 
-        // 冷却更新和动作执行
-        let cooldown = self.plant_impl.get_cooldown();
+        // 冷却更新和动作执行，调校文件可以覆盖植物自身的动作间隔
+        let cooldown = self.tuned_cooldown.unwrap_or_else(|| self.plant_impl.get_cooldown());
         if cooldown > 0 {
             self.cooldown_timer += dt;
             if self.cooldown_timer >= cooldown {
-                self.cooldown_timer = 0; // 重置计时器
+                // 把自上次动作以来实际经过的时间交给植物，再重置计时器
+                let elapsed = self.cooldown_timer;
+                self.cooldown_timer = 0;
 
                 // 调用特定植物的 update_action 方法
-                self.plant_impl.update_action(self.grid_x, self.grid_y, suns, peas, zombies);
+                let mut world = PlantContext::new(self.grid_x, self.grid_y, self.plant_type, self.geometry, elapsed, zombies, effects);
+                self.plant_impl.update_action(&mut world);
             }
         }
-        
+
         // 检查特殊效果
-        self.plant_impl.special_effect(self.grid_x, self.grid_y);
+        let mut world = PlantContext::new(self.grid_x, self.grid_y, self.plant_type, self.geometry, dt, zombies, effects);
+        self.plant_impl.special_effect(&mut world);
     }
 
     /// 绘制植物到屏幕上。
     ///
-    /// 计算植物在屏幕上的精确绘制位置，并调用具体植物实现的 `get_current_frame_image`
-    /// 来获取当前应显示的动画帧图像进行绘制。
+    /// 计算植物在屏幕上的精确绘制位置，并把动画播放器当前的帧加入精灵批处理器。
     ///
     /// # Arguments
     ///
-    /// * `ctx` - ggez的上下文环境。
+    /// * `batcher` - 精灵批处理器，由渲染器在图层结束时统一绘制。
     /// * `resources` - 游戏资源，用于获取植物图像。
-    ///
-    /// # Returns
-    ///
-    /// 返回一个 `GameResult`，表示绘制操作是否成功。
-    pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
+    pub fn draw(&self, batcher: &mut SpriteBatcher, resources: &Resources) {
         // 计算植物在屏幕上的位置（加上少许偏移）
-        let x = GRID_START_X + (self.grid_x as f32) * GRID_CELL_WIDTH + GRID_CELL_WIDTH / 4.0;
-        let y = GRID_START_Y + (self.grid_y as f32) * GRID_CELL_HEIGHT + GRID_CELL_HEIGHT / 4.0;
-
-        // 获取当前植物状态对应的图像
-        let image = self.plant_impl.get_current_frame_image(resources, self.animation_frame);
-
-        // 绘制图像
-        graphics::draw(
-            ctx,
-            image,
-            DrawParam::default()
-                .dest([x, y])
-                .scale([0.8, 0.8]),
-        )
+        let x = self.geometry.column_x(self.grid_x) + self.geometry.cell_width / 4.0;
+        let y = self.geometry.row_y(self.grid_y) + self.geometry.cell_height / 4.0;
+
+        // 按资源清单中当前片段的原点和缩放绘制图像
+        batcher.add_animator(resources, &self.animator, resources.draw_param(self.animator.clip_name()).dest([x, y]));
     }
 
     /// 使植物受到指定量的伤害。
@@ -192,6 +245,18 @@ impl Plant {
         
         false  // 返回false表示植物仍然存活
     }
+
+    /// 治疗植物，生命值不会超过注册表中登记的初始生命值。已经死亡的植物不会被治疗。
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - 治疗量。
+    pub fn heal(&mut self, amount: i32) {
+        if self.is_dead {
+            return;
+        }
+        self.health = (self.health + amount).min(self.spec.health);
+    }
     
     /// 获取植物的损坏状态。
     ///
@@ -214,4 +279,28 @@ impl Plant {
     pub fn get_plant_type(&self) -> PlantType {
         self.plant_type
     }
+
+    /// 僵尸碰到该植物时是否会被立即拖走（例如缠绕水草）。
+    pub fn grabs_zombie(&self) -> bool {
+        self.plant_impl.grabs_zombie()
+    }
+
+    /// 植物驱散迷雾的半径（单元格数），0 表示不照亮任何单元格。
+    pub fn light_radius(&self) -> f32 {
+        self.plant_impl.light_radius()
+    }
+
+    /// 植物是否会挡住落在附近的敌方抛物线投掷物（例如保护伞类植物）。
+    pub fn blocks_lobbed(&self) -> bool {
+        self.plant_impl.blocks_lobbed()
+    }
+
+    /// 沿行飞过该植物所在单元格的投射物会被转换成的类型，`None` 表示投射物保持原样。
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - 飞过的投射物的类型。
+    pub fn convert_projectile(&self, kind: ProjectileKind) -> Option<ProjectileKind> {
+        self.plant_impl.convert_projectile(kind)
+    }
 }
@@ -0,0 +1,210 @@
+//! # 植物上下文模块 (`plant_context`)
+//!
+//! 定义了植物在更新时获得的 `PlantContext`。它为植物提供：
+//! - 自身的位置、所在草坪的几何信息和本次更新经过的时间；
+//! - 按行、范围或单元格查询僵尸的方法；
+//! - 一个命令缓冲区，植物通过它发射投射物、产生阳光、伤害僵尸或施加状态效果、
+//!   伤害或治疗植物、移除自身以及发出事件。
+//!
+//! 命令缓冲区中的 `WorldEffect` 由游戏主循环在所有植物更新完成后统一执行，
+//! 因此植物在更新过程中看到的僵尸状态不会被其他植物的动作改变。
+
+use crate::entities::projectile::{HitEffect, Projectile};
+use crate::entities::sun::Sun;
+use crate::plants::world_effect::{PlantEvent, WorldEffect};
+use crate::plants::PlantType;
+use crate::ui::lawn::LawnGeometry;
+use crate::zombies::{HitKind, Zombie};
+
+/// 单株植物更新时使用的世界上下文。
+pub struct PlantContext<'a> {
+    /// 植物所在的网格x坐标。
+    grid_x: usize,
+    /// 植物所在的网格y坐标。
+    grid_y: usize,
+    /// 植物的类型，用于移除自身。
+    plant_type: PlantType,
+    /// 植物所在草坪的几何信息。
+    geometry: LawnGeometry,
+    /// 本次调用经过的时间（毫秒）。
+    elapsed: u64,
+    /// 场上所有僵尸（只读）。
+    zombies: &'a [Zombie],
+    /// 命令缓冲区，收集植物对游戏世界产生的效果。
+    effects: &'a mut Vec<WorldEffect>,
+}
+
+impl<'a> PlantContext<'a> {
+    /// 为指定位置的植物创建上下文。
+    ///
+    /// # Arguments
+    ///
+    /// * `grid_x` - 植物所在的网格x坐标。
+    /// * `grid_y` - 植物所在的网格y坐标。
+    /// * `plant_type` - 植物的类型。
+    /// * `geometry` - 草坪的几何信息。
+    /// * `elapsed` - 本次调用经过的时间（毫秒）：对 `update_action` 是自上一次执行动作以来经过的时间，
+    ///   对 `special_effect` 是本帧的时间增量。
+    /// * `zombies` - 场上所有僵尸。
+    /// * `effects` - 收集世界效果的命令缓冲区。
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 `PlantContext`。
+    pub fn new(
+        grid_x: usize,
+        grid_y: usize,
+        plant_type: PlantType,
+        geometry: LawnGeometry,
+        elapsed: u64,
+        zombies: &'a [Zombie],
+        effects: &'a mut Vec<WorldEffect>,
+    ) -> Self {
+        PlantContext { grid_x, grid_y, plant_type, geometry, elapsed, zombies, effects }
+    }
+
+    /// 植物所在的网格x坐标。
+    pub fn grid_x(&self) -> usize {
+        self.grid_x
+    }
+
+    /// 植物所在的网格y坐标。
+    pub fn grid_y(&self) -> usize {
+        self.grid_y
+    }
+
+    /// 植物所在草坪的几何信息。
+    pub fn geometry(&self) -> &LawnGeometry {
+        &self.geometry
+    }
+
+    /// 本次调用经过的时间（毫秒）。
+    ///
+    /// 动作间隔可以被调校文件覆盖，因此植物内部的计时（例如消化、充能）应按这个时间推进，
+    /// 而不是按自身的默认间隔推进。
+    pub fn elapsed(&self) -> u64 {
+        self.elapsed
+    }
+
+    /// 场上所有未处于死亡状态的僵尸。
+    pub fn zombies(&self) -> impl Iterator<Item = &'a Zombie> + 'a {
+        let zombies: &'a [Zombie] = self.zombies;
+        zombies.iter().filter(|zombie| !zombie.is_dying)
+    }
+
+    /// 指定行中所有未处于死亡状态的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - 行索引。
+    pub fn zombies_in_lane(&self, row: usize) -> impl Iterator<Item = &'a Zombie> + 'a {
+        self.zombies().filter(move |zombie| zombie.row == row)
+    }
+
+    /// 指定行中碰撞矩形与 `[min_x, max_x]` 区间重叠的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - 行索引。
+    /// * `min_x` - 区间左端的屏幕X坐标。
+    /// * `max_x` - 区间右端的屏幕X坐标。
+    pub fn zombies_in_range(&self, row: usize, min_x: f32, max_x: f32) -> impl Iterator<Item = &'a Zombie> + 'a {
+        self.zombies_in_lane(row).filter(move |zombie| {
+            let rect = zombie.get_rect();
+            rect.x + rect.w >= min_x && rect.x <= max_x
+        })
+    }
+
+    /// 碰撞矩形与指定单元格重叠的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - 单元格的列索引。
+    /// * `row` - 单元格的行索引。
+    pub fn zombies_in_cell(&self, col: usize, row: usize) -> impl Iterator<Item = &'a Zombie> + 'a {
+        let min_x = self.geometry.column_x(col);
+        self.zombies_in_range(row, min_x, min_x + self.geometry.cell_width)
+    }
+
+    /// 植物所在行中位于植物右侧（前方）的僵尸。
+    pub fn zombies_ahead(&self) -> impl Iterator<Item = &'a Zombie> + 'a {
+        let min_x = self.geometry.column_x(self.grid_x);
+        self.zombies_in_range(self.grid_y, min_x, f32::INFINITY)
+    }
+
+    /// 发射一个投射物。
+    pub fn spawn_projectile(&mut self, projectile: Projectile) {
+        self.effects.push(WorldEffect::SpawnProjectile(projectile));
+    }
+
+    /// 产生一个阳光。
+    pub fn spawn_sun(&mut self, sun: Sun) {
+        self.effects.push(WorldEffect::SpawnSun(sun));
+    }
+
+    /// 对指定的僵尸造成伤害。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_id` - 目标僵尸的唯一标识。
+    /// * `damage` - 伤害值。
+    /// * `kind` - 命中方式。
+    pub fn damage_zombie(&mut self, zombie_id: u64, damage: i32, kind: HitKind) {
+        self.effects.push(WorldEffect::DamageZombie { zombie_id, damage, kind });
+    }
+
+    /// 对指定的僵尸施加状态效果。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_id` - 目标僵尸的唯一标识。
+    /// * `effect` - 要施加的效果。
+    pub fn affect_zombie(&mut self, zombie_id: u64, effect: HitEffect) {
+        self.effects.push(WorldEffect::AffectZombie { zombie_id, effect });
+    }
+
+    /// 立即吞掉指定的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_id` - 目标僵尸的唯一标识。
+    pub fn swallow_zombie(&mut self, zombie_id: u64) {
+        self.effects.push(WorldEffect::SwallowZombie { zombie_id });
+    }
+
+    /// 伤害指定单元格中最外层的植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `grid_x` - 单元格的网格x坐标。
+    /// * `grid_y` - 单元格的网格y坐标。
+    /// * `amount` - 伤害值。
+    pub fn damage_plant(&mut self, grid_x: usize, grid_y: usize, amount: i32) {
+        self.effects.push(WorldEffect::DamagePlant { grid_x, grid_y, amount });
+    }
+
+    /// 治疗指定单元格中最外层的植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `grid_x` - 单元格的网格x坐标。
+    /// * `grid_y` - 单元格的网格y坐标。
+    /// * `amount` - 治疗量。
+    pub fn heal_plant(&mut self, grid_x: usize, grid_y: usize, amount: i32) {
+        self.effects.push(WorldEffect::HealPlant { grid_x, grid_y, amount });
+    }
+
+    /// 移除植物自身。
+    pub fn remove_self(&mut self) {
+        self.effects.push(WorldEffect::RemovePlant {
+            grid_x: self.grid_x,
+            grid_y: self.grid_y,
+            plant_type: self.plant_type,
+        });
+    }
+
+    /// 发出一个事件。
+    pub fn emit(&mut self, event: PlantEvent) {
+        self.effects.push(WorldEffect::Event(event));
+    }
+}
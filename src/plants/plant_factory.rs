@@ -2,17 +2,21 @@
 //!
 //! 本模块定义了 `PlantType` 枚举和 `PlantFactory` 结构体，
 //! 用于根据指定的植物类型创建相应的植物实例 (`Box<dyn PlantTrait>`)。
+//! 每种植物的花费、构造函数等信息登记在内容注册表 (`core::registry`) 中。
 //! 这种工厂模式使得在游戏逻辑中创建不同植物更加方便和类型安全。
 
+use crate::core::registry::{self, PlantSpec};
 use crate::plants::plant_trait::PlantTrait;
-use crate::plants::peashooter::Peashooter;
-use crate::plants::sunflower::Sunflower;
-use crate::plants::wallnut::WallNut;
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
 
 /// 枚举了游戏中所有可用的植物类型。
 ///
 /// 每种植物类型对应一个具体的植物实现。
 /// 此枚举也用于确定植物的成本等属性。
+///
+/// 序列化时，内置植物使用变体名（例如 `"Peashooter"`），自定义植物使用注册时的标识，
+/// 因此存档不依赖自定义植物的注册顺序。
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PlantType {
     /// 豌豆射手：基础远程攻击单位。
@@ -21,31 +25,113 @@ pub enum PlantType {
     Sunflower,
     /// 坚果墙：高生命值的防御单位。
     WallNut,
+    /// 睡莲：只能种在水面上，陆地植物可以种在它上面。
+    LilyPad,
+    /// 缠绕水草：只能种在水面上，把碰到它的僵尸拖入水下。
+    TangleKelp,
+    /// 南瓜头：套在其他植物外面的保护壳。
+    Pumpkin,
+    /// 路灯花：驱散周围的迷雾。
+    Plantern,
+    /// 三叶草：暂时吹散全部迷雾。
+    Blover,
+    /// 花盆：可以种在草地或屋顶上的底座，屋顶上的植物都必须种在花盆上。
+    FlowerPot,
+    /// 卷心菜投手：向僵尸投出沿抛物线飞行的卷心菜。
+    CabbagePult,
+    /// 玉米投手：投出玉米粒，有几率投出使僵尸定身的黄油。
+    KernelPult,
+    /// 寒冰射手：发射使僵尸减速的寒冰豌豆。
+    SnowPea,
+    /// 火炬树桩：把穿过它的豌豆点燃成火焰豌豆。
+    Torchwood,
+    /// 大嘴花：一口吞掉前方的僵尸，之后需要长时间消化。
+    Chomper,
+    /// 仙人掌：发射可以穿透多个僵尸的尖刺。
+    Cactus,
+    /// 杨桃：同时向五个方向发射自由飞行的星星。
+    Starfruit,
+    /// 磁力菇：吸走附近僵尸的护甲。
+    MagnetShroom,
+    /// 叶子保护伞：挡住落在周围一格内的敌方抛物线投掷物。
+    UmbrellaLeaf,
+    /// 通过 `registry::register_plant` 注册的自定义植物，编号按注册顺序分配。
+    Custom(u16),
     // 以后在这里添加新的植物类型
     // CherryBomb, // 樱桃炸弹 
     // PotatoMine, // 土豆地雷 
-    // SnowPea,    // 寒冰射手 
 }
 
 impl PlantType {
+    /// 获取该植物类型在内容注册表中的规格。
+    ///
+    /// # Panics
+    ///
+    /// 如果该类型是尚未注册的自定义植物，则 panic。
+    pub fn spec(&self) -> PlantSpec {
+        registry::plant_spec(*self)
+    }
+
     /// 获取指定植物类型种植所需的阳光成本。
     ///
     /// # Returns
     ///
-    /// 返回该植物类型的阳光成本 (`i32`)。
+    /// 返回该植物类型在注册表中登记的阳光成本 (`i32`)。
     pub fn cost(&self) -> i32 {
+        self.spec().cost
+    }
+
+    /// 是否为水生植物。水生植物只能种植在水面上。
+    pub fn is_aquatic(&self) -> bool {
+        self.spec().aquatic
+    }
+
+    /// 获取该植物在网格单元格中占据的层。
+    ///
+    /// # Returns
+    ///
+    /// 返回该植物类型在注册表中登记的层，例如睡莲、花盆等底座植物位于底层，南瓜头位于外壳层。
+    pub fn layer(&self) -> PlantLayer {
+        self.spec().layer
+    }
+}
+
+impl Serialize for PlantType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
-            PlantType::Peashooter => 100,
-            PlantType::Sunflower => 50,
-            PlantType::WallNut => 50,
-            // 添加新植物的成本
-            // PlantType::CherryBomb => 150,
-            // PlantType::PotatoMine => 25,
-            // PlantType::SnowPea => 175,
+            PlantType::Custom(_) => serializer.serialize_str(self.spec().id),
+            builtin => serializer.serialize_str(&format!("{:?}", builtin)),
         }
     }
 }
 
+impl<'de> Deserialize<'de> for PlantType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        registry::plant_types()
+            .into_iter()
+            .find(|plant_type| match plant_type {
+                PlantType::Custom(_) => plant_type.spec().id == name,
+                builtin => format!("{:?}", builtin) == name,
+            })
+            .ok_or_else(|| de::Error::custom(format!("未知的植物类型: {}", name)))
+    }
+}
+
+/// 植物在网格单元格中占据的层，从下到上排列。
+///
+/// 一个单元格的每一层最多种植一株植物。僵尸总是先啃食最外面的一层，
+/// 铲子也总是先铲除最外面的一层。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PlantLayer {
+    /// 底层：睡莲、花盆等底座，其他植物可以种在它上面。
+    Underlay,
+    /// 主层：普通植物。
+    Main,
+    /// 外壳层：南瓜头，包裹在主层植物外面。
+    Shell,
+}
+
 /// 植物工厂结构体。
 ///
 /// 提供一个静态方法 `create_plant` 来实例化不同类型的植物。
@@ -55,7 +141,7 @@ pub struct PlantFactory;
 impl PlantFactory {
     /// 根据给定的 `PlantType` 创建并返回一个具体的植物实例。
     ///
-    /// 植物实例被封装在 `Box<dyn PlantTrait>` 中，以实现动态分派。
+    /// 植物实例由注册表中该类型的构造函数创建，并被封装在 `Box<dyn PlantTrait>` 中，以实现动态分派。
     ///
     /// # Arguments
     ///
@@ -65,14 +151,6 @@ impl PlantFactory {
     ///
     /// 返回一个实现了 `PlantTrait` 的植物对象的 `Box` 指针。
     pub fn create_plant(plant_type: PlantType) -> Box<dyn PlantTrait> {
-        match plant_type {
-            PlantType::Peashooter => Box::new(Peashooter::new()),
-            PlantType::Sunflower => Box::new(Sunflower::new()),
-            PlantType::WallNut => Box::new(WallNut::new()),
-            // 在这里添加新的植物类型
-            // PlantType::CherryBomb => Box::new(CherryBomb::new()),
-            // PlantType::PotatoMine => Box::new(PotatoMine::new()),
-            // PlantType::SnowPea => Box::new(SnowPea::new()),
-        }
+        (plant_type.spec().constructor)()
     }
-}
\ No newline at end of file
+}
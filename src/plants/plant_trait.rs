@@ -4,23 +4,14 @@
 //! 它规定了植物必须具备的一系列通用行为和属性获取方法，
 //! 使得游戏主逻辑可以通过统一的接口与不同类型的植物进行交互。
 
-use crate::entities::sun::Sun;
-use crate::entities::pea::Pea;
-use crate::core::resources::Resources;
-use ggez::graphics;
-use crate::zombies::Zombie; // 确保 Zombie 类型已导入
+use crate::entities::projectile::ProjectileKind;
+use crate::plants::plant_context::PlantContext;
 
-/// `PlantTrait` 定义了所有植物共享的核心行为和属性。
+/// `PlantTrait` 定义了所有植物共享的核心行为。
 ///
 /// 通过实现此特征，不同类型的植物（如豌豆射手、向日葵）可以被泛化处理。
+/// 花费、生命值和卡片贴图等静态属性不在此特征中，而是登记在内容注册表的 `PlantSpec` 中。
 pub trait PlantTrait {
-    /// 获取植物的初始生命值。
-    ///
-    /// # Returns
-    ///
-    /// 返回植物的初始生命值 (`i32`)。
-    fn get_initial_health(&self) -> i32;
-
     /// 获取植物动作的冷却时间（例如，攻击或产生阳光的间隔）。
     ///
     /// # Returns
@@ -28,65 +19,32 @@ pub trait PlantTrait {
     /// 返回冷却时间，单位为毫秒 (`u64`)。
     fn get_cooldown(&self) -> u64;
 
-    /// 获取植物动画的总帧数。
-    ///
-    /// # Returns
-    ///
-    /// 返回动画的帧数 (`usize`)。
-    fn get_frame_count(&self) -> usize;
-
     /// 更新植物的特定动作，例如发射豌豆或产生阳光。
     ///
     /// 此方法在植物的冷却计时器完成后被调用。
     ///
     /// # Arguments
     ///
-    /// * `grid_x` - 植物所在的网格x坐标。
-    /// * `grid_y` - 植物所在的网格y坐标。
-    /// * `suns` - 一个可变向量的引用，用于收集新产生的阳光。
-    /// * `peas` - 一个可变向量的引用，用于收集新发射的豌豆。
-    /// * `zombies` - 一个僵尸向量的引用，用于检查僵尸位置。
-    fn update_action(&mut self, grid_x: usize, grid_y: usize, suns: &mut Vec<Sun>, peas: &mut Vec<Pea>, zombies: &Vec<Zombie>);
+    /// * `world` - 植物的世界上下文，提供植物的位置、僵尸查询，以及发射投射物、产生阳光、
+    ///   伤害僵尸、移除自身等命令。
+    fn update_action(&mut self, world: &mut PlantContext);
     
-    /// 获取种植该植物所需的阳光成本。
-    ///
-    /// # Returns
-    ///
-    /// 返回阳光成本 (`i32`)。
-    fn get_cost(&self) -> i32;
-
-    /// 获取植物在商店中显示的卡片图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    ///
-    /// # Returns
-    ///
-    /// 返回商店卡片图像的引用 (`&'a graphics::Image`)。
-    fn get_card_image<'a>(&self, resources: &'a Resources) -> &'a graphics::Image;
-
-    /// 获取植物当前动画帧对应的图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `animation_frame` - 当前动画帧的索引。
+    /// （可选）植物当前状态使用的动画片段（即资源清单中的贴图名称）。
     ///
-    /// # Returns
-    ///
-    /// 返回当前动画帧图像的引用 (`&'a graphics::Image`)。
-    fn get_current_frame_image<'a>(&self, resources: &'a Resources, animation_frame: usize) -> &'a graphics::Image;
+    /// 默认返回 `None`，使用注册表中该植物的 `PlantSpec::image`。
+    /// 外观随状态变化的植物（例如消化中的大嘴花）可以覆盖此方法，`Plant` 会在片段变化时从头播放新的片段。
+    fn animation_clip(&self) -> Option<&'static str> {
+        None
+    }
 
     /// （可选）执行植物的特殊效果或被动能力。
     ///
-    /// 默认实现为空。具体植物可以覆盖此方法以实现特殊逻辑。
+    /// 每次植物更新时都会调用，不受冷却时间限制。默认实现为空。具体植物可以覆盖此方法以实现特殊逻辑。
     ///
     /// # Arguments
     ///
-    /// * `_grid_x` - 植物所在的网格x坐标 (默认未使用)。
-    /// * `_grid_y` - 植物所在的网格y坐标 (默认未使用)。
-    fn special_effect(&mut self, _grid_x: usize, _grid_y: usize) {
+    /// * `_world` - 植物的世界上下文 (默认未使用)。
+    fn special_effect(&mut self, _world: &mut PlantContext) {
         // 默认无特殊效果
     }
 
@@ -100,4 +58,36 @@ pub trait PlantTrait {
     fn get_damage_state(&self) -> usize {
         1 // 默认只有一个状态（未损坏）
     }
+
+    /// （可选）僵尸碰到该植物时是否会被立即拖走（例如缠绕水草）。
+    ///
+    /// 默认返回 `false`，僵尸会正常啃食植物。
+    fn grabs_zombie(&self) -> bool {
+        false
+    }
+
+    /// （可选）植物驱散迷雾的半径（单元格数，例如路灯花）。
+    ///
+    /// 默认返回 `0.0`，表示不照亮任何单元格。
+    fn light_radius(&self) -> f32 {
+        0.0
+    }
+
+    /// （可选）植物是否会挡住落在自身周围一格内的敌方抛物线投掷物（例如叶子保护伞）。
+    ///
+    /// 默认返回 `false`。
+    fn blocks_lobbed(&self) -> bool {
+        false
+    }
+
+    /// （可选）沿行飞过该植物所在单元格的投射物会被转换成的类型（例如火炬树桩把豌豆点燃成火焰豌豆）。
+    ///
+    /// 默认返回 `None`，投射物保持原样。
+    ///
+    /// # Arguments
+    ///
+    /// * `_kind` - 飞过的投射物的类型。
+    fn convert_projectile(&self, _kind: ProjectileKind) -> Option<ProjectileKind> {
+        None
+    }
 }
\ No newline at end of file
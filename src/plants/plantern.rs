@@ -0,0 +1,46 @@
+//! # 路灯花模块 (`plantern`)
+//!
+//! 实现了迷雾关卡中的照明植物——路灯花。
+//! 路灯花本身没有攻击能力，但会驱散周围一定半径内的迷雾，使被遮挡的僵尸重新可见。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 路灯花植物的结构体。
+///
+/// 路灯花没有特有状态，照亮范围由 `light_radius` 提供给迷雾计算。
+#[derive(Default)]
+pub struct Plantern;
+
+impl Plantern {
+    /// 创建一个新的 `Plantern` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Plantern` 实例。
+    pub fn new() -> Self {
+        Plantern
+    }
+}
+
+/// 路灯花没有主动动作。
+const COOLDOWN: u64 = 0;
+/// 路灯花的照亮半径（单元格数）。
+const LIGHT_RADIUS: f32 = 1.5;
+
+impl PlantTrait for Plantern {
+    /// 获取路灯花的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 路灯花没有主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 路灯花没有主动动作
+    }
+
+    /// 路灯花会照亮周围的单元格。
+    fn light_radius(&self) -> f32 {
+        LIGHT_RADIUS
+    }
+}
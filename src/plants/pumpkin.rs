@@ -0,0 +1,40 @@
+//! # 南瓜头模块 (`pumpkin`)
+//!
+//! 实现了游戏中的保护壳植物——南瓜头。
+//! 南瓜头种在单元格的外壳层，可以套在已有的植物外面（也可以先种南瓜头再往里面种植物）。
+//! 僵尸会先啃食南瓜头，南瓜头被吃掉之后才会啃到里面的植物。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 南瓜头植物的结构体。
+///
+/// 南瓜头没有特有状态，其保护作用由网格的分层规则和僵尸的攻击顺序实现。
+#[derive(Default)]
+pub struct Pumpkin;
+
+impl Pumpkin {
+    /// 创建一个新的 `Pumpkin` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Pumpkin` 实例。
+    pub fn new() -> Self {
+        Pumpkin
+    }
+}
+
+/// 南瓜头没有主动动作。
+const COOLDOWN: u64 = 0;
+
+impl PlantTrait for Pumpkin {
+    /// 获取南瓜头的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 南瓜头没有主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 南瓜头没有主动动作
+    }
+}
@@ -0,0 +1,49 @@
+//! # 寒冰射手模块 (`snow_pea`)
+//!
+//! 实现了游戏中的减速攻击单位——寒冰射手。
+//! 寒冰射手会周期性地向其所在行的前方发射寒冰豌豆，被击中的僵尸会被减速。
+
+use crate::entities::projectile::{Projectile, ProjectileKind};
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 寒冰射手植物的结构体。
+///
+/// 寒冰射手没有特有状态，发射间隔由 `COOLDOWN` 控制。
+#[derive(Default)]
+pub struct SnowPea;
+
+impl SnowPea {
+    /// 创建一个新的 `SnowPea` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `SnowPea` 实例。
+    pub fn new() -> Self {
+        SnowPea
+    }
+}
+
+/// 寒冰射手发射寒冰豌豆的冷却时间（毫秒）。
+const COOLDOWN: u64 = 1400;
+
+impl PlantTrait for SnowPea {
+    /// 获取寒冰射手发射寒冰豌豆的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 如果所在行前方有僵尸，向前发射一颗寒冰豌豆。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 寒冰射手的世界上下文，用于检查前方是否有僵尸并发射寒冰豌豆。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        if world.zombies_ahead().next().is_some() {
+            let geometry = *world.geometry();
+            let x = geometry.column_x(world.grid_x()) + geometry.cell_width * 0.8;
+            let y = geometry.row_y(world.grid_y()) + geometry.cell_height * 0.3;
+            world.spawn_projectile(Projectile::straight_on_lawn(x, y, world.grid_y(), ProjectileKind::SnowPea, geometry));
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! # 杨桃模块 (`starfruit`)
+//!
+//! 实现了游戏中的多方向攻击单位——杨桃。
+//! 场上有僵尸时，杨桃会同时向五个方向（上、下、后方以及前方两条斜线）发射自由飞行的星星，
+//! 星星会命中途经任何一行中的僵尸。
+
+use crate::entities::projectile::{Flight, Projectile, ProjectileKind};
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 杨桃植物的结构体。
+///
+/// 杨桃没有特有状态，发射间隔由 `COOLDOWN` 控制。
+#[derive(Default)]
+pub struct Starfruit;
+
+impl Starfruit {
+    /// 创建一个新的 `Starfruit` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Starfruit` 实例。
+    pub fn new() -> Self {
+        Starfruit
+    }
+}
+
+/// 杨桃发射星星的冷却时间（毫秒）。
+const COOLDOWN: u64 = 1400;
+/// 五颗星星的飞行方向（单位向量）：上、下、后方、右上、右下。
+pub const STAR_DIRECTIONS: [(f32, f32); 5] = [(0.0, -1.0), (0.0, 1.0), (-1.0, 0.0), (0.866, -0.5), (0.866, 0.5)];
+
+impl PlantTrait for Starfruit {
+    /// 获取杨桃发射星星的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 如果场上有僵尸，从杨桃中心向五个方向各发射一颗星星。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 杨桃的世界上下文，用于检查场上是否有僵尸并发射星星。
+    fn update_action(&mut self, world: &mut PlantContext) {
+        if world.zombies().next().is_none() {
+            return;
+        }
+
+        let geometry = *world.geometry();
+        let x = geometry.column_x(world.grid_x()) + geometry.cell_width * 0.4;
+        let y = geometry.row_y(world.grid_y()) + geometry.cell_height * 0.3;
+        let speed = ProjectileKind::Star.speed();
+        for (dx, dy) in STAR_DIRECTIONS {
+            let velocity = (dx * speed, dy * speed);
+            let star = Projectile::new(ProjectileKind::Star, x, y, world.grid_y(), velocity, Flight::Free, geometry);
+            world.spawn_projectile(star);
+        }
+    }
+}
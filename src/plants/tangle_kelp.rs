@@ -0,0 +1,44 @@
+//! # 缠绕水草模块 (`tangle_kelp`)
+//!
+//! 实现了泳池关卡中的一次性水生植物——缠绕水草。
+//! 缠绕水草只能种植在水面上，当僵尸碰到它时，会把僵尸拖入水下，自己也随之消失。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 缠绕水草植物的结构体。
+///
+/// 缠绕水草没有特有状态，拖拽僵尸的逻辑由 `CollisionManager` 根据 `grabs_zombie` 处理。
+#[derive(Default)]
+pub struct TangleKelp;
+
+impl TangleKelp {
+    /// 创建一个新的 `TangleKelp` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `TangleKelp` 实例。
+    pub fn new() -> Self {
+        TangleKelp
+    }
+}
+
+/// 缠绕水草没有周期性的主动动作。
+const COOLDOWN: u64 = 0;
+
+impl PlantTrait for TangleKelp {
+    /// 获取缠绕水草的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 缠绕水草没有周期性的主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 拖拽僵尸在僵尸接触时处理
+    }
+
+    /// 僵尸碰到缠绕水草时会被拖入水下。
+    fn grabs_zombie(&self) -> bool {
+        true
+    }
+}
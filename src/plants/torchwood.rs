@@ -0,0 +1,49 @@
+//! # 火炬树桩模块 (`torchwood`)
+//!
+//! 实现了游戏中的辅助防御单位——火炬树桩。
+//! 火炬树桩本身不会攻击，但可以阻挡僵尸前进。沿行飞过它所在单元格的豌豆会被点燃成火焰豌豆，
+//! 造成双倍伤害并溅射周围的僵尸；寒冰豌豆飞过时则会被融化成普通豌豆。
+
+use crate::entities::projectile::ProjectileKind;
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 火炬树桩植物的结构体。
+///
+/// 火炬树桩没有特有状态，对投射物的转换由 `convert_projectile` 定义。
+#[derive(Default)]
+pub struct Torchwood;
+
+impl Torchwood {
+    /// 创建一个新的 `Torchwood` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Torchwood` 实例。
+    pub fn new() -> Self {
+        Torchwood
+    }
+}
+
+/// 火炬树桩的冷却时间（火炬树桩没有主动动作）。
+const COOLDOWN: u64 = 1000;
+
+impl PlantTrait for Torchwood {
+    /// 获取火炬树桩的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 火炬树桩没有主动动作，飞过的豌豆在碰撞检测中被转换。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+    }
+
+    /// 普通豌豆被点燃成火焰豌豆，寒冰豌豆被融化成普通豌豆，其他投射物保持原样。
+    fn convert_projectile(&self, kind: ProjectileKind) -> Option<ProjectileKind> {
+        match kind {
+            ProjectileKind::Pea => Some(ProjectileKind::FirePea),
+            ProjectileKind::SnowPea => Some(ProjectileKind::Pea),
+            _ => None,
+        }
+    }
+}
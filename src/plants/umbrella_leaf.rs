@@ -0,0 +1,45 @@
+//! # 叶子保护伞模块 (`umbrella_leaf`)
+//!
+//! 实现了游戏中的防御单位——叶子保护伞。
+//! 叶子保护伞会挡住落在自身周围一格内的敌方抛物线投掷物（例如投篮车僵尸的篮球），
+//! 保护它和周围的植物不受伤害。
+
+use crate::plants::plant_context::PlantContext;
+use crate::plants::plant_trait::PlantTrait;
+
+/// 叶子保护伞植物的结构体。
+///
+/// 叶子保护伞没有特有状态，也没有主动的动作。
+#[derive(Default)]
+pub struct UmbrellaLeaf;
+
+impl UmbrellaLeaf {
+    /// 创建一个新的 `UmbrellaLeaf` 实例。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `UmbrellaLeaf` 实例。
+    pub fn new() -> Self {
+        UmbrellaLeaf
+    }
+}
+
+/// 叶子保护伞没有主动动作。
+const COOLDOWN: u64 = 0;
+
+impl PlantTrait for UmbrellaLeaf {
+    /// 获取叶子保护伞的冷却时间。
+    fn get_cooldown(&self) -> u64 {
+        COOLDOWN
+    }
+
+    /// 叶子保护伞没有主动动作。
+    fn update_action(&mut self, _world: &mut PlantContext) {
+        // 叶子保护伞没有主动动作
+    }
+
+    /// 叶子保护伞挡住落在周围一格内的敌方抛物线投掷物。
+    fn blocks_lobbed(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,160 @@
+//! # 世界效果模块 (`world_effect`)
+//!
+//! 植物在更新时只能通过 `PlantContext` 读取僵尸，不能直接修改游戏世界。
+//! 植物要产生的效果（发射投射物、产生阳光、伤害僵尸、治疗植物、移除自身、发出事件等）
+//! 以 `WorldEffect` 的形式写入命令缓冲区，由游戏主循环在所有植物更新完成后统一对 `WorldState` 执行。
+
+use crate::entities::projectile::{HitEffect, Projectile};
+use crate::entities::sun::Sun;
+use crate::plants::{Plant, PlantType};
+use crate::zombies::{HitKind, Zombie};
+
+/// 植物发出的、需要由游戏主循环处理的事件。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlantEvent {
+    /// 吹散草坪上的迷雾（例如三叶草）。
+    BlowFog,
+}
+
+/// 植物对游戏世界产生的效果。
+pub enum WorldEffect {
+    /// 发射一个投射物。
+    SpawnProjectile(Projectile),
+    /// 产生一个阳光。
+    SpawnSun(Sun),
+    /// 对指定的僵尸造成伤害。
+    DamageZombie {
+        /// 目标僵尸的唯一标识。
+        zombie_id: u64,
+        /// 伤害值。
+        damage: i32,
+        /// 命中方式，决定僵尸的正面护盾能否挡住这次伤害。
+        kind: HitKind,
+    },
+    /// 对指定的僵尸施加状态效果（减速、定身等）。
+    AffectZombie {
+        /// 目标僵尸的唯一标识。
+        zombie_id: u64,
+        /// 要施加的效果。
+        effect: HitEffect,
+    },
+    /// 立即吞掉指定的僵尸（例如大嘴花），僵尸不播放死亡动画直接消失。
+    SwallowZombie {
+        /// 被吞掉的僵尸的唯一标识。
+        zombie_id: u64,
+    },
+    /// 伤害指定单元格中最外层的植物。
+    DamagePlant {
+        /// 单元格的网格x坐标。
+        grid_x: usize,
+        /// 单元格的网格y坐标。
+        grid_y: usize,
+        /// 伤害值。
+        amount: i32,
+    },
+    /// 治疗指定单元格中最外层的植物，生命值不会超过植物的初始生命值。
+    HealPlant {
+        /// 单元格的网格x坐标。
+        grid_x: usize,
+        /// 单元格的网格y坐标。
+        grid_y: usize,
+        /// 治疗量。
+        amount: i32,
+    },
+    /// 移除指定单元格中指定类型的植物（例如一次性植物移除自身）。
+    RemovePlant {
+        /// 植物的网格x坐标。
+        grid_x: usize,
+        /// 植物的网格y坐标。
+        grid_y: usize,
+        /// 植物的类型，用于区分同一单元格中不同层的植物。
+        plant_type: PlantType,
+    },
+    /// 发出一个事件。
+    Event(PlantEvent),
+}
+
+/// 执行世界效果时可以修改的游戏世界。
+pub struct WorldState<'a> {
+    /// 场上所有僵尸。
+    pub zombies: &'a mut Vec<Zombie>,
+    /// 场上所有植物。被移除或被杀死的植物只会被标记为死亡，由调用者负责清理。
+    pub plants: &'a mut Vec<Plant>,
+    /// 场上所有投射物。
+    pub projectiles: &'a mut Vec<Projectile>,
+    /// 场上所有阳光。
+    pub suns: &'a mut Vec<Sun>,
+    /// 植物发出的事件，由调用者处理。
+    pub events: &'a mut Vec<PlantEvent>,
+}
+
+impl WorldEffect {
+    /// 对游戏世界执行该效果。
+    ///
+    /// 目标僵尸已经不存在或正在死亡、目标单元格中没有植物时，效果被忽略。
+    ///
+    /// # Arguments
+    ///
+    /// * `world` - 可以修改的游戏世界。
+    ///
+    /// # Returns
+    ///
+    /// 返回因此效果而死亡的僵尸数量。
+    pub fn apply(self, world: &mut WorldState) -> usize {
+        match self {
+            WorldEffect::SpawnProjectile(projectile) => world.projectiles.push(projectile),
+            WorldEffect::SpawnSun(sun) => world.suns.push(sun),
+            WorldEffect::DamageZombie { zombie_id, damage, kind } => {
+                if let Some(zombie) = Self::living_zombie(world, zombie_id) {
+                    return zombie.take_hit(damage, kind) as usize;
+                }
+            }
+            WorldEffect::AffectZombie { zombie_id, effect } => {
+                if let Some(zombie) = Self::living_zombie(world, zombie_id) {
+                    zombie.apply_effect(effect);
+                }
+            }
+            WorldEffect::SwallowZombie { zombie_id } => {
+                if let Some(zombie) = Self::living_zombie(world, zombie_id) {
+                    zombie.swallow();
+                    return 1;
+                }
+            }
+            WorldEffect::DamagePlant { grid_x, grid_y, amount } => {
+                if let Some(plant) = Self::outermost_plant(world, grid_x, grid_y) {
+                    plant.take_damage(amount);
+                }
+            }
+            WorldEffect::HealPlant { grid_x, grid_y, amount } => {
+                if let Some(plant) = Self::outermost_plant(world, grid_x, grid_y) {
+                    plant.heal(amount);
+                }
+            }
+            WorldEffect::RemovePlant { grid_x, grid_y, plant_type } => {
+                for plant in world
+                    .plants
+                    .iter_mut()
+                    .filter(|plant| plant.grid_x == grid_x && plant.grid_y == grid_y && plant.get_plant_type() == plant_type)
+                {
+                    plant.is_dead = true;
+                }
+            }
+            WorldEffect::Event(event) => world.events.push(event),
+        }
+        0
+    }
+
+    /// 查找指定标识的、未处于死亡状态的僵尸。
+    fn living_zombie<'w>(world: &'w mut WorldState, zombie_id: u64) -> Option<&'w mut Zombie> {
+        world.zombies.iter_mut().find(|zombie| zombie.id() == zombie_id && !zombie.is_dying)
+    }
+
+    /// 查找指定单元格中最外层的存活植物。
+    fn outermost_plant<'w>(world: &'w mut WorldState, grid_x: usize, grid_y: usize) -> Option<&'w mut Plant> {
+        world
+            .plants
+            .iter_mut()
+            .filter(|plant| !plant.is_dead && plant.grid_x == grid_x && plant.grid_y == grid_y)
+            .max_by_key(|plant| plant.get_plant_type().layer())
+    }
+}
@@ -0,0 +1,206 @@
+//! # 迷雾模块 (`fog`)
+//!
+//! 实现迷雾关卡中覆盖草坪右侧若干列的迷雾。
+//! 迷雾只影响绘制：被迷雾覆盖的单元格中的僵尸和豌豆不会被绘制，碰撞和模拟完全不受影响。
+//! 路灯花 (`Plantern`) 会照亮周围一定半径内的单元格，三叶草 (`Blover`) 可以暂时吹散全部迷雾。
+
+use ggez::graphics::{self, Color, DrawMode, DrawParam, MeshBuilder, Rect};
+use ggez::{Context, GameResult};
+use crate::plants::Plant;
+use crate::ui::lawn::LawnGeometry;
+
+/// 三叶草吹散迷雾后，迷雾重新出现之前的时间（毫秒）。
+pub const BLOVER_CLEAR_DURATION: u64 = 15000;
+
+/// 草坪上的迷雾状态。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Fog {
+    /// 被迷雾覆盖的列数（从最右侧一列向左计算），0 表示没有迷雾。
+    columns: usize,
+    /// 迷雾被吹散后剩余的时间（毫秒），为 0 时迷雾正常显示。
+    cleared_timer: u64,
+}
+
+impl Fog {
+    /// 创建覆盖草坪最右侧 `columns` 列的迷雾。
+    ///
+    /// # Arguments
+    ///
+    /// * `columns` - 被迷雾覆盖的列数，0 表示没有迷雾。
+    ///
+    /// # Returns
+    ///
+    /// 返回新的 `Fog` 实例。
+    pub fn new(columns: usize) -> Self {
+        Fog {
+            columns,
+            cleared_timer: 0,
+        }
+    }
+
+    /// 创建一个不覆盖任何单元格的迷雾，用于没有迷雾的关卡。
+    pub fn none() -> Self {
+        Fog::new(0)
+    }
+
+    /// 获取被迷雾覆盖的列数。
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// 迷雾当前是否可见（存在迷雾且没有被吹散）。
+    pub fn is_active(&self) -> bool {
+        self.columns > 0 && self.cleared_timer == 0
+    }
+
+    /// 吹散全部迷雾，持续 `BLOVER_CLEAR_DURATION` 毫秒。
+    pub fn blow_away(&mut self) {
+        self.cleared_timer = BLOVER_CLEAR_DURATION;
+    }
+
+    /// 更新迷雾被吹散后的计时。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    pub fn update(&mut self, dt: u64) {
+        self.cleared_timer = self.cleared_timer.saturating_sub(dt);
+    }
+
+    /// 计算当前被迷雾遮挡的单元格。
+    ///
+    /// 迷雾覆盖的列中，不在任何路灯花照亮范围内的单元格会被遮挡。
+    /// 每个路灯花只遍历一次，渲染器每帧计算一次后供所有僵尸和豌豆查询。
+    ///
+    /// # Arguments
+    ///
+    /// * `geometry` - 草坪的几何信息。
+    /// * `plants` - 场上所有植物，用于计算路灯花的照亮范围。
+    ///
+    /// # Returns
+    ///
+    /// 返回本帧的 `FogMask`。
+    pub fn mask(&self, geometry: &LawnGeometry, plants: &[Plant]) -> FogMask {
+        let mut hidden = Vec::new();
+        if self.is_active() {
+            let first_col = geometry.cols.saturating_sub(self.columns);
+            hidden = vec![false; geometry.rows * geometry.cols];
+            for row in 0..geometry.rows {
+                for col in first_col..geometry.cols {
+                    hidden[row * geometry.cols + col] = true;
+                }
+            }
+
+            for plant in plants {
+                let radius = plant.light_radius();
+                if radius <= 0.0 {
+                    continue;
+                }
+                for row in 0..geometry.rows {
+                    for col in first_col..geometry.cols {
+                        let dx = plant.grid_x as f32 - col as f32;
+                        let dy = plant.grid_y as f32 - row as f32;
+                        if dx * dx + dy * dy <= radius * radius {
+                            hidden[row * geometry.cols + col] = false;
+                        }
+                    }
+                }
+            }
+        }
+
+        FogMask { geometry: *geometry, hidden }
+    }
+
+    /// 在所有被遮挡的单元格上绘制迷雾。
+    ///
+    /// 最右侧一列的迷雾会延伸到屏幕右边缘，遮住刚生成的僵尸。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `mask` - 本帧被遮挡的单元格。
+    ///
+    /// # Returns
+    ///
+    /// 如果绘制成功，则返回 `GameResult<()>`。
+    pub fn draw(ctx: &mut Context, mask: &FogMask) -> GameResult {
+        let geometry = &mask.geometry;
+
+        // 所有被遮挡的单元格合并为一个 `Mesh`，一次绘制完成
+        let (screen_width, _) = graphics::drawable_size(ctx);
+        let mut builder = MeshBuilder::new();
+        let mut hidden_cells = 0;
+        for row in 0..geometry.rows {
+            for col in 0..geometry.cols {
+                if !mask.is_cell_hidden(col, row) {
+                    continue;
+                }
+                let x = geometry.column_x(col);
+                let width = if col + 1 == geometry.cols {
+                    (screen_width - x).max(geometry.cell_width)
+                } else {
+                    geometry.cell_width
+                };
+                builder.rectangle(
+                    DrawMode::fill(),
+                    Rect::new(x, geometry.row_y(row), width, geometry.cell_height),
+                    Color::new(0.85, 0.87, 0.9, 0.92),
+                )?;
+                hidden_cells += 1;
+            }
+        }
+        if hidden_cells == 0 {
+            return Ok(());
+        }
+        let fog = builder.build(ctx)?;
+        graphics::draw(ctx, &fog, DrawParam::default())
+    }
+}
+
+/// 一帧中被迷雾遮挡的单元格，由 `Fog::mask` 计算。
+#[derive(Debug, Clone, PartialEq)]
+pub struct FogMask {
+    /// 计算时使用的草坪几何信息。
+    geometry: LawnGeometry,
+    /// 按行优先排列的每个单元格是否被遮挡，迷雾不可见时为空。
+    hidden: Vec<bool>,
+}
+
+impl FogMask {
+    /// 检查指定单元格是否被迷雾遮挡。
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - 单元格的列索引。
+    /// * `row` - 单元格的行索引。
+    ///
+    /// # Returns
+    ///
+    /// 如果单元格被遮挡则返回 `true`，草坪以外的单元格不会被遮挡。
+    pub fn is_cell_hidden(&self, col: usize, row: usize) -> bool {
+        if col >= self.geometry.cols {
+            return false;
+        }
+        self.hidden.get(row * self.geometry.cols + col).copied().unwrap_or(false)
+    }
+
+    /// 检查指定行中某个X坐标处的物体（僵尸或豌豆）是否被迷雾遮挡。
+    ///
+    /// 草坪右边缘以外的位置按最右侧一列处理。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 物体的屏幕X坐标。
+    /// * `row` - 物体所在的行。
+    ///
+    /// # Returns
+    ///
+    /// 如果物体被遮挡则返回 `true`。
+    pub fn hides(&self, x: f32, row: usize) -> bool {
+        if self.hidden.is_empty() || x < self.geometry.origin_x {
+            return false;
+        }
+        let col = (((x - self.geometry.origin_x) / self.geometry.cell_width) as usize).min(self.geometry.cols - 1);
+        self.is_cell_hidden(col, row)
+    }
+}
@@ -2,9 +2,14 @@
 //!
 //! 定义了游戏区域的网格布局，包括其尺寸、单元格大小以及相关操作。
 //! 网格用于确定植物的放置位置、僵尸的移动路径以及其他游戏元素的空间关系。
+//! 网格的形状由关卡的 `LawnLayout` 决定，下面的常量是标准5行草坪的默认值。
 
 use ggez::{Context, GameResult};
-use ggez::graphics::{self, Mesh, Color, DrawParam};
+use ggez::graphics::{self, Mesh, MeshBuilder, Color, DrawParam, DrawMode, Rect};
+use rand::seq::SliceRandom;
+use std::cell::RefCell;
+use crate::ui::lawn::{LawnGeometry, LawnLayout, TileType};
+use crate::plants::{PlantLayer, PlantType};
 
 // 定义网格位置常量
 // -------->x 
@@ -14,31 +19,76 @@ use ggez::graphics::{self, Mesh, Color, DrawParam};
 // v 
 // y
 
-/// 网格在屏幕上的起始X坐标（左边距）。
+/// 标准草坪在屏幕上的起始X坐标（左边距）。
 pub const GRID_START_X: f32 = 254.0;  // 左边距
-/// 网格在屏幕上的起始Y坐标（上边距）。
+/// 标准草坪在屏幕上的起始Y坐标（上边距）。
 pub const GRID_START_Y: f32 = 75.0;  // 上边距
-/// 网格中每个单元格的高度。
+/// 标准草坪中每个单元格的高度。
 pub const GRID_CELL_HEIGHT: f32 = 100.0; // 单元格高度
-/// 网格中每个单元格的宽度。
+/// 标准草坪中每个单元格的宽度。
 pub const GRID_CELL_WIDTH: f32 = 80.0; // 单元格宽度
-/// 网格的宽度（列数）。
+/// 标准草坪的宽度（列数）。
 pub const GRID_WIDTH: usize = 9;      // 网格宽度（列数）
-/// 网格的高度（行数）。
+/// 标准草坪的高度（行数）。
 pub const GRID_HEIGHT: usize = 5;     // 网格高度（行数）
+/// 墓碑只会出现在从这一列（从0开始）到最右侧的区域中。
+pub const GRAVE_MIN_COLUMN: usize = 4;
+
+/// 网格中单个单元格的状态。
+///
+/// 每个单元格分为三层（见 `PlantLayer`），每一层最多种植一株植物。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cell {
+    /// 底层是否种有植物（例如水面上的睡莲）。陆地植物可以种在底层植物上。
+    pub underlay: bool,
+    /// 主层是否已经种植了植物。
+    pub occupied: bool,
+    /// 外壳层是否种有植物（例如南瓜头）。
+    pub shell: bool,
+    /// 单元格中是否有墓碑。有墓碑的单元格不能种植植物。
+    pub grave: bool,
+}
+
+impl Cell {
+    /// 检查指定的层是否已经种有植物。
+    pub fn has(&self, layer: PlantLayer) -> bool {
+        match layer {
+            PlantLayer::Underlay => self.underlay,
+            PlantLayer::Main => self.occupied,
+            PlantLayer::Shell => self.shell,
+        }
+    }
+
+    /// 设置指定的层是否种有植物。
+    pub fn set(&mut self, layer: PlantLayer, filled: bool) {
+        match layer {
+            PlantLayer::Underlay => self.underlay = filled,
+            PlantLayer::Main => self.occupied = filled,
+            PlantLayer::Shell => self.shell = filled,
+        }
+    }
+
+    /// 检查单元格的所有层是否都是空的。
+    pub fn is_empty(&self) -> bool {
+        !self.underlay && !self.occupied && !self.shell
+    }
+}
 
 /// 代表游戏区域的网格结构。
 ///
-/// `Grid` 结构体维护一个二维数组 `occupied` 来跟踪哪些单元格已被植物占据。
+/// `Grid` 结构体维护一个二维数组 `cells` 来跟踪每个单元格的状态（是否种植了植物、是否有墓碑）。
 /// 它提供了将屏幕坐标转换为网格坐标、检查单元格占用状态以及在屏幕上绘制网格线的方法。
 pub struct Grid {
-    /// 一个二维布尔数组，标记网格单元格是否被占据。
-    /// `occupied[y][x]` 为 `true` 表示 (x, y) 位置的单元格被占据。
-    occupied: [[bool; GRID_WIDTH]; GRID_HEIGHT], // 使用常量定义数组大小
+    /// 当前关卡的草坪布局。
+    layout: LawnLayout,
+    /// 一个二维单元格数组，`cells[y][x]` 表示 (x, y) 位置的单元格，尺寸由 `layout` 决定。
+    cells: Vec<Vec<Cell>>,
+    /// 缓存的网格图形（地形覆盖、网格线和墓碑），第一次绘制时生成，墓碑变化时失效。
+    mesh: RefCell<Option<Mesh>>,
 }
 
 impl Grid {
-    /// 创建一个新的 `Grid` 实例。
+    /// 创建一个使用标准5行草坪布局的 `Grid` 实例。
     ///
     /// 初始化时，所有网格单元格都标记为未被占据。
     ///
@@ -46,8 +96,73 @@ impl Grid {
     ///
     /// 返回一个新的 `Grid` 实例。
     pub fn new() -> Self {
+        Grid::with_layout(LawnLayout::standard())
+    }
+
+    /// 创建一个使用指定草坪布局的 `Grid` 实例。
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - 关卡的草坪布局。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Grid` 实例，所有单元格都未被占据。
+    pub fn with_layout(layout: LawnLayout) -> Self {
+        let geometry = layout.geometry;
         Grid {
-            occupied: [[false; GRID_WIDTH]; GRID_HEIGHT], // 使用常量初始化
+            layout,
+            cells: vec![vec![Cell::default(); geometry.cols]; geometry.rows],
+            mesh: RefCell::new(None),
+        }
+    }
+
+    /// 获取网格使用的草坪布局。
+    pub fn layout(&self) -> &LawnLayout {
+        &self.layout
+    }
+
+    /// 获取网格的几何信息。
+    pub fn geometry(&self) -> LawnGeometry {
+        self.layout.geometry
+    }
+
+    /// 使用指定的草坪布局创建一个带有随机墓碑的 `Grid` 实例，开始关卡时使用。
+    ///
+    /// 墓碑随机分布在 `GRAVE_MIN_COLUMN` 列及其右侧的草地上，不会重叠。
+    ///
+    /// # Arguments
+    ///
+    /// * `layout` - 关卡的草坪布局。
+    /// * `count` - 墓碑数量，超过可放置的单元格数量时会被截断，0 表示没有墓碑。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Grid` 实例。
+    pub fn with_graves(layout: LawnLayout, count: usize) -> Self {
+        let mut grid = Grid::with_layout(layout);
+        grid.place_random_graves(count);
+        grid
+    }
+
+    /// 在可种植的单元格中随机放置墓碑。
+    ///
+    /// 墓碑随机分布在 `GRAVE_MIN_COLUMN` 列及其右侧的草地上，不会与已有墓碑重叠。
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - 墓碑数量，超过可放置的单元格数量时会被截断。
+    pub fn place_random_graves(&mut self, count: usize) {
+        let geometry = self.geometry();
+        let mut candidates: Vec<(usize, usize)> = (0..geometry.rows)
+            .filter(|&y| self.layout.tile(y) == TileType::Grass)
+            .flat_map(|y| (GRAVE_MIN_COLUMN..geometry.cols).map(move |x| (x, y)))
+            .filter(|&(x, y)| !self.has_grave(x, y))
+            .collect();
+        candidates.shuffle(&mut rand::thread_rng());
+
+        for &(x, y) in candidates.iter().take(count) {
+            self.place_grave(x, y);
         }
     }
 
@@ -64,22 +179,10 @@ impl Grid {
     /// 其中 `grid_x` 是列索引，`grid_y` 是行索引。
     /// 如果坐标在网格范围之外，则返回 `None`。
     pub fn get_grid_position(&self, x: f32, y: f32) -> Option<(usize, usize)> {
-        if x < GRID_START_X || x > GRID_START_X + GRID_CELL_WIDTH * GRID_WIDTH as f32 || 
-           y < GRID_START_Y || y > GRID_START_Y + GRID_CELL_HEIGHT * GRID_HEIGHT as f32 {
-            return None;
-        }
-        
-        let grid_x = ((x - GRID_START_X) / GRID_CELL_WIDTH) as usize;
-        let grid_y = ((y - GRID_START_Y) / GRID_CELL_HEIGHT) as usize;
-        
-        if grid_x < GRID_WIDTH && grid_y < GRID_HEIGHT {
-            Some((grid_x, grid_y))
-        } else {
-            None
-        }
+        self.layout.geometry.cell_at(x, y)
     }
 
-    /// 检查指定的网格单元格是否已被占据。
+    /// 检查指定的网格单元格的主层是否已被占据。
     ///
     /// # Arguments
     ///
@@ -90,40 +193,117 @@ impl Grid {
     ///
     /// 如果单元格 `(x, y)` 被占据，则返回 `true`，否则返回 `false`。
     /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
-    /// 调用者应确保索引在 `0..GRID_WIDTH` 和 `0..GRID_HEIGHT` 范围内。
+    /// 调用者应确保索引在布局的列数和行数范围内。
     pub fn is_occupied(&self, x: usize, y: usize) -> bool {
-        self.occupied[y][x]
+        self.cells[y][x].occupied
     }
 
-    /// 将指定的网格单元格标记为已占据。
+    /// 获取指定单元格的状态。
     ///
-    /// # Arguments
+    /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
+    pub fn cell(&self, x: usize, y: usize) -> &Cell {
+        &self.cells[y][x]
+    }
+
+    /// 检查指定单元格中是否有墓碑。
     ///
-    /// * `x` - 网格的列索引。
-    /// * `y` - 网格的行索引。
+    /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
+    pub fn has_grave(&self, x: usize, y: usize) -> bool {
+        self.cells[y][x].grave
+    }
+
+    /// 在指定单元格中放置一个墓碑。
+    ///
+    /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
+    pub fn place_grave(&mut self, x: usize, y: usize) {
+        self.cells[y][x].grave = true;
+        self.mesh.get_mut().take();
+    }
+
+    /// 移除指定单元格中的墓碑。
     ///
     /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
-    /// 调用者应确保索引在 `0..GRID_WIDTH` 和 `0..GRID_HEIGHT` 范围内。
-    pub fn occupy(&mut self, x: usize, y: usize) {
-        self.occupied[y][x] = true;
+    pub fn remove_grave(&mut self, x: usize, y: usize) {
+        self.cells[y][x].grave = false;
+        self.mesh.get_mut().take();
+    }
+
+    /// 获取所有墓碑的网格坐标，按行优先顺序排列。
+    ///
+    /// # Returns
+    ///
+    /// 返回 `(grid_x, grid_y)` 坐标列表。
+    pub fn graves(&self) -> Vec<(usize, usize)> {
+        let geometry = self.geometry();
+        (0..geometry.rows)
+            .flat_map(|y| (0..geometry.cols).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.has_grave(x, y))
+            .collect()
     }
-    
-    /// 将指定的网格单元格标记为未占据。
+
+    /// 检查指定类型的植物能否种在指定单元格中。
+    ///
+    /// 放置规则如下：
+    /// - 泥地和有墓碑的单元格不能种植任何植物。
+    /// - 植物所在的层（见 `PlantType::layer`）必须是空的。
+    /// - 底层植物只能种在完全空的单元格中，不能塞到已有植物的下面。
+    /// - 水生植物只能种在水面上，且下面不能有底层植物。
+    /// - 陆地植物（包括南瓜头）可以种在草地上，或者种在水面上已有的底层植物上。
+    /// - 屋顶上只能直接种花盆，其他植物都必须种在花盆上。
     ///
     /// # Arguments
     ///
     /// * `x` - 网格的列索引。
     /// * `y` - 网格的行索引。
+    /// * `plant_type` - 要种植的植物类型。
+    ///
+    /// # Returns
+    ///
+    /// 如果可以种植则返回 `true`，否则返回 `false`。
+    ///
+    /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
+    pub fn can_place(&self, x: usize, y: usize, plant_type: PlantType) -> bool {
+        let cell = self.cell(x, y);
+        let layer = plant_type.layer();
+        if cell.grave || cell.has(layer) {
+            return false;
+        }
+        if layer == PlantLayer::Underlay && !cell.is_empty() {
+            return false;
+        }
+
+        match self.layout.tile(y) {
+            TileType::Grass => !plant_type.is_aquatic(),
+            TileType::Roof => !plant_type.is_aquatic() && (layer == PlantLayer::Underlay || cell.underlay),
+            TileType::Water => {
+                if plant_type.is_aquatic() {
+                    !cell.underlay
+                } else {
+                    cell.underlay
+                }
+            }
+            TileType::Dirt => false,
+        }
+    }
+
+    /// 在指定单元格中记录一株新种下的植物，填充它所在的层。
+    ///
+    /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
+    pub fn place(&mut self, x: usize, y: usize, plant_type: PlantType) {
+        self.cells[y][x].set(plant_type.layer(), true);
+    }
+
+    /// 从指定单元格中移除一株植物的记录，清空它所在的层，与 `place` 相对应。
     ///
     /// **注意**: 此方法假设 `x` 和 `y` 是有效的网格索引，不进行边界检查。
-    /// 调用者应确保索引在 `0..GRID_WIDTH` 和 `0..GRID_HEIGHT` 范围内。
-    pub fn unoccupy(&mut self, x: usize, y: usize) {
-        self.occupied[y][x] = false;
+    pub fn remove(&mut self, x: usize, y: usize, plant_type: PlantType) {
+        self.cells[y][x].set(plant_type.layer(), false);
     }
 
-    /// 在屏幕上绘制网格线。
+    /// 在屏幕上绘制网格线和墓碑。
     ///
-    /// 此方法使用ggez的图形功能来渲染网格的垂直和水平线。
+    /// 地形覆盖、网格线和墓碑合并为一个 `Mesh`，第一次绘制时生成并缓存，之后每一帧只需一次绘制调用。
+    /// 放置或移除墓碑会让缓存失效。
     ///
     /// # Arguments
     ///
@@ -133,33 +313,89 @@ impl Grid {
     ///
     /// 如果绘制成功，则返回 `GameResult<()>`。
     pub fn draw(&self, ctx: &mut Context) -> GameResult {
+        let mut mesh = self.mesh.borrow_mut();
+        if mesh.is_none() {
+            *mesh = Some(self.build_mesh(ctx)?);
+        }
+        graphics::draw(ctx, mesh.as_ref().unwrap(), DrawParam::default())
+    }
+
+    /// 生成网格的 `Mesh`：地形覆盖、网格线和墓碑。
+    ///
+    /// 墓碑以灰色石碑的形状绘制在对应单元格中。
+    fn build_mesh(&self, ctx: &mut Context) -> GameResult<Mesh> {
+        let geometry = self.geometry();
+        let mut builder = MeshBuilder::new();
+
+        // 泥地行用半透明的棕色覆盖，表示不能种植
+        for row in (0..geometry.rows).filter(|&row| self.layout.tile(row) == TileType::Dirt) {
+            builder.rectangle(
+                DrawMode::fill(),
+                Rect::new(geometry.origin_x, geometry.row_y(row), geometry.width(), geometry.cell_height),
+                Color::new(0.45, 0.3, 0.15, 0.6),
+            )?;
+        }
+
+        // 水面行用半透明的蓝色覆盖
+        for row in (0..geometry.rows).filter(|&row| self.layout.tile(row) == TileType::Water) {
+            builder.rectangle(
+                DrawMode::fill(),
+                Rect::new(geometry.origin_x, geometry.row_y(row), geometry.width(), geometry.cell_height),
+                Color::new(0.2, 0.45, 0.85, 0.55),
+            )?;
+        }
+
+        // 屋顶行按地形高度用深浅不同的瓦片颜色覆盖，越高颜色越深
+        for row in (0..geometry.rows).filter(|&row| self.layout.tile(row) == TileType::Roof) {
+            for col in 0..geometry.cols {
+                let shade = 0.25 + geometry.terrain_height(col) / 400.0;
+                builder.rectangle(
+                    DrawMode::fill(),
+                    Rect::new(geometry.column_x(col), geometry.row_y(row), geometry.cell_width, geometry.cell_height),
+                    Color::new(0.6, 0.25, 0.15, shade.min(0.75)),
+                )?;
+            }
+        }
+
         // 绘制网格线
-        for i in 0..=GRID_WIDTH {
+        for i in 0..=geometry.cols {
             //绘制的起始x 也就是每一列的左侧位置
-            let x = GRID_START_X + i as f32 * GRID_CELL_WIDTH;
-            let line = Mesh::new_line(
-                ctx,
+            let x = geometry.column_x(i);
+            builder.line(
                 //第一个参数是该列的左上角,第二个参数是该列的左下角
-                &[[x, GRID_START_Y], [x, GRID_START_Y + GRID_CELL_HEIGHT * GRID_HEIGHT as f32]],
+                &[[x, geometry.origin_y], [x, geometry.bottom()]],
                 1.0,
                 Color::new(0.0, 0.0, 0.0, 0.2),
             )?;
-            graphics::draw(ctx, &line, DrawParam::default())?;
         }
 
-        for i in 0..=GRID_HEIGHT {
+        for i in 0..=geometry.rows {
             //绘制的起始y 也就是每一行的上侧位置
-            let y = GRID_START_Y + i as f32 * GRID_CELL_HEIGHT;
-            let line = Mesh::new_line(
-                ctx,
+            let y = geometry.row_y(i);
+            builder.line(
                 //第一个参数是该行的左上角,第二个参数是该行的右上角
-                &[[GRID_START_X, y], [GRID_START_X + GRID_CELL_WIDTH * GRID_WIDTH as f32, y]],
+                &[[geometry.origin_x, y], [geometry.right(), y]],
                 1.0,
                 Color::new(0.0, 0.0, 0.0, 0.2),
             )?;
-            graphics::draw(ctx, &line, DrawParam::default())?;
         }
-        
-        Ok(())
+
+        // 绘制墓碑：底座加上略窄的碑身
+        for (x, y) in self.graves() {
+            let cell_x = geometry.column_x(x);
+            let cell_y = geometry.row_y(y);
+            builder.rectangle(
+                DrawMode::fill(),
+                Rect::new(cell_x + 20.0, cell_y + 25.0, geometry.cell_width - 40.0, geometry.cell_height - 40.0),
+                Color::new(0.45, 0.45, 0.5, 1.0),
+            )?;
+            builder.rectangle(
+                DrawMode::fill(),
+                Rect::new(cell_x + 12.0, cell_y + geometry.cell_height - 25.0, geometry.cell_width - 24.0, 12.0),
+                Color::new(0.3, 0.25, 0.2, 1.0),
+            )?;
+        }
+
+        builder.build(ctx)
     }
 }
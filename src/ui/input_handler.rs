@@ -3,11 +3,11 @@
 //! 负责处理游戏中的用户输入，主要是鼠标点击事件。
 //! 它管理着阳光的收集、商店中植物卡片的选择、以及在网格上放置植物的逻辑。
 
-use crate::ui::grid::{Grid, GRID_START_X, GRID_START_Y, GRID_CELL_HEIGHT, GRID_CELL_WIDTH, GRID_WIDTH, GRID_HEIGHT};
+use crate::ui::grid::Grid;
 use crate::plants::{Plant, PlantType};
 use crate::ui::shop::{Shop, SHOP_START_Y, CARD_HEIGHT};
 use crate::ui::shovel::Shovel;
-use crate::entities::sun::Sun;
+use crate::entities::sun::{Sun, SUN_VALUE};
 use ggez::input::mouse::MouseButton;
 
 /// `InputHandler` 结构体。
@@ -83,7 +83,7 @@ impl InputHandler {
             let initial_sun_count = *sun_count;
             suns.retain(|sun| {
                 if sun.contains_point(x, y) {
-                    *sun_count += 25;
+                    *sun_count += SUN_VALUE;
                     false // Remove the sun
                 } else {
                     true // Keep the sun
@@ -103,8 +103,7 @@ impl InputHandler {
 
             // 处理植物放置逻辑
             if selected_plant.is_some() {
-                if x >= GRID_START_X && x <= GRID_START_X + GRID_CELL_WIDTH * GRID_WIDTH as f32 &&
-                   y >= GRID_START_Y && y <= GRID_START_Y + GRID_CELL_HEIGHT * GRID_HEIGHT as f32 {
+                if grid.get_grid_position(x, y).is_some() {
                    if Self::place_plant(x, y, grid, plants, selected_plant, sun_count, shop) {
                        // 放置成功，清除选择状态
                        shop.selected_plant = None;
@@ -163,12 +162,12 @@ impl InputHandler {
     ) -> bool {
         if let Some(plant_type) = selected_plant {
             if let Some((grid_x, grid_y)) = grid.get_grid_position(x, y) {
-                // 检查是否已有植物
-                if !grid.is_occupied(grid_x, grid_y) && *sun_count >= plant_type.cost() {
-                    let plant = Plant::new(*plant_type, grid_x, grid_y);
+                // 检查地形、已有植物、睡莲和墓碑是否允许种植该植物
+                if grid.can_place(grid_x, grid_y, *plant_type) && *sun_count >= plant_type.cost() {
+                    let plant = Plant::on_lawn(*plant_type, grid_x, grid_y, grid.geometry());
                     plants.push(plant);
                     *sun_count -= plant_type.cost();
-                    grid.occupy(grid_x, grid_y);
+                    grid.place(grid_x, grid_y, *plant_type);
 
                     // 在植物成功放置后，才触发卡片冷却
                     shop.trigger_card_cooldown(*plant_type);
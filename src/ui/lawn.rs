@@ -0,0 +1,233 @@
+//! # 草坪布局模块 (`lawn`)
+//!
+//! 描述关卡中草坪的形状：行数、列数、在屏幕上的位置、单元格大小以及每一行的地形。
+//! 不同关卡可以使用不同的布局，例如标准的5行草坪、只有3行的教学草坪，或者6行的泳池草坪。
+//!
+//! `LawnGeometry` 只包含尺寸和位置信息，可以廉价地复制给僵尸、豌豆和植物，
+//! 使它们在任意布局上都能正确地计算自己的屏幕坐标。
+
+use crate::ui::grid::{GRID_CELL_HEIGHT, GRID_CELL_WIDTH, GRID_HEIGHT, GRID_START_X, GRID_START_Y, GRID_WIDTH};
+use serde::Deserialize;
+
+/// 僵尸生成时距离草坪右边缘向内的距离（像素）。
+const ZOMBIE_SPAWN_INSET: f32 = 24.0;
+/// 豌豆飞出草坪右边缘多远后被移除（像素）。
+const PROJECTILE_CULL_MARGIN: f32 = 26.0;
+
+/// 草坪的几何信息：行列数、左上角位置和单元格大小。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LawnGeometry {
+    /// 行数。
+    pub rows: usize,
+    /// 列数。
+    pub cols: usize,
+    /// 草坪左上角的X坐标。
+    pub origin_x: f32,
+    /// 草坪左上角的Y坐标。
+    pub origin_y: f32,
+    /// 单元格宽度。
+    pub cell_width: f32,
+    /// 单元格高度。
+    pub cell_height: f32,
+    /// 从最左侧一列开始有多少列是斜坡（屋顶关卡），0 表示整块草坪是平的。
+    pub slope_columns: usize,
+    /// 斜坡上每向右一列地形升高的高度（像素）。
+    pub slope_step: f32,
+}
+
+impl LawnGeometry {
+    /// 标准的5行9列白天草坪，与背景图片对齐。
+    pub const STANDARD: LawnGeometry = LawnGeometry {
+        rows: GRID_HEIGHT,
+        cols: GRID_WIDTH,
+        origin_x: GRID_START_X,
+        origin_y: GRID_START_Y,
+        cell_width: GRID_CELL_WIDTH,
+        cell_height: GRID_CELL_HEIGHT,
+        slope_columns: 0,
+        slope_step: 0.0,
+    };
+
+    /// 草坪的总宽度（像素）。
+    pub fn width(&self) -> f32 {
+        self.cell_width * self.cols as f32
+    }
+
+    /// 草坪的总高度（像素）。
+    pub fn height(&self) -> f32 {
+        self.cell_height * self.rows as f32
+    }
+
+    /// 草坪右边缘的X坐标。
+    pub fn right(&self) -> f32 {
+        self.origin_x + self.width()
+    }
+
+    /// 草坪下边缘的Y坐标。
+    pub fn bottom(&self) -> f32 {
+        self.origin_y + self.height()
+    }
+
+    /// 第 `col` 列左边缘的X坐标。
+    pub fn column_x(&self, col: usize) -> f32 {
+        self.origin_x + col as f32 * self.cell_width
+    }
+
+    /// 第 `row` 行上边缘的Y坐标。
+    pub fn row_y(&self, row: usize) -> f32 {
+        self.origin_y + row as f32 * self.cell_height
+    }
+
+    /// 将屏幕像素坐标转换为网格坐标。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 屏幕上的X坐标。
+    /// * `y` - 屏幕上的Y坐标。
+    ///
+    /// # Returns
+    ///
+    /// 如果坐标在草坪范围内，返回 `Some((grid_x, grid_y))`，否则返回 `None`。
+    pub fn cell_at(&self, x: f32, y: f32) -> Option<(usize, usize)> {
+        if x < self.origin_x || x > self.right() || y < self.origin_y || y > self.bottom() {
+            return None;
+        }
+
+        let grid_x = ((x - self.origin_x) / self.cell_width) as usize;
+        let grid_y = ((y - self.origin_y) / self.cell_height) as usize;
+
+        if grid_x < self.cols && grid_y < self.rows {
+            Some((grid_x, grid_y))
+        } else {
+            None
+        }
+    }
+
+    /// 第 `col` 列的地形高度（像素）。
+    ///
+    /// 斜坡从最左侧一列开始逐列升高，斜坡右侧的地形与斜坡顶端一样高。平坦的草坪始终为 0。
+    pub fn terrain_height(&self, col: usize) -> f32 {
+        self.slope_step * col.min(self.slope_columns) as f32
+    }
+
+    /// 屏幕X坐标处的地形高度（像素），草坪左右两侧以外的位置按最近的一列计算。
+    pub fn terrain_height_at(&self, x: f32) -> f32 {
+        let col = ((x - self.origin_x).max(0.0) / self.cell_width) as usize;
+        self.terrain_height(col.min(self.cols.saturating_sub(1)))
+    }
+
+    /// 僵尸生成时的X坐标，位于草坪右边缘附近。
+    pub fn zombie_spawn_x(&self) -> f32 {
+        self.right() - ZOMBIE_SPAWN_INSET
+    }
+
+    /// 豌豆等投射物超过此X坐标后会被移除。
+    pub fn projectile_cull_x(&self) -> f32 {
+        self.right() + PROJECTILE_CULL_MARGIN
+    }
+}
+
+impl Default for LawnGeometry {
+    fn default() -> Self {
+        LawnGeometry::STANDARD
+    }
+}
+
+/// 草坪中一整行的地形类型。
+///
+/// 关卡文件中以小写名称表示，例如 `"grass"`、`"water"`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TileType {
+    /// 普通草地，可以种植植物，僵尸会从这一行出现。
+    Grass,
+    /// 没有铺草皮的泥地，不能种植植物，也不会有僵尸出现。
+    Dirt,
+    /// 泳池中的水面，只能种植水生植物，或者种在睡莲上的陆地植物。僵尸会游泳经过。
+    Water,
+    /// 屋顶的瓦片，任何植物都必须种在花盆上。
+    Roof,
+}
+
+/// 关卡的草坪布局：几何信息加上每一行的地形。
+///
+/// 内置的布局（标准、教学、泳池、屋顶）由对应的构造函数创建；关卡文件中也可以直接写出行列数、
+/// 位置、单元格大小和每一行的地形（见 `mechanics::campaign`）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LawnLayout {
+    /// 草坪的几何信息。
+    pub geometry: LawnGeometry,
+    /// 每一行的地形，长度应与 `geometry.rows` 一致，缺少的行视为草地。
+    pub row_tiles: Vec<TileType>,
+}
+
+impl LawnLayout {
+    /// 标准的5行草地布局。
+    pub fn standard() -> LawnLayout {
+        LawnLayout {
+            geometry: LawnGeometry::STANDARD,
+            row_tiles: vec![TileType::Grass; GRID_HEIGHT],
+        }
+    }
+
+    /// 只有中间3行的教学草坪，位于标准草坪的第2至第4行。
+    pub fn tutorial() -> LawnLayout {
+        LawnLayout {
+            geometry: LawnGeometry {
+                rows: 3,
+                origin_y: GRID_START_Y + GRID_CELL_HEIGHT,
+                ..LawnGeometry::STANDARD
+            },
+            row_tiles: vec![TileType::Grass; 3],
+        }
+    }
+
+    /// 6行的泳池草坪，中间两行是水面。单元格略矮，使6行能放进同样的屏幕区域。
+    pub fn pool() -> LawnLayout {
+        LawnLayout {
+            geometry: LawnGeometry {
+                rows: 6,
+                cell_height: 85.0,
+                ..LawnGeometry::STANDARD
+            },
+            row_tiles: vec![
+                TileType::Grass,
+                TileType::Grass,
+                TileType::Water,
+                TileType::Water,
+                TileType::Grass,
+                TileType::Grass,
+            ],
+        }
+    }
+
+    /// 5行的屋顶，左侧5列是逐渐升高的斜坡。
+    pub fn roof() -> LawnLayout {
+        LawnLayout {
+            geometry: LawnGeometry {
+                slope_columns: 5,
+                slope_step: 20.0,
+                ..LawnGeometry::STANDARD
+            },
+            row_tiles: vec![TileType::Roof; GRID_HEIGHT],
+        }
+    }
+
+    /// 获取第 `row` 行的地形。
+    pub fn tile(&self, row: usize) -> TileType {
+        self.row_tiles.get(row).copied().unwrap_or(TileType::Grass)
+    }
+
+    /// 获取所有会有僵尸出现的行（所有非泥地的行）。
+    pub fn spawn_rows(&self) -> Vec<usize> {
+        (0..self.geometry.rows)
+            .filter(|&row| self.tile(row) != TileType::Dirt)
+            .collect()
+    }
+}
+
+impl Default for LawnLayout {
+    fn default() -> Self {
+        LawnLayout::standard()
+    }
+}
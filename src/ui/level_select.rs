@@ -0,0 +1,188 @@
+//! # 选关界面模块 (`level_select`)
+//!
+//! 显示冒险模式中的所有关卡，并标出每一关是未解锁、已解锁还是已通关。
+//! 玩家点击已解锁或已通关的关卡即可开始游戏。
+//! 关卡按钮下方还有进入无尽模式的按钮。
+
+use ggez::{Context, GameResult};
+use ggez::graphics::{self, Color, DrawMode, DrawParam, Mesh, Rect, Text, TextFragment};
+use crate::core::profile::Profile;
+use crate::core::resources::Resources;
+use crate::mechanics::campaign::{self, LevelStatus};
+
+/// 关卡按钮的宽度。
+pub const LEVEL_BUTTON_WIDTH: f32 = 200.0;
+/// 关卡按钮的高度。
+pub const LEVEL_BUTTON_HEIGHT: f32 = 120.0;
+/// 第一个关卡按钮的X坐标。
+pub const LEVEL_BUTTON_START_X: f32 = 240.0;
+/// 第一行关卡按钮的Y坐标。
+pub const LEVEL_BUTTON_START_Y: f32 = 170.0;
+/// 相邻关卡按钮之间的间距。
+pub const LEVEL_BUTTON_SPACING: f32 = 40.0;
+/// 每一行最多显示的关卡按钮数量。
+pub const LEVEL_BUTTONS_PER_ROW: usize = 4;
+
+/// 无尽模式按钮的宽度。
+pub const ENDLESS_BUTTON_WIDTH: f32 = 200.0;
+/// 无尽模式按钮的高度。
+pub const ENDLESS_BUTTON_HEIGHT: f32 = 60.0;
+
+/// 玩家在选关界面中的选择。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelChoice {
+    /// 冒险模式中的关卡，值为关卡在当前关卡列表中的索引。
+    Adventure(usize),
+    /// 无尽模式。
+    Endless,
+}
+
+/// 选关界面。
+///
+/// 这是一个无状态的结构体，关卡的状态全部来自传入的 `Profile`。
+pub struct LevelSelect;
+
+impl LevelSelect {
+    /// 计算第 `index` 个关卡按钮的矩形区域，超过一行时自动换行。
+    pub fn button_rect(index: usize) -> Rect {
+        let column = index % LEVEL_BUTTONS_PER_ROW;
+        let row = index / LEVEL_BUTTONS_PER_ROW;
+        Rect::new(
+            LEVEL_BUTTON_START_X + (LEVEL_BUTTON_WIDTH + LEVEL_BUTTON_SPACING) * column as f32,
+            LEVEL_BUTTON_START_Y + (LEVEL_BUTTON_HEIGHT + LEVEL_BUTTON_SPACING) * row as f32,
+            LEVEL_BUTTON_WIDTH,
+            LEVEL_BUTTON_HEIGHT,
+        )
+    }
+
+    /// 无尽模式按钮的矩形区域，位于全部关卡按钮的下方并水平居中。
+    pub fn endless_button_rect() -> Rect {
+        let level_count = campaign::level_count();
+        let rows = level_count.div_ceil(LEVEL_BUTTONS_PER_ROW);
+        let columns = level_count.clamp(1, LEVEL_BUTTONS_PER_ROW);
+        let row_width = LEVEL_BUTTON_WIDTH * columns as f32 + LEVEL_BUTTON_SPACING * (columns - 1) as f32;
+        Rect::new(
+            LEVEL_BUTTON_START_X + row_width / 2.0 - ENDLESS_BUTTON_WIDTH / 2.0,
+            LEVEL_BUTTON_START_Y + (LEVEL_BUTTON_HEIGHT + LEVEL_BUTTON_SPACING) * rows as f32,
+            ENDLESS_BUTTON_WIDTH,
+            ENDLESS_BUTTON_HEIGHT,
+        )
+    }
+
+    /// 处理选关界面中的鼠标点击。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 鼠标点击的X坐标。
+    /// * `y` - 鼠标点击的Y坐标。
+    /// * `profile` - 玩家存档，用于判断关卡是否已解锁。
+    ///
+    /// # Returns
+    ///
+    /// 如果点击了可进入的关卡或无尽模式按钮，返回对应的 `LevelChoice`，否则返回 `None`。
+    pub fn handle_click(x: f32, y: f32, profile: &Profile) -> Option<LevelChoice> {
+        if LevelSelect::endless_button_rect().contains([x, y]) {
+            return Some(LevelChoice::Endless);
+        }
+
+        (0..campaign::level_count())
+            .find(|&index| {
+                LevelSelect::button_rect(index).contains([x, y])
+                    && profile.level_status(index) != LevelStatus::Locked
+            })
+            .map(LevelChoice::Adventure)
+    }
+
+    /// 绘制选关界面。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 游戏资源，用于绘制背景。
+    /// * `profile` - 玩家存档，用于显示关卡状态。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw(ctx: &mut Context, resources: &Resources, profile: &Profile) -> GameResult {
+        graphics::draw(ctx, resources.image("background"), DrawParam::default())?;
+
+        // 半透明遮罩，使关卡按钮更醒目
+        let screen_size = graphics::drawable_size(ctx);
+        let overlay = Mesh::new_rectangle(
+            ctx,
+            DrawMode::fill(),
+            Rect::new(0.0, 0.0, screen_size.0, screen_size.1),
+            Color::new(0.0, 0.0, 0.0, 0.4),
+        )?;
+        graphics::draw(ctx, &overlay, DrawParam::default())?;
+
+        let title = Text::new(
+            TextFragment::new("Adventure")
+                .color(Color::WHITE)
+                .scale(70.0)
+        );
+        let title_width = title.width(ctx);
+        graphics::draw(ctx, &title, DrawParam::default().dest([screen_size.0 / 2.0 - title_width / 2.0, 60.0]))?;
+
+        for (index, level) in campaign::levels().iter().enumerate() {
+            let rect = LevelSelect::button_rect(index);
+            let status = profile.level_status(index);
+            let (fill, status_label) = match status {
+                LevelStatus::Locked => (Color::new(0.4, 0.4, 0.4, 0.9), "Locked"),
+                LevelStatus::Unlocked => (Color::new(0.95, 0.85, 0.5, 0.95), "Unlocked"),
+                LevelStatus::Completed => (Color::new(0.5, 0.85, 0.4, 0.95), "Completed"),
+            };
+
+            let button = Mesh::new_rectangle(ctx, DrawMode::fill(), rect, fill)?;
+            graphics::draw(ctx, &button, DrawParam::default())?;
+
+            let name_text = Text::new(
+                TextFragment::new(format!("{} {}", level.id, level.name))
+                    .color(Color::BLACK)
+                    .scale(28.0)
+            );
+            let name_width = name_text.width(ctx);
+            graphics::draw(ctx, &name_text, DrawParam::default().dest([rect.x + rect.w / 2.0 - name_width / 2.0, rect.y + 25.0]))?;
+
+            let status_text = Text::new(
+                TextFragment::new(status_label)
+                    .color(Color::BLACK)
+                    .scale(20.0)
+            );
+            let status_width = status_text.width(ctx);
+            graphics::draw(ctx, &status_text, DrawParam::default().dest([rect.x + rect.w / 2.0 - status_width / 2.0, rect.y + 75.0]))?;
+        }
+
+        // 无尽模式按钮
+        let endless_rect = LevelSelect::endless_button_rect();
+        let endless_button = Mesh::new_rectangle(ctx, DrawMode::fill(), endless_rect, Color::new(0.6, 0.4, 0.8, 0.95))?;
+        graphics::draw(ctx, &endless_button, DrawParam::default())?;
+
+        let endless_text = Text::new(
+            TextFragment::new("Endless")
+                .color(Color::BLACK)
+                .scale(28.0)
+        );
+        let endless_width = endless_text.width(ctx);
+        let endless_height = endless_text.height(ctx);
+        graphics::draw(
+            ctx,
+            &endless_text,
+            DrawParam::default().dest([
+                endless_rect.x + endless_rect.w / 2.0 - endless_width / 2.0,
+                endless_rect.y + endless_rect.h / 2.0 - endless_height / 2.0,
+            ]),
+        )?;
+
+        let hint = Text::new(
+            TextFragment::new("Make sure the screen zoom ratio is 100%.")
+                .color(Color::WHITE)
+                .scale(24.0)
+        );
+        let hint_width = hint.width(ctx);
+        graphics::draw(ctx, &hint, DrawParam::default().dest([screen_size.0 / 2.0 - hint_width / 2.0, screen_size.1 - 60.0]))?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,14 @@
 //! # UI模块 (`ui`)
 //!
-//! 包含与用户界面相关的子模块，例如游戏网格 (`grid`)、
-//! 植物商店 (`shop`)、铲子功能 (`shovel`) 以及输入处理 (`input_handler`)。
+//! 包含与用户界面相关的子模块，例如游戏网格 (`grid`)、草坪布局 (`lawn`)、
+//! 植物商店 (`shop`)、铲子功能 (`shovel`)、输入处理 (`input_handler`)、选关界面 (`level_select`)、选卡界面 (`seed_chooser`) 以及迷雾 (`fog`)。
 //! 这个模块组织了所有直接与玩家交互或在屏幕上呈现视觉元素的组件。
 
 pub mod grid;
+pub mod lawn;
 pub mod shop;
 pub mod input_handler;
-pub mod shovel;
\ No newline at end of file
+pub mod shovel;
+pub mod level_select;
+pub mod seed_chooser;
+pub mod fog;
\ No newline at end of file
@@ -0,0 +1,274 @@
+//! # 选卡界面模块 (`seed_chooser`)
+//!
+//! 在每个关卡开始前，玩家需要从已解锁的植物中挑选最多 N 张卡片带入关卡。
+//! 选卡时草坪会向右滚动，展示本关将要出现的僵尸。
+//! 选好之后，选中的卡片会按顺序填充到商店 (`Shop`) 中。
+
+use ggez::{Context, GameResult};
+use ggez::graphics::{self, Color, DrawMode, DrawParam, Mesh, Rect, Text, TextFragment};
+use crate::core::resources::Resources;
+use crate::plants::PlantType;
+use crate::ui::shop::{CARD_WIDTH, CARD_HEIGHT, CARD_SPACING, SHOP_START_X, SHOP_START_Y};
+
+/// 默认的卡槽数量，定义在玩家存档中。
+pub use crate::core::profile::DEFAULT_SEED_SLOTS;
+
+/// 可选卡片面板的左上角X坐标。
+pub const CHOOSER_START_X: f32 = 260.0;
+/// 可选卡片面板的左上角Y坐标。
+pub const CHOOSER_START_Y: f32 = 130.0;
+/// 可选卡片面板每行显示的卡片数量。
+pub const CHOOSER_COLUMNS: usize = 8;
+/// 可选卡片面板中每个格子的宽度。
+const CHOOSER_CELL_WIDTH: f32 = CARD_WIDTH + CARD_SPACING;
+/// 可选卡片面板中每个格子的高度。
+const CHOOSER_CELL_HEIGHT: f32 = CARD_HEIGHT + CARD_SPACING;
+/// 选卡时草坪向右滚动的最大距离（像素）。
+pub const LAWN_SCROLL_DISTANCE: f32 = 250.0;
+/// 草坪滚动动画的持续时间（毫秒）。
+const LAWN_SCROLL_DURATION: u64 = 1000;
+
+/// 选卡界面的状态。
+pub struct SeedChooser {
+    /// 玩家可以选择的植物（已解锁的植物）。
+    pub available: Vec<PlantType>,
+    /// 玩家已经选中的植物，按选择顺序排列。
+    pub chosen: Vec<PlantType>,
+    /// 最多可以选择的卡片数量。
+    pub slots: usize,
+    /// 草坪滚动动画已经播放的时间（毫秒）。
+    scroll_timer: u64,
+}
+
+impl SeedChooser {
+    /// 创建一个新的选卡界面。
+    ///
+    /// # Arguments
+    ///
+    /// * `available` - 玩家可以选择的植物。
+    /// * `slots` - 卡槽数量，至少为1。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `SeedChooser` 实例，初始时没有选中任何卡片。
+    pub fn new(available: &[PlantType], slots: usize) -> Self {
+        SeedChooser {
+            available: available.to_vec(),
+            chosen: Vec::new(),
+            slots: slots.max(1),
+            scroll_timer: 0,
+        }
+    }
+
+    /// 更新草坪滚动动画。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    pub fn update(&mut self, dt: u64) {
+        self.scroll_timer = (self.scroll_timer + dt).min(LAWN_SCROLL_DURATION);
+    }
+
+    /// 获取当前草坪的滚动距离，使用缓出曲线使滚动更平滑。
+    pub fn scroll_offset(&self) -> f32 {
+        let progress = self.scroll_timer as f32 / LAWN_SCROLL_DURATION as f32;
+        let eased = 1.0 - (1.0 - progress) * (1.0 - progress);
+        eased * LAWN_SCROLL_DISTANCE
+    }
+
+    /// 判断卡槽是否已满。
+    pub fn is_full(&self) -> bool {
+        self.chosen.len() >= self.slots
+    }
+
+    /// 判断指定植物是否已被选中。
+    pub fn is_chosen(&self, plant_type: PlantType) -> bool {
+        self.chosen.contains(&plant_type)
+    }
+
+    /// 选择一张卡片放入卡槽。
+    ///
+    /// # Returns
+    ///
+    /// 如果卡片可选、尚未选中且卡槽未满，则选中并返回 `true`，否则返回 `false`。
+    pub fn pick(&mut self, plant_type: PlantType) -> bool {
+        if self.is_full() || self.is_chosen(plant_type) || !self.available.contains(&plant_type) {
+            return false;
+        }
+        self.chosen.push(plant_type);
+        true
+    }
+
+    /// 将卡槽中第 `slot` 张卡片放回可选面板，后面的卡片依次前移。
+    ///
+    /// # Returns
+    ///
+    /// 返回被移除的植物类型，如果该卡槽为空则返回 `None`。
+    pub fn remove(&mut self, slot: usize) -> Option<PlantType> {
+        if slot < self.chosen.len() {
+            Some(self.chosen.remove(slot))
+        } else {
+            None
+        }
+    }
+
+    /// 判断是否可以开始关卡（至少选择了一张卡片）。
+    pub fn is_ready(&self) -> bool {
+        !self.chosen.is_empty()
+    }
+
+    /// 计算卡槽中第 `slot` 张卡片的矩形区域，与商店中卡片的位置一致。
+    pub fn slot_rect(slot: usize) -> Rect {
+        Rect::new(
+            SHOP_START_X + (CARD_WIDTH + CARD_SPACING) * slot as f32,
+            SHOP_START_Y,
+            CARD_WIDTH,
+            CARD_HEIGHT,
+        )
+    }
+
+    /// 计算可选面板中第 `index` 张卡片的矩形区域。
+    pub fn available_rect(index: usize) -> Rect {
+        let column = index % CHOOSER_COLUMNS;
+        let row = index / CHOOSER_COLUMNS;
+        Rect::new(
+            CHOOSER_START_X + CHOOSER_CELL_WIDTH * column as f32,
+            CHOOSER_START_Y + CHOOSER_CELL_HEIGHT * row as f32,
+            CARD_WIDTH,
+            CARD_HEIGHT,
+        )
+    }
+
+    /// 可选面板的矩形区域。
+    fn panel_rect(&self) -> Rect {
+        let rows = self.available.len().div_ceil(CHOOSER_COLUMNS);
+        Rect::new(
+            CHOOSER_START_X - 10.0,
+            CHOOSER_START_Y - 10.0,
+            CHOOSER_CELL_WIDTH * CHOOSER_COLUMNS as f32 + 10.0,
+            CHOOSER_CELL_HEIGHT * rows.max(1) as f32 + 80.0,
+        )
+    }
+
+    /// "开始"按钮的矩形区域，位于可选面板底部。
+    pub fn start_button_rect(&self) -> Rect {
+        let panel = self.panel_rect();
+        Rect::new(panel.x + panel.w / 2.0 - 70.0, panel.y + panel.h - 55.0, 140.0, 45.0)
+    }
+
+    /// 处理选卡界面中的鼠标点击。
+    ///
+    /// 点击卡槽中的卡片会将其放回；点击可选面板中的卡片会将其放入卡槽；
+    /// 点击"开始"按钮时，如果已经选择了卡片则表示选卡完成。
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - 鼠标点击的X坐标。
+    /// * `y` - 鼠标点击的Y坐标。
+    ///
+    /// # Returns
+    ///
+    /// 如果玩家确认开始关卡，返回 `true`，否则返回 `false`。
+    pub fn handle_click(&mut self, x: f32, y: f32) -> bool {
+        if let Some(slot) = (0..self.chosen.len()).find(|&slot| SeedChooser::slot_rect(slot).contains([x, y])) {
+            self.remove(slot);
+            return false;
+        }
+
+        if let Some(index) = (0..self.available.len()).find(|&index| SeedChooser::available_rect(index).contains([x, y])) {
+            let plant_type = self.available[index];
+            self.pick(plant_type);
+            return false;
+        }
+
+        self.start_button_rect().contains([x, y]) && self.is_ready()
+    }
+
+    /// 绘制一张植物卡片及其阳光花费。
+    fn draw_card(ctx: &mut Context, resources: &Resources, plant_type: PlantType, rect: Rect, dimmed: bool) -> GameResult {
+        let color = if dimmed { Color::new(0.4, 0.4, 0.4, 1.0) } else { Color::WHITE };
+
+        if let Some(card_image) = resources.sprite(plant_type.spec().card) {
+            graphics::draw(
+                ctx,
+                card_image,
+                DrawParam::default()
+                    .dest([rect.x, rect.y])
+                    .scale([0.9, 0.9])
+                    .color(color),
+            )?;
+        }
+
+        let cost_text = Text::new(
+            TextFragment::new(plant_type.cost().to_string())
+                .color(Color::BLACK)
+                .scale(15.0)
+        );
+        let text_pos = [
+            rect.x + CARD_WIDTH / 2.0 - cost_text.width(ctx) / 2.0,
+            rect.y + CARD_HEIGHT - 18.0,
+        ];
+        graphics::draw(ctx, &cost_text, DrawParam::default().dest(text_pos))
+    }
+
+    /// 绘制卡槽中的卡片、可选卡片面板以及"开始"按钮。
+    ///
+    /// 背景、商店面板和僵尸阵容由 `Renderer` 负责绘制。
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - ggez的上下文环境。
+    /// * `resources` - 游戏资源。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个 `GameResult`，表示绘制操作是否成功。
+    pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
+        // 卡槽中已选中的卡片
+        for (slot, &plant_type) in self.chosen.iter().enumerate() {
+            SeedChooser::draw_card(ctx, resources, plant_type, SeedChooser::slot_rect(slot), false)?;
+        }
+
+        // 可选卡片面板
+        let panel = Mesh::new_rectangle(
+            ctx,
+            DrawMode::fill(),
+            self.panel_rect(),
+            Color::new(0.35, 0.25, 0.15, 0.9),
+        )?;
+        graphics::draw(ctx, &panel, DrawParam::default())?;
+
+        for (index, &plant_type) in self.available.iter().enumerate() {
+            // 已选中的卡片在面板中显示为灰色
+            let dimmed = self.is_chosen(plant_type);
+            SeedChooser::draw_card(ctx, resources, plant_type, SeedChooser::available_rect(index), dimmed)?;
+        }
+
+        // "开始"按钮
+        let button = self.start_button_rect();
+        graphics::draw(
+            ctx,
+            resources.image("button"),
+            DrawParam::default()
+                .dest([button.x, button.y])
+                .scale([button.w / resources.image("button").width() as f32, button.h / resources.image("button").height() as f32])
+                .color(if self.is_ready() { Color::WHITE } else { Color::new(0.5, 0.5, 0.5, 1.0) }),
+        )?;
+
+        let label = Text::new(
+            TextFragment::new("Let's Rock!")
+                .color(Color::BLACK)
+                .scale(22.0)
+        );
+        let label_width = label.width(ctx);
+        let label_height = label.height(ctx);
+        graphics::draw(
+            ctx,
+            &label,
+            DrawParam::default().dest([
+                button.x + button.w / 2.0 - label_width / 2.0,
+                button.y + button.h / 2.0 - label_height / 2.0,
+            ]),
+        )
+    }
+}
@@ -5,8 +5,9 @@
 //! 并处理购买后的冷却逻辑。
 
 use ggez::{Context, GameResult};
-use ggez::graphics::{self, DrawParam, Color, Rect};
-use crate::plants::{PlantType, PlantFactory};
+use ggez::graphics::{self, DrawParam, Color, Rect, Text, TextFragment};
+use crate::plants::PlantType;
+use crate::core::registry::PlantSpec;
 use crate::core::resources::Resources;
 use std::time::{Duration, Instant};
 
@@ -21,25 +22,19 @@ pub const SHOP_START_Y: f32 = 8.0;
 /// 商店中相邻植物卡片之间的水平间距。
 pub const CARD_SPACING: f32 = 10.0;
 
-/// 不同植物类型在商店中的冷却时间（毫秒）。
-/// 顺序应与 `PlantType` 枚举的定义顺序一致。
-const COOLDOWN_TIMES: [u64; 3] = [
-    7500,  // 豌豆射手 (Peashooter)
-    5000,  // 向日葵 (Sunflower)
-    25000, // 坚果墙 (WallNut)
-];
-
 /// 代表商店中的一张植物卡片。
 ///
 /// 每张卡片关联一种植物类型，并管理其可用性（基于阳光和冷却时间）。
 pub struct PlantCard {
     /// 卡片对应的植物类型。
     pub plant_type: PlantType,
+    /// 创建卡片时从注册表读取的规格（阳光成本、贴图键等），调校文件重新加载后通过 `refresh_spec` 更新。
+    spec: PlantSpec,
     /// 卡片在屏幕上的位置 (x, y)。
     pub position: (f32, f32),
     /// 卡片当前是否可用（可购买）。
     pub available: bool,
-    /// 购买此植物后的冷却时间。
+    /// 购买此植物后的冷却时间，取自注册表中该植物的规格。
     pub cooldown: Duration,
     /// 上次购买此植物的时间点，用于计算冷却。
     pub last_used: Option<Instant>,
@@ -47,6 +42,13 @@ pub struct PlantCard {
     pub rect: Rect,
     /// 冷却动画显示的进度 (0.0 - 1.0)
     pub cooldown_display_progress: f32,
+    /// 阳光成本的文字，只在规格刷新时重新创建。
+    cost_text: Text,
+}
+
+/// 创建卡片上显示阳光成本的文字。
+fn cost_text(cost: i32) -> Text {
+    Text::new(TextFragment::new(cost.to_string()).color(Color::BLACK).scale(15.0))
 }
 
 impl PlantCard {
@@ -63,18 +65,35 @@ impl PlantCard {
     pub fn new(plant_type: PlantType, index: usize) -> Self {
         let x = SHOP_START_X + (CARD_WIDTH + CARD_SPACING) * index as f32;
         let y = SHOP_START_Y;
+        let spec = plant_type.spec();
         
         PlantCard {
             plant_type,
+            spec,
             position: (x, y),
             available: true,
-            cooldown: Duration::from_millis(COOLDOWN_TIMES[plant_type as usize]),
+            cooldown: Duration::from_millis(spec.recharge_ms),
             last_used: None,
             rect: Rect::new(x, y, CARD_WIDTH, CARD_HEIGHT),
             cooldown_display_progress: 1.0, // 初始是完全冷却好的状态
+            cost_text: cost_text(spec.cost),
         }
     }
 
+    /// 重新读取注册表中的规格，更新卡片的冷却时间和阳光成本文字。
+    ///
+    /// 规格在创建卡片时缓存，调校文件重新加载后需要调用此方法。
+    pub fn refresh_spec(&mut self) {
+        self.spec = self.plant_type.spec();
+        self.cooldown = Duration::from_millis(self.spec.recharge_ms);
+        self.cost_text = cost_text(self.spec.cost);
+    }
+
+    /// 卡片上显示的阳光成本。
+    pub fn cost(&self) -> i32 {
+        self.spec.cost
+    }
+
     /// 更新植物卡片的状态，主要是其可用性。
     ///
     /// 卡片的可用性取决于：
@@ -85,6 +104,8 @@ impl PlantCard {
     ///
     /// * `sun_count` - 玩家当前的阳光数量。
     pub fn update(&mut self, sun_count: i32) {
+        let cost = self.spec.cost;
+
         // 检查冷却时间
         if let Some(last_used) = self.last_used {
             let elapsed = last_used.elapsed();
@@ -93,11 +114,11 @@ impl PlantCard {
                 // 更新冷却显示进度
                 self.cooldown_display_progress = elapsed.as_millis() as f32 / self.cooldown.as_millis() as f32;
             } else {
-                self.available = sun_count >= self.plant_type.cost();
+                self.available = sun_count >= cost;
                 self.cooldown_display_progress = 1.0; // 完全冷却好了
             }
         } else {
-            self.available = sun_count >= self.plant_type.cost();
+            self.available = sun_count >= cost;
             self.cooldown_display_progress = 1.0; // 完全冷却好了
         }
     }
@@ -115,18 +136,16 @@ impl PlantCard {
     ///
     /// 如果绘制成功，返回 `GameResult<()>`。
     pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
-        // 创建一个临时的植物实例来获取卡片图像
-        let plant = PlantFactory::create_plant(self.plant_type);
-        let card_image = plant.get_card_image(resources);
-        
-        // 绘制卡片
-        graphics::draw(
-            ctx,
-            card_image,
-            DrawParam::default()
-                .dest([self.position.0, self.position.1])
-                .scale([0.9, 0.9])
-        )?;
+        // 按注册表中的卡片贴图键绘制卡片
+        if let Some(card_image) = resources.sprite(self.spec.card) {
+            graphics::draw(
+                ctx,
+                card_image,
+                DrawParam::default()
+                    .dest([self.position.0, self.position.1])
+                    .scale([0.9, 0.9])
+            )?;
+        }
         
         // 如果卡片在冷却中，绘制半透明遮罩
         if self.cooldown_display_progress < 1.0 {
@@ -142,19 +161,13 @@ impl PlantCard {
             graphics::draw(ctx, &rect, DrawParam::default())?;
         }
         
-        // 绘制阳光消耗
-        let cost_text = graphics::Text::new(
-            graphics::TextFragment::new(self.plant_type.cost().to_string())
-                .color(Color::BLACK)
-                .scale(15.0)
-        );
-        
+        // 绘制阳光消耗，文字在成本变化前一直复用
         let text_pos = [
-            self.position.0 + CARD_WIDTH/2.0 - cost_text.width(ctx) as f32 / 2.0,
+            self.position.0 + CARD_WIDTH/2.0 - self.cost_text.width(ctx) as f32 / 2.0,
             self.position.1 + CARD_HEIGHT - 18.0
         ];
         
-        graphics::draw(ctx, &cost_text, DrawParam::default().dest(text_pos))?;
+        graphics::draw(ctx, &self.cost_text, DrawParam::default().dest(text_pos))?;
         
         Ok(())
     }
@@ -199,12 +212,26 @@ impl Shop {
     ///
     /// 返回一个新的 `Shop` 实例。
     pub fn new() -> Self {
-        let mut cards = Vec::new();
-        
-        // 添加植物卡片
-        cards.push(PlantCard::new(PlantType::Sunflower, 0));
-        cards.push(PlantCard::new(PlantType::Peashooter, 1));
-        cards.push(PlantCard::new(PlantType::WallNut, 2));
+        Shop::with_plants(&[PlantType::Sunflower, PlantType::Peashooter, PlantType::WallNut])
+    }
+
+    /// 使用指定的植物列表创建 `Shop`。
+    ///
+    /// 卡片按照列表顺序从左到右排列，例如冒险模式中玩家已解锁的植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `plants` - 商店中要出售的植物类型。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `Shop` 实例。
+    pub fn with_plants(plants: &[PlantType]) -> Self {
+        let cards = plants
+            .iter()
+            .enumerate()
+            .map(|(index, &plant_type)| PlantCard::new(plant_type, index))
+            .collect();
         
         Shop {
             cards,
@@ -245,28 +272,35 @@ impl Shop {
         }
         
         // 如果有选择的植物，绘制跟随鼠标的植物预览
-        if let Some(plant_type) = self.selected_plant {
+        let selected_card = self.selected_plant.and_then(|plant_type| self.cards.iter().find(|card| card.plant_type == plant_type));
+        if let Some(card) = selected_card {
             let mouse_pos = ggez::input::mouse::position(ctx);
             let x = mouse_pos.x;
             let y = mouse_pos.y;
             
-            // 创建一个临时的植物实例来获取预览图像
-            let plant = PlantFactory::create_plant(plant_type);
-            let image = plant.get_current_frame_image(resources, 0); // 使用第一帧作为预览
-            
-            graphics::draw(
-                ctx,
-                image,
-                DrawParam::default()
-                    .dest([x - 30.0, y - 30.0])
-                    .scale([0.6, 0.6])
-                    .color(Color::new(1.0, 1.0, 1.0, 0.7)),
-            )?;
+            // 使用注册表中植物贴图的第一帧作为预览
+            if let Some(image) = resources.sprite(card.spec.image) {
+                graphics::draw(
+                    ctx,
+                    image,
+                    DrawParam::default()
+                        .dest([x - 30.0, y - 30.0])
+                        .scale([0.6, 0.6])
+                        .color(Color::new(1.0, 1.0, 1.0, 0.7)),
+                )?;
+            }
         }
         
         Ok(())
     }
     
+    /// 调校文件重新加载后，刷新所有卡片缓存的规格。
+    pub fn refresh_specs(&mut self) {
+        for card in &mut self.cards {
+            card.refresh_spec();
+        }
+    }
+
     /// 触发指定植物类型卡片的冷却计时。
     ///
     /// 当植物成功放置到网格上后，应调用此方法以启动相应卡片的冷却计时。
@@ -317,7 +351,7 @@ impl Shop {
         for card in &mut self.cards {
             if card.contains_point(x, y) && card.available {
                 let plant_type = card.plant_type;
-                if sun_count >= plant_type.cost() {
+                if sun_count >= card.cost() {
                     self.selected_plant = Some(plant_type);
                     return Some(plant_type);
                 }
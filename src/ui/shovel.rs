@@ -91,20 +91,21 @@ impl Shovel {
     /// 如果成功铲除了植物，返回 `true`；否则返回 `false`。
     pub fn dig(&self, x: f32, y: f32, grid: &mut Grid, plants: &mut Vec<Plant>) -> bool {
         if let Some((grid_x, grid_y)) = grid.get_grid_position(x, y) {
-            if grid.is_occupied(grid_x, grid_y) {
-                // 找到并移除对应位置的植物
-                let plant_index = plants.iter().position(|plant| 
-                    plant.grid_x == grid_x && plant.grid_y == grid_y
-                );
-                
-                if let Some(index) = plant_index {
-                    plants.remove(index);
-                    
-                    // 将该位置标记为未占用
-                    grid.unoccupy(grid_x, grid_y);
-                    
-                    return true;
-                }
+            // 铲除该单元格中最外面一层的植物（南瓜头 > 主层植物 > 睡莲）
+            let plant_index = plants
+                .iter()
+                .enumerate()
+                .filter(|(_, plant)| plant.grid_x == grid_x && plant.grid_y == grid_y)
+                .max_by_key(|(_, plant)| plant.get_plant_type().layer())
+                .map(|(index, _)| index);
+
+            if let Some(index) = plant_index {
+                let plant = plants.remove(index);
+
+                // 清空该植物在单元格中占据的层
+                grid.remove(grid_x, grid_y, plant.get_plant_type());
+
+                return true;
             }
         }
         false
@@ -126,16 +127,16 @@ impl Shovel {
         // 绘制铲子框
         let bank_param = DrawParam::default()
             .dest([self.bank_rect.x, self.bank_rect.y])
-            .scale([self.bank_rect.w / resources.shovel_bank_image.width() as f32,
-                    self.bank_rect.h / resources.shovel_bank_image.height() as f32]);
-        graphics::draw(ctx, &resources.shovel_bank_image, bank_param)?;
+            .scale([self.bank_rect.w / resources.image("shovel_bank").width() as f32,
+                    self.bank_rect.h / resources.image("shovel_bank").height() as f32]);
+        graphics::draw(ctx, resources.image("shovel_bank"), bank_param)?;
         
         // 绘制铲子
         let shovel_param = DrawParam::default()
             .dest([self.rect.x, self.rect.y])
-            .scale([self.rect.w / resources.shovel_image.width() as f32,
-                    self.rect.h / resources.shovel_image.height() as f32]);
-        graphics::draw(ctx, &resources.shovel_image, shovel_param)?;
+            .scale([self.rect.w / resources.image("shovel").width() as f32,
+                    self.rect.h / resources.image("shovel").height() as f32]);
+        graphics::draw(ctx, resources.image("shovel"), shovel_param)?;
         
         Ok(())
     }
@@ -0,0 +1,54 @@
+//! # 投篮车僵尸模块 (`catapult_zombie`)
+//!
+//! 实现了游戏中会远程攻击植物的僵尸——投篮车僵尸。
+//! 投篮车僵尸在前进的同时，会向所在行最左侧的植物投出沿抛物线飞行的篮球，
+//! 篮球落在保护伞类植物周围一格内时会被挡住。篮球用完后，投篮车僵尸只会像普通僵尸一样啃食植物。
+
+use crate::entities::projectile::ProjectileKind;
+use crate::zombies::zombie_trait::ZombieTrait;
+
+/// 两次投篮之间的间隔（毫秒）。
+pub const LOB_INTERVAL: u64 = 3000;
+/// 投篮车上携带的篮球数量。
+pub const BASKETBALLS: u32 = 20;
+
+/// 投篮车僵尸的结构体实现。
+///
+/// `CatapultZombie` 只跟踪剩余的篮球数量，投掷的节奏和目标由 `Zombie::throw_at_plants` 决定。
+pub struct CatapultZombie {
+    /// 剩余的篮球数量。
+    basketballs: u32,
+}
+
+impl CatapultZombie {
+    /// 创建一个新的 `CatapultZombie` 实例，篮球数量为 `BASKETBALLS`。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个新的 `CatapultZombie`。
+    pub fn new() -> Self {
+        CatapultZombie { basketballs: BASKETBALLS }
+    }
+}
+
+impl Default for CatapultZombie {
+    fn default() -> Self {
+        CatapultZombie::new()
+    }
+}
+
+impl ZombieTrait for CatapultZombie {
+    /// 投篮车僵尸每隔 `LOB_INTERVAL` 投出一个篮球。
+    fn lob_interval(&self) -> Option<u64> {
+        Some(LOB_INTERVAL)
+    }
+
+    /// 还有篮球时消耗一个篮球，否则返回 `None`。
+    fn next_lob(&mut self) -> Option<ProjectileKind> {
+        if self.basketballs == 0 {
+            return None;
+        }
+        self.basketballs -= 1;
+        Some(ProjectileKind::Basketball)
+    }
+}
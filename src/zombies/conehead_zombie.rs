@@ -4,44 +4,32 @@
 //! 路障僵尸比普通僵尸拥有更高的初始生命值，因为它头戴路障作为额外护甲。
 //! 当其生命值降低到一定程度（相当于失去路障后），它会转变为一个普通僵尸。
 
+use crate::core::registry::ZombieSpec;
 use crate::zombies::zombie_trait::ZombieTrait;
-use ggez::graphics::Image; // 移除了未使用的 DrawParam
-use crate::core::resources::Resources;
-use crate::zombies::normal_zombie::NormalZombie;
-// use crate::zombies::zombie_factory::ZombieType; // ZombieType 未在此文件中直接使用
+use crate::zombies::zombie_factory::ZombieType;
 
-/// 路障僵尸的初始生命值。
-const INITIAL_HEALTH: i32 = 370; // 路障僵尸有更多的生命值
 /// 普通僵尸的生命值阈值。
 /// 当路障僵尸的生命值低于此值时，其行为和外观将转变为普通僵尸。
 const NORMAL_ZOMBIE_HEALTH: i32 = 200; 
-/// 路障僵尸的移动速度（像素/毫秒）。
-const SPEED: f32 = 0.017; 
-/// 路障僵尸的攻击伤害值。
-const ATTACK_DAMAGE: i32 = 100; 
-/// 路障僵尸的攻击间隔（毫秒）。
-const ATTACK_INTERVAL: u64 = 1000; // 攻击间隔为1秒
 
 /// 路障僵尸的结构体实现。
 ///
-/// `ConeheadZombie` 内部包含一个 `NormalZombie` 实例，用于在路障被破坏后
-/// 模拟其行为转变为普通僵尸。它还跟踪自身是否已转变以及当前的生命值，
-/// 以便正确处理伤害和动画。
+/// `ConeheadZombie` 跟踪自身是否已经失去路障以及当前的生命值，
+/// 路障被破坏后改用普通僵尸的贴图，以便正确处理伤害和动画。
 pub struct ConeheadZombie {
     /// 标记路障僵尸是否已经失去了路障并转变为普通僵尸形态。
     transformed_to_normal: bool,
-    /// 一个内部的 `NormalZombie` 实例。
-    /// 当 `transformed_to_normal` 为 `true` 时，路障僵尸的许多行为会委托给此实例。
-    normal_zombie: NormalZombie,
     /// 路障僵尸当前的生命值。
     /// 用于判断何时应该失去路障并转变形态。
     current_health: i32,
+    /// 普通僵尸的规格，失去路障后使用其中的贴图键。创建时缓存，绘制时不再访问注册表。
+    normal: ZombieSpec,
 }
 
 impl ConeheadZombie {
     /// 创建一个新的 `ConeheadZombie` 实例。
     ///
-    /// 初始化时，僵尸拥有路障，生命值为 `INITIAL_HEALTH`。
+    /// 初始化时，僵尸拥有路障，生命值为注册表中路障僵尸的生命值。
     ///
     /// # Returns
     ///
@@ -49,117 +37,40 @@ impl ConeheadZombie {
     pub fn new() -> Self {
         ConeheadZombie {
             transformed_to_normal: false,
-            normal_zombie: NormalZombie::new(),
-            current_health: INITIAL_HEALTH,
+            current_health: ZombieType::Conehead.spec().health,
+            normal: ZombieType::Normal.spec(),
         }
     }
 }
 
 impl ZombieTrait for ConeheadZombie {
-    /// 获取路障僵尸的初始生命值。
-    fn get_initial_health(&self) -> i32 {
-        INITIAL_HEALTH
-    }
-    
-    /// 获取路障僵尸的移动速度。
-    fn get_speed(&self) -> f32 {
-        SPEED
-    }
-    
-    /// 获取路障僵尸的攻击伤害。
-    fn get_attack_damage(&self) -> i32 {
-        ATTACK_DAMAGE
-    }
-    
-    /// 获取路障僵尸的攻击间隔。
-    fn get_attack_interval(&self) -> u64 {
-        ATTACK_INTERVAL
-    }
-    
-    /// 获取路障僵尸行走动画的总帧数。
-    ///
-    /// 如果已转变为普通僵尸，则返回普通僵尸的行走帧数。
-    fn get_walk_frame_count(&self) -> usize {
+    /// 失去路障后改用普通僵尸的行走贴图，否则使用注册表中路障僵尸的行走贴图。
+    fn walk_sprite(&self) -> Option<&'static str> {
         if self.transformed_to_normal {
-            // 变成普通僵尸后使用普通僵尸的行走帧数
-            self.normal_zombie.get_walk_frame_count()
+            Some(self.normal.walk)
         } else {
-            // 路障僵尸行走动画有21帧
-            21
-        }
-    }
-    
-    /// 获取路障僵尸攻击动画的总帧数。
-    ///
-    /// 如果已转变为普通僵尸，则返回普通僵尸的攻击帧数。
-    fn get_attack_frame_count(&self) -> usize {
-        if self.transformed_to_normal {
-            // 变成普通僵尸后使用普通僵尸的攻击帧数
-            self.normal_zombie.get_attack_frame_count()
-        } else {
-            // 路障僵尸攻击动画有11帧
-            11
+            None
         }
     }
-    
-    /// 获取路障僵尸当前行走动画帧对应的图像。
-    ///
-    /// 如果已转变为普通僵尸，则返回普通僵尸的行走图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `frame` - 当前动画帧的索引。
-    ///
-    /// # Returns
-    ///
-    /// 返回对应帧的图像引用。
-    fn get_walk_image<'a>(&self, resources: &'a Resources, frame: usize) -> &'a Image {
+
+    /// 失去路障后改用普通僵尸的啃食贴图，否则使用注册表中路障僵尸的啃食贴图。
+    fn attack_sprite(&self) -> Option<&'static str> {
         if self.transformed_to_normal {
-            // 变成普通僵尸后使用普通僵尸的行走图片
-            self.normal_zombie.get_walk_image(resources, frame)
+            Some(self.normal.attack)
         } else {
-            // 使用路障僵尸特定的行走图片
-            let cone_walk_images = &resources.cone_zombie_walk_images;
-            let frame_count = cone_walk_images.len();
-            if frame_count > 0 {
-                &cone_walk_images[frame % frame_count]
-            } else {
-                // 如果没有特定图片，回退到普通僵尸图片
-                self.normal_zombie.get_walk_image(resources, frame)
-            }
+            None
         }
     }
-    
-    /// 获取路障僵尸当前攻击动画帧对应的图像。
-    ///
-    /// 如果已转变为普通僵尸，则返回普通僵尸的攻击图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `frame` - 当前动画帧的索引。
-    ///
-    /// # Returns
-    ///
-    /// 返回对应帧的图像引用。
-    fn get_attack_image<'a>(&self, resources: &'a Resources, frame: usize) -> &'a Image {
+
+    /// 失去路障后改用普通僵尸的游泳贴图，否则使用注册表中路障僵尸的游泳贴图。
+    fn swim_sprite(&self) -> Option<&'static str> {
         if self.transformed_to_normal {
-            // 变成普通僵尸后使用普通僵尸的攻击图片
-            self.normal_zombie.get_attack_image(resources, frame)
+            Some(self.normal.swim)
         } else {
-            // 使用路障僵尸特定的攻击图片
-            let cone_attack_images = &resources.cone_zombie_attack_images;
-            let frame_count = cone_attack_images.len();
-            if frame_count > 0 {
-                &cone_attack_images[frame % frame_count]
-            } else {
-                // 如果没有特定图片，回退到普通僵尸图片
-                self.normal_zombie.get_attack_image(resources, frame)
-            }
+            None
         }
     }
-    
+
     /// 指示路障僵尸是否具有特殊能力（即路障未掉落）。
     ///
     /// # Returns
@@ -202,7 +113,27 @@ impl ZombieTrait for ConeheadZombie {
         false // 没有触发特殊处理
     }
     
-    /// 实现transform_health方法
+    /// 路障还没有掉落时，路障僵尸有护甲。
+    fn has_armor(&self) -> bool {
+        !self.transformed_to_normal
+    }
+
+    /// 立即打掉路障，路障僵尸转变为普通僵尸形态。
+    ///
+    /// # Returns
+    ///
+    /// 如果路障还在并被打掉，返回 `true`。
+    fn knock_off_armor(&mut self) -> bool {
+        if self.transformed_to_normal {
+            return false;
+        }
+
+        self.current_health = self.current_health.min(NORMAL_ZOMBIE_HEALTH);
+        self.transformed_to_normal = true;
+        true
+    }
+
+        /// 实现transform_health方法
     fn transform_health(&self) -> Option<i32> {
         if self.transformed_to_normal {
             // 如果已经转变为普通僵尸，返回普通僵尸的健康值
@@ -3,36 +3,96 @@
 //! 负责定义和管理游戏中所有类型的僵尸。
 //!
 //! 该模块包含：
-//! - 各种具体僵尸类型的实现（例如 `normal_zombie`、`conehead_zombie`）。
+//! - 各种具体僵尸类型的实现（例如 `normal_zombie`、`conehead_zombie`、`catapult_zombie`）。
 //! - 一个通用的 `Zombie` 结构体，用于在游戏中表示一个僵尸实例，并处理其通用逻辑（如移动、动画、受伤、攻击）。
 //! - `ZombieTrait`，一个定义所有僵尸类型必须实现的共享行为的特征。
 //! - `ZombieFactory`，用于根据 `ZombieType` 枚举创建具体僵尸实例的工厂。
+//! - `status`，记录僵尸身上的临时状态效果（例如定身、减速）。
 //!
 //! 僵尸从屏幕右侧生成，并向左移动，试图攻击玩家的植物。
 //! 它们具有生命值、移动速度、攻击力和攻击间隔等属性。
 //! 僵尸还具有行走、攻击和死亡等动画。
 
-use ggez::{Context, GameResult};
-use ggez::graphics::{self, DrawParam, Rect}; // DrawParam 在 Zombie::draw 中使用
+use ggez::graphics::Rect;
+use crate::core::animation::{self, Animator};
+use crate::core::registry::ZombieSpec;
+use crate::core::render_cache::SpriteBatcher;
 use crate::core::resources::Resources;
-use crate::ui::grid::{GRID_START_Y, GRID_CELL_HEIGHT, GRID_CELL_WIDTH, GRID_START_X};
+use crate::ui::lawn::LawnGeometry;
+use crate::entities::projectile::{HitEffect, Projectile};
+use crate::plants::Plant;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 // 声明子模块
 pub mod normal_zombie;
 pub mod zombie_trait;
 pub mod zombie_factory;
 pub mod conehead_zombie;
+pub mod catapult_zombie;
+pub mod status;
 // 未来可以添加
 // pub mod buckethead_zombie;
 
 // 从工厂模块中重新导出僵尸类型枚举和工厂本身
 pub use zombie_factory::{ZombieType, ZombieFactory};
+use status::StatusEffects;
+
+/// 没有游泳素材时，游泳的僵尸露出水面的比例（从行走图像顶部算起）。
+const SWIMMING_VISIBLE_FRACTION: f32 = 0.6;
+/// 被减速的僵尸的移动和啃食速度倍率。
+const SLOW_FACTOR: f32 = 0.5;
+/// 死亡动画的贴图名称。清单中应为 `hold_last` 片段，播放完成后僵尸被移除。
+const DIE_SPRITE: &str = "zombie_die";
+/// 头部掉落动画的贴图名称。
+const HEAD_SPRITE: &str = "zombie_head";
+/// 死亡动画中让头部开始掉落的帧事件。
+const HEAD_DROP_EVENT: &str = "head_drop";
+
+/// 列出一组僵尸类型在关卡中需要的全部贴图，供资源加载器在进入关卡时加载。
+///
+/// 除了每种僵尸的行走、啃食和游泳动画，还包括所有僵尸共用的死亡和头部掉落动画，
+/// 以及失去护甲后使用的普通僵尸动画。
+///
+/// # Arguments
+///
+/// * `zombie_types` - 关卡中会出现的僵尸类型。
+///
+/// # Returns
+///
+/// 返回不重复的贴图名称列表。
+pub fn sprite_keys(zombie_types: &[ZombieType]) -> Vec<&'static str> {
+    let normal = ZombieType::Normal.spec();
+    let mut keys = vec![DIE_SPRITE, HEAD_SPRITE, normal.walk, normal.attack, normal.swim];
+    for zombie_type in zombie_types {
+        let spec = zombie_type.spec();
+        for key in [spec.walk, spec.attack, spec.swim] {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    keys
+}
+
+/// 下一个生成的僵尸的唯一标识。
+static NEXT_ZOMBIE_ID: AtomicU64 = AtomicU64::new(0);
+
+/// 攻击命中僵尸的方式，决定僵尸的正面护盾能否挡住这次攻击。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitKind {
+    /// 沿着行水平飞行的攻击（例如豌豆），会被正面护盾挡住。
+    Straight,
+    /// 从上方落下的抛物线攻击（例如卷心菜），会越过正面护盾。
+    Lobbed,
+}
 
 /// 代表一个游戏中的僵尸实例。
 ///
 /// `Zombie` 结构体封装了一个具体僵尸实现（通过 `Box<dyn ZombieTrait>`），
 /// 并管理其在游戏世界中的状态，如位置、生命值、动画、攻击行为等。
 pub struct Zombie {
+    /// 僵尸的唯一标识，用于区分同一个投射物已经命中过的僵尸。
+    id: u64,
     /// 僵尸的具体类型（例如，普通僵尸、路障僵尸）。
     zombie_type: ZombieType,
     /// 僵尸所在的行（网格的y索引）。
@@ -41,12 +101,10 @@ pub struct Zombie {
     pub x: f32,
     /// 僵尸当前的生命值。
     health: i32,
-    /// 僵尸的移动速度（像素/毫秒）。
-    speed: f32,
-    /// 当前动画帧的索引。
-    animation_frame: usize,
-    /// 动画计时器，用于控制动画帧的切换速率。
-    animation_timer: u64,
+    /// 创建僵尸时从注册表读取的规格（移动速度、攻击力、贴图键等），调校文件重新加载后通过 `refresh_spec` 更新。
+    spec: ZombieSpec,
+    /// 当前动画（行走、啃食或死亡）的播放器，按僵尸的状态切换片段。
+    animator: Animator,
     /// 标记僵尸当前是否正在攻击。
     attacking: bool, 
     /// 标记僵尸是否正在播放死亡动画。
@@ -54,26 +112,27 @@ pub struct Zombie {
     /// 标记僵尸的死亡动画是否已播放完成。
     pub death_animation_complete: bool, 
     
-    /// 僵尸的攻击伤害值。
-    attack_damage: i32,     
-    /// 僵尸的攻击间隔（毫秒）。
-    attack_interval: u64,   
     /// 攻击计时器，用于控制攻击频率。
     attack_timer: u64,      
     /// 如果正在攻击，则为目标植物在植物列表中的索引。
     attack_target: Option<usize>, 
     
-    /// 标记僵尸的头部是否正在掉落（作为死亡动画的一部分）。
-    head_falling: bool, 
-    /// 头部掉落动画的当前帧索引。
-    head_animation_frame: usize, 
-    /// 头部掉落动画的计时器。
-    head_animation_timer: u64, 
+    /// 头部掉落动画的播放器，死亡动画触发 `head_drop` 事件后才存在。
+    head_animator: Option<Animator>,
     /// 掉落头部的X坐标。
     head_x: f32, 
     /// 掉落头部的Y坐标。
     head_y: f32, 
     
+    /// 僵尸所在草坪的几何信息，用于计算屏幕坐标。
+    geometry: LawnGeometry,
+    /// 僵尸是否在水面行中游泳。游泳的僵尸使用游泳贴图，没有游泳素材时只绘制行走动画的上半身。
+    swimming: bool,
+    /// 作用在僵尸身上的状态效果（例如定身、减速）。
+    status: StatusEffects,
+    /// 投掷计时器，用于控制会投掷的僵尸（例如投篮车僵尸）的投掷频率。
+    lob_timer: u64,
+    
     /// 对具体僵尸行为实现的动态分发。
     /// 这是一个实现了 `ZombieTrait` 的对象，包含了特定僵尸类型的逻辑。
     zombie_impl: Box<dyn zombie_trait::ZombieTrait>,
@@ -90,42 +149,55 @@ impl Zombie {
     /// # Returns
     ///
     /// 返回一个初始化后的 `Zombie` 实例。
-    /// 僵尸会从屏幕右侧外部开始移动。
+    /// 僵尸会从标准草坪的右侧外部开始移动。
     pub fn new(zombie_type: ZombieType, row: usize) -> Self {
+        Zombie::on_lawn(zombie_type, row, LawnGeometry::STANDARD)
+    }
+
+    /// 在指定布局的草坪上创建一个新的 `Zombie` 实例。
+    ///
+    /// # Arguments
+    ///
+    /// * `zombie_type` - 要创建的僵尸的类型 (`ZombieType`)。
+    /// * `row` - 僵尸生成的行号（网格y索引）。
+    /// * `geometry` - 草坪的几何信息。
+    ///
+    /// # Returns
+    ///
+    /// 返回一个初始化后的 `Zombie` 实例，从草坪右边缘附近开始移动。
+    pub fn on_lawn(zombie_type: ZombieType, row: usize, geometry: LawnGeometry) -> Self {
         // 使用工厂创建具体僵尸实现
         let zombie_impl = ZombieFactory::create_zombie(zombie_type);
         
-        // 获取僵尸基本属性
-        let health = zombie_impl.get_initial_health();
-        let speed = zombie_impl.get_speed();
-        let attack_damage = zombie_impl.get_attack_damage();
-        let attack_interval = zombie_impl.get_attack_interval();
+        // 从注册表获取僵尸基本属性
+        let spec = zombie_type.spec();
 
         Zombie {
+            id: NEXT_ZOMBIE_ID.fetch_add(1, Ordering::Relaxed),
             zombie_type,
             row,
-            x: 950.0, // 从屏幕右侧开始，确保完全在屏幕外生成
-            health,
-            speed,
-            animation_frame: 0,
-            animation_timer: 0,
+            x: geometry.zombie_spawn_x(), // 从草坪右侧开始
+            health: spec.health,
+            spec,
+            animator: Animator::new(zombie_impl.walk_sprite().unwrap_or(spec.walk)),
             attacking: false,
             is_dying: false,
             death_animation_complete: false,
             
             // 初始化攻击相关字段
-            attack_damage,
-            attack_interval,
             attack_timer: 0,
             attack_target: None,
             
             // 初始化头部动画相关字段
-            head_falling: false,
-            head_animation_frame: 0,
-            head_animation_timer: 0,
+            head_animator: None,
             head_x: 0.0,
             head_y: 0.0,
             
+            geometry,
+            swimming: false,
+            status: StatusEffects::new(),
+            lob_timer: 0,
+            
             // 具体僵尸实现
             zombie_impl,
         }
@@ -143,116 +215,112 @@ impl Zombie {
     pub fn update(&mut self, dt: u64) {
         // 如果僵尸正在死亡，处理死亡动画
         if self.is_dying {
-            self.animation_timer += dt;
-            if self.animation_timer > 200 { // 每200ms更新一帧死亡动画
-                // 死亡动画总共有10帧
-                if self.animation_frame < 9 { // 0-9共10帧
-                    self.animation_frame += 1;
-                    
-                    // 在死亡动画第2帧时触发头部掉落
-                    if self.animation_frame == 2 && !self.head_falling {
-                        self.head_falling = true;
-                        // 设置头部初始位置，相对于僵尸的位置
-                        self.head_x = self.x + 40.0; // TODO：根据僵尸图像调整头部位置的偏移量
-                        let y = GRID_START_Y + (self.row as f32) * GRID_CELL_HEIGHT - GRID_CELL_HEIGHT/4.0;
-                        self.head_y = y + 20.0; //TODO： 根据僵尸图像调整头部位置的偏移量
-                    }
-                } else {
-                    // 动画播放完成
-                    self.death_animation_complete = true;
-                }
-                self.animation_timer = 0;
-            }
-            
             // 处理头部掉落动画
-            if self.head_falling {
-                self.head_animation_timer += dt;
-                if self.head_animation_timer > 150 { // 头部动画比主体动画稍快
-                    // 头部动画总共有12帧
-                    if self.head_animation_frame < 11 { // 0-11共12帧
-                        self.head_animation_frame += 1;
-                    }
-                    self.head_animation_timer = 0;
-                }
+            if let Some(head_animator) = &mut self.head_animator {
+                head_animator.update(dt);
             }
-            
+
+            // 死亡动画播放到 `head_drop` 事件所在的帧时头部开始掉落
+            if self.animator.update(dt).iter().any(|event| event == HEAD_DROP_EVENT) {
+                self.head_animator = Some(Animator::new(HEAD_SPRITE));
+                // 设置头部初始位置，相对于僵尸的位置
+                self.head_x = self.x + 40.0; // TODO：根据僵尸图像调整头部位置的偏移量
+                let y = self.screen_y();
+                self.head_y = y + 20.0; //TODO： 根据僵尸图像调整头部位置的偏移量
+            }
+
+            // 死亡动画播放完成
+            self.death_animation_complete = self.animator.is_finished();
+
             return; // 正在死亡时不执行其他更新逻辑
         }
 
-        // 行走动画更新
-        self.animation_timer += dt;
-        if self.animation_timer > 200 {
-            let frame_count = if self.attacking {
-                self.zombie_impl.get_attack_frame_count()
-            } else {
-                self.zombie_impl.get_walk_frame_count()
-            };
-            
-            self.animation_frame = (self.animation_frame + 1) % frame_count;
-            self.animation_timer = 0;
+        // 被定身时既不播放动画也不移动
+        self.status.update(dt);
+        if self.status.is_stunned() {
+            return;
         }
 
+        // 行走和啃食动画更新（外观可能已经变化，例如失去路障），被减速时动画也会变慢
+        self.animator.play(self.current_sprite());
+        self.animator.update(self.scaled_dt(dt));
+
         // 移动逻辑
         if !self.attacking {
-            self.x -= self.speed * dt as f32;
+            self.x -= self.current_speed() * dt as f32;
         }
 
         // 调用特定僵尸的更新逻辑
         self.zombie_impl.update_special(dt);
     }
 
+    /// 重新读取注册表中的规格。
+    ///
+    /// 规格在创建僵尸时缓存，更新和绘制时不再访问注册表，因此调校文件重新加载后需要调用此方法。
+    /// 新的移动速度和攻击力立即生效，当前生命值保持不变。
+    pub fn refresh_spec(&mut self) {
+        self.spec = self.zombie_type.spec();
+    }
+
+    /// 当前状态对应的动画片段：死亡、啃食或行走。具体僵尸可以覆盖注册表中的行走和啃食贴图。
+    fn current_sprite(&self) -> &'static str {
+        if self.is_dying {
+            DIE_SPRITE
+        } else if self.attacking {
+            self.zombie_impl.attack_sprite().unwrap_or(self.spec.attack)
+        } else {
+            self.zombie_impl.walk_sprite().unwrap_or(self.spec.walk)
+        }
+    }
+
+    /// 当前的游泳贴图。具体僵尸可以覆盖注册表中的游泳贴图（例如失去路障后）。
+    fn swim_sprite(&self) -> &'static str {
+        self.zombie_impl.swim_sprite().unwrap_or(self.spec.swim)
+    }
+
+    /// 僵尸图像左上角的Y坐标，略高于所在行的上边缘。
+    fn screen_y(&self) -> f32 {
+        self.geometry.row_y(self.row) - self.geometry.cell_height / 4.0
+    }
+
     /// 在屏幕上绘制僵尸。
     ///
-    /// 根据僵尸的当前状态（行走、攻击、死亡）选择并绘制相应的动画帧。
-    /// 如果僵尸正在死亡且头部掉落动画已触发，则还会绘制掉落的头部。
+    /// 把僵尸当前状态（行走、攻击、死亡）对应的动画帧加入精灵批处理器。
+    /// 如果僵尸正在死亡且头部掉落动画已触发，则还会加入掉落的头部。
     ///
     /// # Arguments
     ///
-    /// * `ctx` - ggez的 `Context` 引用。
+    /// * `batcher` - 精灵批处理器，由渲染器在图层结束时统一绘制。
     /// * `resources` - 游戏资源 (`Resources`) 的引用，用于获取图像。
-    ///
-    /// # Returns
-    ///
-    /// 如果绘制成功，返回 `GameResult<()>`。
-    pub fn draw(&self, ctx: &mut Context, resources: &Resources) -> GameResult {
+    pub fn draw(&self, batcher: &mut SpriteBatcher, resources: &Resources) {
         // 计算僵尸在屏幕上的 Y 坐标
-        let y = GRID_START_Y + (self.row as f32) * GRID_CELL_HEIGHT - GRID_CELL_HEIGHT/4.0;
-
-        // 根据僵尸状态选择图像
-        let image = if self.is_dying {
-            // 使用死亡动画
-            self.zombie_impl.get_die_image(resources, self.animation_frame)
-        } else if self.attacking {
-            // 使用攻击动画
-            self.zombie_impl.get_attack_image(resources, self.animation_frame)
-        } else {
-            // 使用行走动画
-            self.zombie_impl.get_walk_image(resources, self.animation_frame)
-        };
+        let y = self.screen_y();
 
-        // 获取僵尸特定的绘制参数
-        let mut draw_params = self.zombie_impl.get_draw_params();
-        draw_params = draw_params.dest([self.x, y]);
+        // 动画播放器当前的片段即要绘制的贴图，按资源清单中贴图的原点和缩放绘制
+        let mut draw_params = resources.draw_param(self.animator.clip_name()).dest([self.x, y]);
 
-        // 绘制僵尸主体
-        graphics::draw(ctx, image, draw_params)?;
-        
-        // 如果头部正在掉落，绘制头部动画
-        if self.head_falling && !self.death_animation_complete {
-            let frame_count = resources.zombie_head_images.len();
-            if frame_count > 0 && self.head_animation_frame < frame_count {
-                let head_image = &resources.zombie_head_images[self.head_animation_frame];
-                graphics::draw(
-                    ctx,
-                    head_image,
-                    DrawParam::default()
-                        .dest([self.head_x, self.head_y])
-                        .scale([0.7, 0.7]), // 头部图像可以稍微小一点
-                )?;
+        if self.swimming && !self.is_dying {
+            let swim = self.swim_sprite();
+            if !self.attacking && resources.has_artwork(swim) {
+                // 游泳素材按行走动画的播放时间播放
+                let frame = animation::frame_at(swim, self.animator.elapsed());
+                batcher.add(resources, swim, frame, resources.draw_param(swim).dest([self.x, y]));
+            } else {
+                // 没有游泳素材时只显示行走动画的上半身，并下沉一些，看起来像泡在水里
+                draw_params = draw_params
+                    .src(Rect::new(0.0, 0.0, 1.0, SWIMMING_VISIBLE_FRACTION))
+                    .dest([self.x, y + self.geometry.cell_height * (1.0 - SWIMMING_VISIBLE_FRACTION) / 2.0]);
+                batcher.add_animator(resources, &self.animator, draw_params);
             }
+        } else {
+            // 绘制僵尸主体
+            batcher.add_animator(resources, &self.animator, draw_params);
         }
         
-        Ok(())
+        // 如果头部正在掉落，绘制头部动画
+        if let Some(head_animator) = self.head_animator.as_ref().filter(|_| !self.death_animation_complete) {
+            batcher.add_animator(resources, head_animator, resources.draw_param(HEAD_SPRITE).dest([self.head_x, self.head_y]));
+        }
     }
 
     /// 获取僵尸的碰撞矩形。
@@ -264,7 +332,7 @@ impl Zombie {
     ///
     /// 返回一个 `Rect` 结构，表示僵尸的碰撞边界框。
     pub fn get_rect(&self) -> Rect {
-        let y = GRID_START_Y + (self.row as f32) * GRID_CELL_HEIGHT - GRID_CELL_HEIGHT/4.0;
+        let y = self.screen_y();
         
         // 僵尸的碰撞区域应该比显示的图像小一些，以使游戏更加公平 .TODO: 根据实际图像大小调整
         let width = 20.0;
@@ -289,6 +357,28 @@ impl Zombie {
     ///
     /// 如果僵尸因此次伤害而死亡，则返回 `true`，否则返回 `false`。
     pub fn take_damage(&mut self, damage: i32) -> bool {
+        self.take_hit(damage, HitKind::Straight)
+    }
+
+    /// 以指定的命中方式对僵尸造成伤害。
+    ///
+    /// 直线攻击会先被僵尸的正面护盾（如果有）吸收，抛物线攻击会越过护盾直接命中僵尸。
+    /// 之后的处理与 `take_damage` 相同。
+    ///
+    /// # Arguments
+    ///
+    /// * `damage` - 对僵尸造成的伤害值。
+    /// * `kind` - 攻击命中的方式。
+    ///
+    /// # Returns
+    ///
+    /// 如果僵尸因此次伤害而死亡，则返回 `true`，否则返回 `false`。
+    pub fn take_hit(&mut self, damage: i32, kind: HitKind) -> bool {
+        // 正面护盾只能挡住直线攻击
+        if kind == HitKind::Straight && self.zombie_impl.absorb_with_shield(damage) {
+            return false;
+        }
+
         // 先检查是否有特殊伤害处理逻辑（如路障掉落等）
         let damage_handled = self.zombie_impl.handle_damage(damage);
         
@@ -309,9 +399,8 @@ impl Zombie {
         if self.health <= 0 {
             // 设置死亡动画状态
             self.is_dying = true;
-            // 重置动画帧以便从头播放死亡动画
-            self.animation_frame = 0;
-            self.animation_timer = 0;
+            // 从头播放死亡动画
+            self.animator.play(DIE_SPRITE);
             return true;
         }
         
@@ -329,14 +418,19 @@ impl Zombie {
     /// * `plant_health` - 一个可变引用，指向目标植物的当前生命值。
     /// * `dt` - 自上一帧以来经过的时间（毫秒），用于更新攻击计时器。
     pub fn attack_plant(&mut self, plant_health: &mut i32, dt: u64) {
+        // 被定身时不能啃食植物
+        if self.status.is_stunned() {
+            return;
+        }
+
         // 增加攻击计时器
-        self.attack_timer += dt;
+        self.attack_timer += self.scaled_dt(dt);
         
         // 如果达到攻击间隔时间，执行攻击
-        if self.attack_timer >= self.attack_interval {
+        if self.attack_timer >= self.spec.attack_interval {
             // 对植物造成伤害
-            *plant_health -= self.attack_damage;
-            println!("僵尸啃咬了植物，造成{}点伤害，植物剩余生命值: {}", self.attack_damage, *plant_health);
+            *plant_health -= self.spec.attack_damage;
+            println!("僵尸啃咬了植物，造成{}点伤害，植物剩余生命值: {}", self.spec.attack_damage, *plant_health);
             
             // 重置攻击计时器
             self.attack_timer = 0;
@@ -363,14 +457,14 @@ impl Zombie {
         }
         
         // 获取植物的屏幕坐标 (左边缘),这个位置是微调出来的
-        let plant_left_edge = GRID_START_X + (plant_grid_x as f32) * GRID_CELL_WIDTH - GRID_CELL_WIDTH;
+        let plant_left_edge = self.geometry.column_x(plant_grid_x) - self.geometry.cell_width;
         
         // 获取僵尸的前部坐标 (右边缘)
         let zombie_right_edge = self.x + 20.0; // TODO:根据僵尸大小调整
         
         // 如果僵尸的右边缘达到或超过植物的左边缘，则视为碰撞
         // 通常加一个小的偏移量使碰撞更接近图形效果
-        zombie_right_edge >= plant_left_edge && zombie_right_edge <= plant_left_edge + GRID_CELL_WIDTH
+        zombie_right_edge >= plant_left_edge && zombie_right_edge <= plant_left_edge + self.geometry.cell_width
     }
     
     /// 设置僵尸的攻击状态。
@@ -389,9 +483,8 @@ impl Zombie {
             self.attack_target = target_index;
             
             // 重置攻击相关的动画
+            self.animator.play(self.current_sprite());
             if is_attacking {
-                self.animation_frame = 0;
-                self.animation_timer = 0;
                 self.attack_timer = 0;
             }
         }
@@ -405,4 +498,187 @@ impl Zombie {
     pub fn get_zombie_type(&self) -> ZombieType {
         self.zombie_type
     }
+
+    /// 设置僵尸是否在水面行中游泳。
+    pub fn set_swimming(&mut self, swimming: bool) {
+        self.swimming = swimming;
+    }
+
+    /// 僵尸是否在水面行中游泳。
+    pub fn is_swimming(&self) -> bool {
+        self.swimming
+    }
+
+    /// 使僵尸被定身一段时间（例如被黄油击中），期间不能移动也不能啃食植物。
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - 定身持续的时间（毫秒）。
+    pub fn stun(&mut self, duration: u64) {
+        self.status.stun(duration);
+    }
+
+    /// 僵尸当前是否被定身。
+    pub fn is_stunned(&self) -> bool {
+        self.status.is_stunned()
+    }
+
+    /// 使僵尸被减速一段时间（例如被寒冰豌豆击中），期间移动和啃食速度减半。
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - 减速持续的时间（毫秒）。
+    pub fn slow(&mut self, duration: u64) {
+        self.status.slow(duration);
+    }
+
+    /// 解除僵尸身上的减速效果（例如被火焰豌豆击中）。
+    pub fn thaw(&mut self) {
+        self.status.thaw();
+    }
+
+    /// 僵尸当前是否被减速。
+    pub fn is_slowed(&self) -> bool {
+        self.status.is_slowed()
+    }
+
+    /// 让会投掷的僵尸（例如投篮车僵尸）向所在行最左侧的植物投出一个敌方抛物线投掷物。
+    ///
+    /// 投掷计时器每隔 `ZombieTrait::lob_interval` 毫秒就绪一次，就绪后只要所在行中僵尸左侧有存活的植物
+    /// 并且还有可以投掷的东西，就立即投掷。正在死亡、被定身或在水中游泳的僵尸不会投掷。
+    ///
+    /// # Arguments
+    ///
+    /// * `plants` - 场上所有植物。
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    ///
+    /// # Returns
+    ///
+    /// 返回投出的敌方投掷物，本次没有投掷时返回 `None`。
+    pub fn throw_at_plants(&mut self, plants: &[Plant], dt: u64) -> Option<Projectile> {
+        let interval = self.zombie_impl.lob_interval()?;
+        if self.is_dying || self.is_stunned() || self.swimming {
+            return None;
+        }
+        self.lob_timer = (self.lob_timer + dt).min(interval);
+        if self.lob_timer < interval {
+            return None;
+        }
+
+        let geometry = self.geometry;
+        let target = plants
+            .iter()
+            .filter(|plant| !plant.is_dead && plant.grid_y == self.row && geometry.column_x(plant.grid_x) < self.x)
+            .min_by_key(|plant| plant.grid_x)?;
+        let kind = self.zombie_impl.next_lob()?;
+        self.lob_timer = 0;
+
+        let start = (self.x + 40.0, self.screen_y() + 20.0); // TODO：根据投篮车图像调整投出点
+        let landing = (
+            geometry.column_x(target.grid_x) + geometry.cell_width / 2.0,
+            geometry.row_y(self.row) + geometry.cell_height / 2.0,
+        );
+        Some(Projectile::hostile_lobbed(start, landing, self.row, kind, geometry))
+    }
+
+    /// 僵尸当前是否还有可以被打掉的护甲（例如路障）。
+    pub fn has_armor(&self) -> bool {
+        self.zombie_impl.has_armor()
+    }
+
+    /// 打掉僵尸的护甲（例如路障），僵尸的生命值降为失去护甲后的生命值。
+    ///
+    /// # Returns
+    ///
+    /// 如果僵尸有护甲并被打掉，返回 `true`，否则返回 `false`。
+    pub fn knock_off_armor(&mut self) -> bool {
+        if self.is_dying || !self.zombie_impl.knock_off_armor() {
+            return false;
+        }
+        if let Some(new_health) = self.zombie_impl.transform_health() {
+            self.health = self.health.min(new_health);
+        }
+        true
+    }
+
+    /// 对僵尸施加一种命中效果。
+    ///
+    /// # Arguments
+    ///
+    /// * `effect` - 要施加的效果：减速、定身、解冻或打掉护甲。
+    pub fn apply_effect(&mut self, effect: HitEffect) {
+        match effect {
+            HitEffect::Slow(duration) => self.slow(duration),
+            HitEffect::Stun(duration) => self.stun(duration),
+            HitEffect::Burn => self.thaw(),
+            HitEffect::KnockOffArmor => {
+                self.knock_off_armor();
+            }
+        }
+    }
+
+    /// 获取僵尸的唯一标识。
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 僵尸当前的移动速度，被减速时速度减半。
+    fn current_speed(&self) -> f32 {
+        if self.status.is_slowed() {
+            self.spec.speed * SLOW_FACTOR
+        } else {
+            self.spec.speed
+        }
+    }
+
+    /// 将经过的时间按减速效果缩放，用于动画和啃食计时。
+    fn scaled_dt(&self, dt: u64) -> u64 {
+        if self.status.is_slowed() {
+            (dt as f32 * SLOW_FACTOR) as u64
+        } else {
+            dt
+        }
+    }
+
+    /// 预测僵尸在 `after` 毫秒后的X坐标，供抛物线攻击瞄准使用。
+    ///
+    /// 正在啃食植物、被定身或正在死亡的僵尸不会移动。
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - 从现在起经过的时间（毫秒）。
+    ///
+    /// # Returns
+    ///
+    /// 返回预测的X坐标。
+    pub fn predicted_x(&self, after: u64) -> f32 {
+        if self.attacking || self.is_dying || self.status.is_stunned() {
+            self.x
+        } else {
+            self.x - self.current_speed() * after as f32
+        }
+    }
+
+    /// 僵尸被拖入水下（例如被缠绕水草抓住），立即死亡且不播放死亡动画。
+    pub fn drown(&mut self) {
+        self.remove_instantly();
+    }
+
+    /// 僵尸被一口吞下（例如被大嘴花吞掉），立即死亡且不播放死亡动画。
+    pub fn swallow(&mut self) {
+        self.remove_instantly();
+    }
+
+    /// 僵尸能否被大嘴花等植物一口吞下。
+    pub fn can_be_swallowed(&self) -> bool {
+        self.zombie_impl.can_be_swallowed()
+    }
+
+    /// 使僵尸立即死亡并在下一次清理时被移除，不播放死亡动画。
+    fn remove_instantly(&mut self) {
+        self.health = 0;
+        self.is_dying = true;
+        self.attacking = false;
+        self.death_animation_complete = true;
+    }
 }
@@ -0,0 +1,63 @@
+//! # 僵尸状态效果模块 (`status`)
+//!
+//! 记录作用在单个僵尸身上的临时状态效果，例如被玉米投手的黄油击中后的定身、被寒冰豌豆击中后的减速。
+//! 状态效果只会随时间流逝而消失，由 `Zombie::update` 每帧推进。
+
+/// 僵尸身上的状态效果。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatusEffects {
+    /// 定身剩余的时间（毫秒），为 0 时没有被定身。被定身的僵尸不能移动也不能啃食植物。
+    stun_remaining: u64,
+    /// 减速剩余的时间（毫秒），为 0 时没有被减速。被减速的僵尸移动和啃食都会变慢。
+    slow_remaining: u64,
+}
+
+impl StatusEffects {
+    /// 创建没有任何状态效果的 `StatusEffects`。
+    pub fn new() -> Self {
+        StatusEffects::default()
+    }
+
+    /// 使僵尸被定身一段时间。已经被定身时取剩余时间与新时间中较长的一个。
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - 定身持续的时间（毫秒）。
+    pub fn stun(&mut self, duration: u64) {
+        self.stun_remaining = self.stun_remaining.max(duration);
+    }
+
+    /// 僵尸当前是否被定身。
+    pub fn is_stunned(&self) -> bool {
+        self.stun_remaining > 0
+    }
+
+    /// 使僵尸被减速一段时间。已经被减速时取剩余时间与新时间中较长的一个。
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - 减速持续的时间（毫秒）。
+    pub fn slow(&mut self, duration: u64) {
+        self.slow_remaining = self.slow_remaining.max(duration);
+    }
+
+    /// 解除减速效果（例如被火焰豌豆击中）。
+    pub fn thaw(&mut self) {
+        self.slow_remaining = 0;
+    }
+
+    /// 僵尸当前是否被减速。
+    pub fn is_slowed(&self) -> bool {
+        self.slow_remaining > 0
+    }
+
+    /// 推进所有状态效果的计时。
+    ///
+    /// # Arguments
+    ///
+    /// * `dt` - 自上次更新以来的时间增量（毫秒）。
+    pub fn update(&mut self, dt: u64) {
+        self.stun_remaining = self.stun_remaining.saturating_sub(dt);
+        self.slow_remaining = self.slow_remaining.saturating_sub(dt);
+    }
+}
@@ -4,10 +4,8 @@
 //! 用于根据指定的类型创建不同种类的僵尸实例。
 //! 这是一个典型的工厂模式实现，旨在解耦僵尸的创建逻辑和使用逻辑。
 
+use crate::core::registry::{self, ZombieSpec};
 use crate::zombies::zombie_trait::ZombieTrait;
-use crate::zombies::normal_zombie::NormalZombie;
-use crate::zombies::conehead_zombie::ConeheadZombie;
-// use crate::zombies::buckethead_zombie::BucketHeadZombie; // 示例：未来可添加的铁桶僵尸
 
 /// 枚举了游戏中所有可能的僵尸类型。
 ///
@@ -19,6 +17,10 @@ pub enum ZombieType {
     Normal,
     /// 路障僵尸，比普通僵尸更耐打。
     Conehead,
+    /// 投篮车僵尸，向后方的植物投掷篮球。
+    Catapult,
+    /// 通过 `registry::register_zombie` 注册的自定义僵尸，编号按注册顺序分配。
+    Custom(u16),
     // /// 示例：铁桶僵尸，具有更高防御力。
     // Buckethead,
     // /// 示例：橄榄球僵尸，移动速度快，生命值高。
@@ -27,6 +29,17 @@ pub enum ZombieType {
     // ScreenDoorZombie,
 }
 
+impl ZombieType {
+    /// 获取该僵尸类型在内容注册表中的规格。
+    ///
+    /// # Panics
+    ///
+    /// 如果该类型是尚未注册的自定义僵尸，则 panic。
+    pub fn spec(&self) -> ZombieSpec {
+        registry::zombie_spec(*self)
+    }
+}
+
 /// 僵尸工厂结构体。
 ///
 /// `ZombieFactory` 提供一个静态方法 `create_zombie`，
@@ -37,6 +50,8 @@ pub struct ZombieFactory;
 impl ZombieFactory {
     /// 根据给定的 `ZombieType` 创建并返回一个僵尸实例。
     ///
+    /// 僵尸实例由注册表中该类型的构造函数创建。
+    ///
     /// # Arguments
     ///
     /// * `zombie_type` - 要创建的僵尸的类型 (`ZombieType`)。
@@ -44,14 +59,8 @@ impl ZombieFactory {
     /// # Returns
     ///
     /// 返回一个 `Box<dyn ZombieTrait>`，其中包含了新创建的具体僵尸对象。
-    /// 如果请求的僵尸类型未在工厂中定义，则此方法会 panic（当前实现中）。
-    /// 在更健壮的实现中，可能会返回 `Result` 或 `Option`。
+    /// 如果请求的是尚未注册的自定义僵尸类型，则此方法会 panic。
     pub fn create_zombie(zombie_type: ZombieType) -> Box<dyn ZombieTrait> {
-        match zombie_type {
-            ZombieType::Normal => Box::new(NormalZombie::new()),
-            // 以后添加更多僵尸类型
-            ZombieType::Conehead => Box::new(ConeheadZombie::new()),
-            // ZombieType::Buckethead => Box::new(BucketHeadZombie::new()),
-        }
+        (zombie_type.spec().constructor)()
     }
-}
\ No newline at end of file
+}
@@ -2,150 +2,39 @@
 //!
 //! 定义了 `ZombieTrait`，这是一个所有具体僵尸类型都需要实现的特征（接口）。
 //! 它规定了僵尸必须具备的一系列通用行为和属性获取方法，
-//! 包括生命值、速度、攻击属性、动画帧数和图像资源等。
-//! 许多方法提供了默认实现，特别是对于动画帧和图像，它们会从 `Resources`
-//! 中获取通用的僵尸动画资源。具体僵尸类型可以覆盖这些方法以提供特有的行为或视觉效果。
+//! 包括受伤处理和外观变化等。生命值、速度、攻击属性和贴图名称登记在内容注册表的 `ZombieSpec` 中，
+//! 动画的帧数和绘制参数来自资源清单。
+//! 所有方法都提供了默认实现，具体僵尸类型可以覆盖这些方法以提供特有的行为或视觉效果。
 
-use crate::core::resources::Resources;
-// use ggez::{Context, GameResult}; // Context 和 GameResult 未在此 trait 的方法签名中直接使用
-use ggez::graphics::{DrawParam, Image};
+use crate::entities::projectile::ProjectileKind;
 
 /// `ZombieTrait` 定义了所有僵尸类型共享的核心行为和属性。
 ///
 /// 通过实现此特征，不同类型的僵尸（如普通僵尸、路障僵尸）可以被泛化处理，
 /// 并由通用的 `Zombie` 结构体进行管理。
 pub trait ZombieTrait {
-    /// 获取僵尸的初始生命值。
+    /// （可选）当前行走动画使用的贴图名称。
     ///
-    /// # Returns
-    ///
-    /// 返回僵尸的初始生命值 (`i32`)。
-    fn get_initial_health(&self) -> i32;
-    
-    /// 获取僵尸的移动速度。
-    ///
-    /// # Returns
-    ///
-    /// 返回僵尸的移动速度，单位通常是像素/毫秒 (`f32`)。
-    fn get_speed(&self) -> f32;
-    
-    /// 获取僵尸的攻击伤害值。
-    ///
-    /// # Returns
-    ///
-    /// 返回僵尸单次攻击造成的伤害 (`i32`)。
-    fn get_attack_damage(&self) -> i32;
-    
-    /// 获取僵尸的攻击间隔。
-    ///
-    /// # Returns
-    ///
-    /// 返回两次攻击之间的最小时间间隔，单位为毫秒 (`u64`)。
-    fn get_attack_interval(&self) -> u64;
-    
-    /// 获取僵尸行走动画的总帧数。
-    ///
-    /// 默认实现返回22帧。
-    ///
-    /// # Returns
-    ///
-    /// 返回行走动画的帧数 (`usize`)。
-    fn get_walk_frame_count(&self) -> usize {
-        // 默认返回22帧
-        22
-    }
-    
-    /// 获取僵尸攻击动画的总帧数。
-    ///
-    /// 默认实现返回21帧。
-    ///
-    /// # Returns
-    ///
-    /// 返回攻击动画的帧数 (`usize`)。
-    fn get_attack_frame_count(&self) -> usize {
-        // 默认返回21帧
-        21
-    }
-    
-    /// 获取僵尸死亡动画的总帧数。
-    ///
-    /// 默认实现返回10帧。
-    ///
-    /// # Returns
-    ///
-    /// 返回死亡动画的帧数 (`usize`)。
-    fn get_die_frame_count(&self) -> usize {
-        // 默认返回10帧
-        10
-    }
-    
-    /// 获取僵尸当前行走动画帧对应的图像。
-    ///
-    /// 默认实现从 `resources.zombies_walk1_images` 中获取图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `frame` - 当前动画帧的索引。
-    ///
-    /// # Returns
-    ///
-    /// 返回对应帧的图像引用 (`&'a Image`)。
-    fn get_walk_image<'a>(&self, resources: &'a Resources, frame: usize) -> &'a Image {
-        let walk_frame_count = resources.zombies_walk1_images.len();
-        if walk_frame_count > 0 {
-            &resources.zombies_walk1_images[frame % walk_frame_count]
-        } else {
-            &resources.zombies_walk1_images[0]
-        }
+    /// 默认返回 `None`，使用注册表中该僵尸的 `ZombieSpec::walk`。
+    /// 外观会变化的僵尸（例如失去路障的路障僵尸）可以覆盖此方法。
+    fn walk_sprite(&self) -> Option<&'static str> {
+        None
     }
-    
-    /// 获取僵尸当前攻击动画帧对应的图像。
-    ///
-    /// 默认实现从 `resources.zombie_attack_images` 中获取图像。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `frame` - 当前动画帧的索引。
-    ///
-    /// # Returns
+
+    /// （可选）当前啃食动画使用的贴图名称。
     ///
-    /// 返回对应帧的图像引用 (`&'a Image`)。
-    fn get_attack_image<'a>(&self, resources: &'a Resources, frame: usize) -> &'a Image {
-        let attack_frame_count = resources.zombie_attack_images.len();
-        if attack_frame_count > 0 {
-            &resources.zombie_attack_images[frame % attack_frame_count]
-        } else {
-            &resources.zombie_attack_images[0]
-        }
+    /// 默认返回 `None`，使用注册表中该僵尸的 `ZombieSpec::attack`。
+    fn attack_sprite(&self) -> Option<&'static str> {
+        None
     }
-    
-    /// 获取僵尸当前死亡动画帧对应的图像。
-    ///
-    /// 默认实现从 `resources.zombie_die_images` 中获取图像。
-    /// 如果请求的帧超出了动画范围，则返回最后一帧。
-    /// 如果死亡动画图像列表为空，则回退到行走动画的第一帧作为备用。
-    ///
-    /// # Arguments
-    ///
-    /// * `resources` - 游戏资源实例的引用。
-    /// * `frame` - 当前动画帧的索引。
-    ///
-    /// # Returns
+
+    /// （可选）当前游泳动画使用的贴图名称。
     ///
-    /// 返回对应帧的图像引用 (`&'a Image`)。
-    fn get_die_image<'a>(&self, resources: &'a Resources, frame: usize) -> &'a Image {
-        let die_frame_count = resources.zombie_die_images.len();
-        if die_frame_count > 0 && frame < die_frame_count {
-            &resources.zombie_die_images[frame]
-        } else if !resources.zombie_die_images.is_empty() {
-            &resources.zombie_die_images[resources.zombie_die_images.len() - 1]
-        } else {
-            &resources.zombies_walk1_images[0]
-        }
+    /// 默认返回 `None`，使用注册表中该僵尸的 `ZombieSpec::swim`。
+    fn swim_sprite(&self) -> Option<&'static str> {
+        None
     }
-    
+
     /// （可选）执行僵尸的特殊更新逻辑。
     ///
     /// 此方法每帧被调用，允许具体僵尸类型实现其特有的行为或状态更新。
@@ -158,19 +47,6 @@ pub trait ZombieTrait {
         // 默认实现为空，子类可以覆盖
     }
     
-    /// （可选）获取用于绘制此僵尸的特定 `DrawParam`。
-    ///
-    /// 这允许具体僵尸类型自定义其绘制时的缩放、旋转、颜色等参数。
-    /// 默认实现返回一个将图像缩放为原始大小80%的 `DrawParam`。
-    ///
-    /// # Returns
-    ///
-    /// 返回一个 `DrawParam` 实例。
-    fn get_draw_params(&self) -> DrawParam {
-        // 默认绘制参数
-        DrawParam::default().scale([0.8, 0.8])
-    }
-    
     /// （可选）指示此僵尸当前是否具有某种特殊能力或状态。
     ///
     /// 例如，路障僵尸在失去路障前具有特殊能力。
@@ -215,4 +91,62 @@ pub trait ZombieTrait {
         // 默认实现返回None，表示没有变形
         None
     }
+
+    /// （可选）用正面的护盾（例如铁栅门）吸收一次直线攻击的伤害。
+    ///
+    /// 只有直线攻击会调用此方法，抛物线攻击会越过护盾。
+    ///
+    /// # Arguments
+    ///
+    /// * `_damage` - 本次攻击的伤害值，默认未使用。
+    ///
+    /// # Returns
+    ///
+    /// 如果护盾吸收了这次伤害，返回 `true`，僵尸本身不会受到伤害。默认返回 `false`（没有护盾）。
+    fn absorb_with_shield(&mut self, _damage: i32) -> bool {
+        false
+    }
+
+    /// （可选）僵尸当前是否还有可以被打掉的护甲（例如路障）。
+    ///
+    /// 默认返回 `false`（没有护甲）。
+    fn has_armor(&self) -> bool {
+        false
+    }
+
+    /// （可选）立即打掉僵尸的护甲（例如路障），之后僵尸表现为失去护甲后的形态。
+    ///
+    /// 打掉护甲后，`Zombie` 会通过 `transform_health` 获取失去护甲后的生命值。
+    ///
+    /// # Returns
+    ///
+    /// 如果僵尸有护甲并被打掉，返回 `true`。默认返回 `false`（没有护甲）。
+    fn knock_off_armor(&mut self) -> bool {
+        false
+    }
+
+    /// （可选）两次向植物投掷抛物线投掷物之间的间隔（毫秒，例如投篮车僵尸）。
+    ///
+    /// 默认返回 `None`，僵尸不会投掷，只会啃食植物。
+    fn lob_interval(&self) -> Option<u64> {
+        None
+    }
+
+    /// （可选）取出下一个要投向植物的投掷物，例如消耗一个篮球。
+    ///
+    /// 只有 `lob_interval` 返回 `Some` 的僵尸才会调用此方法。
+    ///
+    /// # Returns
+    ///
+    /// 返回投掷物的类型；没有可以投掷的东西时返回 `None`（默认）。
+    fn next_lob(&mut self) -> Option<ProjectileKind> {
+        None
+    }
+
+    /// （可选）僵尸能否被大嘴花等植物一口吞下。
+    ///
+    /// 默认返回 `true`，体型巨大的僵尸应返回 `false`。
+    fn can_be_swallowed(&self) -> bool {
+        true
+    }
 }
\ No newline at end of file
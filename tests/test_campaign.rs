@@ -0,0 +1,172 @@
+use pvz_rust_wsll_lib::core::profile::Profile;
+use pvz_rust_wsll_lib::mechanics::campaign::{self, CampaignError, LevelStatus};
+use pvz_rust_wsll_lib::plants::PlantType;
+
+#[test]
+fn test_new_profile_unlocks_first_level_only() {
+    let profile = Profile::default();
+
+    // 新存档只有第一关解锁
+    assert_eq!(profile.level_status(0), LevelStatus::Unlocked);
+    for index in 1..campaign::level_count() {
+        assert_eq!(profile.level_status(index), LevelStatus::Locked);
+    }
+}
+
+#[test]
+fn test_complete_level_unlocks_reward_and_next_level() {
+    let mut profile = Profile::default();
+    let first_level = &campaign::levels()[0];
+
+    // 首次通关获得奖励植物
+    let reward = profile.complete_level(first_level);
+    assert_eq!(reward, first_level.reward);
+    assert_eq!(profile.level_status(0), LevelStatus::Completed);
+    assert_eq!(profile.level_status(1), LevelStatus::Unlocked);
+
+    // 再次通关不会重复奖励
+    assert_eq!(profile.complete_level(first_level), None);
+    assert_eq!(profile.completed_levels.len(), 1);
+}
+
+#[test]
+fn test_profile_save_and_load() {
+    let dir = std::env::temp_dir().join("pvz_rust_wsll_test_profile");
+    let mut profile = Profile::default();
+    profile.unlocked_plants.push(PlantType::Sunflower);
+    profile.completed_levels.push(campaign::levels()[0].id.clone());
+
+    profile.save(&dir).expect("profile should be saved");
+    let loaded = Profile::load(&dir);
+
+    assert_eq!(loaded, profile);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_night_level_has_no_natural_sun() {
+    use pvz_rust_wsll_lib::mechanics::campaign::Environment;
+    use pvz_rust_wsll_lib::mechanics::entity_manager::EntityManager;
+
+    let levels = campaign::levels();
+    let night = levels
+        .iter()
+        .find(|level| level.environment == Environment::Night)
+        .expect("campaign should contain a night level");
+    let day = &levels[0];
+
+    // 夜间关卡即使经过很长时间也不会掉落阳光
+    let mut night_manager = EntityManager::for_level(night);
+    assert!(!night_manager.check_natural_sun_spawn(60_000));
+
+    // 白天关卡的阳光会正常掉落
+    let mut day_manager = EntityManager::for_level(day);
+    assert!(day_manager.check_natural_sun_spawn(60_000));
+}
+
+#[test]
+fn test_every_registered_plant_is_reachable() {
+    use pvz_rust_wsll_lib::core::registry;
+
+    // 按顺序通关整个冒险模式后，每一种内置植物都已经解锁
+    let levels = campaign::levels();
+    let mut profile = Profile::default();
+    for (index, level) in levels.iter().enumerate() {
+        assert_ne!(profile.level_status(index), LevelStatus::Locked);
+        profile.complete_level(level);
+    }
+    for plant_type in registry::plant_types() {
+        if !matches!(plant_type, PlantType::Custom(_)) {
+            assert!(profile.unlocked_plants.contains(&plant_type), "{:?} is never unlocked", plant_type);
+        }
+    }
+
+    // 每一关最多奖励一种植物，不会重复奖励
+    let rewards: Vec<_> = levels.iter().filter_map(|level| level.reward).collect();
+    for (i, reward) in rewards.iter().enumerate() {
+        assert!(!rewards[i + 1..].contains(reward));
+    }
+}
+
+#[test]
+fn test_levels_file_is_the_campaign() {
+    // 资源目录中的关卡文件与内置的关卡列表一致
+    let content = std::fs::read_to_string("Resource/levels.toml").expect("levels.toml should exist");
+    let levels = campaign::from_toml(&content).expect("levels.toml should be valid");
+    assert_eq!(levels, campaign::builtin());
+    assert_eq!(levels, campaign::levels());
+
+    // 资源目录中没有关卡文件时恢复内置的关卡列表
+    let dir = std::env::temp_dir().join("pvz_rust_wsll_test_missing_levels");
+    assert!(campaign::load(&dir).is_ok());
+    assert_eq!(campaign::levels(), campaign::builtin());
+}
+
+#[test]
+fn test_invalid_levels_file_is_rejected() {
+    let level = |id: &str| {
+        format!(
+            "[[levels]]\nid = \"{}\"\nname = \"Test\"\nenvironment = \"Day\"\nlayout = \"standard\"\ntotal_waves = 3\ninitial_sun = 50\n",
+            id
+        )
+    };
+
+    let valid = campaign::from_toml(&level("t-1")).expect("a minimal level should be valid");
+    assert_eq!(valid[0].graves, 0);
+    assert_eq!(valid[0].reward, None);
+
+    // 重复的关卡标识、空的关卡列表和超出关卡波数的特殊僵尸设置不合法
+    let duplicated = format!("{}\n{}", level("t-1"), level("t-1"));
+    assert!(matches!(campaign::from_toml(&duplicated), Err(CampaignError::Invalid(_))));
+    assert!(matches!(campaign::from_toml("levels = []"), Err(CampaignError::Invalid(_))));
+    let late_conehead = format!("{}conehead_from_wave = 3\n", level("t-1"));
+    assert!(matches!(campaign::from_toml(&late_conehead), Err(CampaignError::Invalid(_))));
+
+    // 未知的布局、植物和字段无法解析
+    let unknown_layout = level("t-1").replace("standard", "moon");
+    assert!(matches!(campaign::from_toml(&unknown_layout), Err(CampaignError::Parse(_))));
+    let unknown_reward = format!("{}reward = \"Moonflower\"\n", level("t-1"));
+    assert!(matches!(campaign::from_toml(&unknown_reward), Err(CampaignError::Parse(_))));
+    let unknown_field = format!("{}boss = true\n", level("t-1"));
+    assert!(matches!(campaign::from_toml(&unknown_field), Err(CampaignError::Parse(_))));
+}
+
+#[test]
+fn test_inline_layout_in_levels_file() {
+    use pvz_rust_wsll_lib::ui::lawn::{LawnLayout, TileType};
+
+    let level = |layout: &str| {
+        format!(
+            "[[levels]]\nid = \"t-1\"\nname = \"Test\"\nenvironment = \"Day\"\nlayout = {}\ntotal_waves = 3\ninitial_sun = 50\n",
+            layout
+        )
+    };
+
+    // 直接写出的布局：行列数、位置、单元格大小和每一行的地形
+    let inline = level(
+        "{ rows = 4, cols = 8, origin = [255.0, 180.0], cell_size = [80.0, 100.0], tiles = [\"grass\", \"water\", \"water\", \"dirt\"] }",
+    );
+    let levels = campaign::from_toml(&inline).expect("an inline layout should be valid");
+    let layout = &levels[0].layout;
+    assert_eq!((layout.geometry.rows, layout.geometry.cols), (4, 8));
+    assert_eq!((layout.geometry.origin_x, layout.geometry.origin_y), (255.0, 180.0));
+    assert_eq!((layout.geometry.cell_width, layout.geometry.cell_height), (80.0, 100.0));
+    assert_eq!(layout.row_tiles, vec![TileType::Grass, TileType::Water, TileType::Water, TileType::Dirt]);
+    assert_eq!(layout.spawn_rows(), vec![0, 1, 2]);
+
+    // 布局名称仍然可以作为内置布局的简写
+    let preset = campaign::from_toml(&level("\"pool\"")).unwrap();
+    assert_eq!(preset[0].layout, LawnLayout::pool());
+
+    // 地形比行数多、没有行或单元格大小不为正的布局不合法，未知的地形和字段无法解析
+    let too_many_tiles = level("{ rows = 1, cols = 9, origin = [0.0, 0.0], cell_size = [80.0, 100.0], tiles = [\"grass\", \"grass\"] }");
+    assert!(matches!(campaign::from_toml(&too_many_tiles), Err(CampaignError::Invalid(_))));
+    let no_rows = level("{ rows = 0, cols = 9, origin = [0.0, 0.0], cell_size = [80.0, 100.0] }");
+    assert!(matches!(campaign::from_toml(&no_rows), Err(CampaignError::Invalid(_))));
+    let flat_cells = level("{ rows = 5, cols = 9, origin = [0.0, 0.0], cell_size = [80.0, 0.0] }");
+    assert!(matches!(campaign::from_toml(&flat_cells), Err(CampaignError::Invalid(_))));
+    let unknown_tile = level("{ rows = 1, cols = 9, origin = [0.0, 0.0], cell_size = [80.0, 100.0], tiles = [\"lava\"] }");
+    assert!(matches!(campaign::from_toml(&unknown_tile), Err(CampaignError::Parse(_))));
+    let unknown_key = level("{ rows = 1, cols = 9, origin = [0.0, 0.0], cell_size = [80.0, 100.0], depth = 2 }");
+    assert!(matches!(campaign::from_toml(&unknown_key), Err(CampaignError::Parse(_))));
+}
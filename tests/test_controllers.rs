@@ -1,4 +1,4 @@
-use pvz_rust_wsll_lib::mechanics::level_controller::LevelController;
+use pvz_rust_wsll_lib::mechanics::level_controller::{LevelController, ENDLESS_ZOMBIES};
 use pvz_rust_wsll_lib::mechanics::entity_manager::EntityManager;
 use pvz_rust_wsll_lib::zombies::ZombieType;
 use pvz_rust_wsll_lib::zombies::Zombie;
@@ -57,3 +57,50 @@ fn test_entity_manager_update() {
     // 这里我们只是验证函数调用是否成功
     assert!(spawn_info.is_empty() || !spawn_info.is_empty());
 }
+
+#[test]
+fn test_endless_level_controller() {
+    use pvz_rust_wsll_lib::mechanics::level_controller::ENDLESS_WAVES_PER_FLAG;
+
+    let level_controller = LevelController::endless();
+    let zombies: Vec<Zombie> = vec![];
+
+    // 无尽模式永远不会胜利，刚开始时也不在休整期
+    assert!(level_controller.is_endless());
+    assert!(!level_controller.is_level_completed(&zombies));
+    assert!(!level_controller.is_on_break());
+    assert_eq!(level_controller.flags_completed(), 0);
+    assert_eq!(level_controller.break_remaining(), None);
+
+    // 预算随波次递增，每面旗帜的最后一波预算更高
+    assert!(LevelController::wave_budget(1) > LevelController::wave_budget(0));
+    assert!(LevelController::wave_budget(ENDLESS_WAVES_PER_FLAG) > LevelController::wave_budget(0));
+    let big_wave = ENDLESS_WAVES_PER_FLAG - 1;
+    assert!(LevelController::wave_budget(big_wave) > LevelController::wave_budget(big_wave + 1));
+}
+
+#[test]
+fn test_endless_wave_composition_spends_budget() {
+    for budget in 1..20 {
+        let wave = LevelController::compose_wave(budget);
+        let spent: u32 = wave.iter().map(|&zombie_type| LevelController::spawn_cost(zombie_type)).sum();
+        assert_eq!(spent, budget);
+        // 波次中只会出现无尽模式阵容中的僵尸，与选卡界面展示和预先加载的阵容一致
+        assert!(wave.iter().all(|zombie_type| ENDLESS_ZOMBIES.contains(zombie_type)));
+    }
+}
+
+#[test]
+fn test_level_without_spawnable_rows_falls_back_to_every_row() {
+    use pvz_rust_wsll_lib::mechanics::campaign::{self, LevelDefinition};
+    use pvz_rust_wsll_lib::ui::lawn::{LawnLayout, TileType};
+
+    // 所有行都是泥地的布局没有僵尸可以出现的行
+    let layout = LawnLayout { row_tiles: vec![TileType::Dirt; 5], ..LawnLayout::standard() };
+    assert!(layout.spawn_rows().is_empty());
+
+    // 控制器退回到所有行，生成僵尸时不会因为空的行列表而 panic
+    let level = LevelDefinition { layout, ..campaign::levels().remove(1) };
+    let controller = LevelController::for_level(&level);
+    assert_eq!(controller.spawn_rows(), &[0, 1, 2, 3, 4]);
+}
@@ -13,3 +13,263 @@ fn test_design_dimensions() {
     // 注意：DESIGN_WIDTH和DESIGN_HEIGHT常量在main.rs中定义，但在测试中无法直接访问
     // 因为测试是针对lib的，而main.rs中的常量不是lib的一部分
 }
+
+#[test]
+fn test_tuning_file_and_overrides() {
+    use pvz_rust_wsll_lib::core::tuning::{self, Tuning, TuningError};
+    use pvz_rust_wsll_lib::entities::projectile::ProjectileKind;
+    use pvz_rust_wsll_lib::plants::chomper::DIGEST_DURATION;
+    use pvz_rust_wsll_lib::plants::{Plant, PlantType};
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+    use pvz_rust_wsll_lib::ui::shop::Shop;
+    use pvz_rust_wsll_lib::zombies::{Zombie, ZombieType};
+    use std::path::Path;
+    use std::time::Duration;
+
+    // 随游戏发布的调校文件与编译时默认值一致
+    let shipped = std::fs::read_to_string(Path::new("Resource").join(tuning::TUNING_FILE_NAME)).unwrap();
+    let shipped = Tuning::from_toml(&shipped).unwrap();
+    let defaults = Tuning::default();
+    assert_eq!(shipped.initial_sun, defaults.initial_sun);
+    assert_eq!(shipped.sun, defaults.sun);
+    assert_eq!(shipped.pea, defaults.pea);
+    assert_eq!(shipped.action_cooldown("peashooter"), Some(1400));
+
+    // 缺少的项使用默认值，未知的字段、标识和越界的数值会被拒绝
+    assert_eq!(Tuning::from_toml("").unwrap(), defaults);
+    assert!(matches!(Tuning::from_toml("initail_sun = 50"), Err(TuningError::Parse(_))));
+    assert!(matches!(Tuning::from_toml("[plants.cherry_bomb]\nhealth = 10"), Err(TuningError::Invalid(_))));
+    assert!(matches!(Tuning::from_toml("[pea]\ndamage = 0"), Err(TuningError::Invalid(_))));
+    assert!(matches!(Tuning::from_toml("[sun]\nmax_delay = 100"), Err(TuningError::Invalid(_))));
+    assert!(matches!(Tuning::from_toml("[plants.wallnut]\nrecharge_ms = 0"), Err(TuningError::Invalid(_))));
+    assert!(matches!(Tuning::from_toml("[plants.chomper]\naction_cooldown_ms = 0"), Err(TuningError::Invalid(_))));
+
+    // 启用的数值会覆盖注册表规格、投射物和植物动作间隔
+    let mut shop = Shop::with_plants(&[PlantType::WallNut]);
+    let default_recharge = shop.cards[0].cooldown;
+    let custom = Tuning::from_toml(
+        "initial_sun = 150\n\
+         [pea]\nspeed = 0.5\ndamage = 30\n\
+         [plants.wallnut]\nhealth = 6000\nrecharge_ms = 20000\n\
+         [plants.chomper]\naction_cooldown_ms = 1000\n\
+         [zombies.conehead]\nhealth = 500\nspeed = 0.02",
+    )
+    .unwrap();
+    tuning::install(custom).unwrap();
+    assert_eq!(tuning::current().initial_sun, 150);
+    assert_eq!(ProjectileKind::Pea.damage(), 30);
+    assert_eq!(ProjectileKind::Pea.speed(), 0.5);
+    assert_eq!(PlantType::WallNut.spec().recharge_ms, 20000);
+    // 已有的商店卡片缓存了规格，刷新后才使用新的数值
+    assert_eq!(shop.cards[0].cooldown, default_recharge);
+    shop.refresh_specs();
+    assert_eq!(shop.cards[0].cooldown, Duration::from_millis(20000));
+    assert_eq!(Plant::new(PlantType::WallNut, 0, 0).health, 6000);
+    let conehead = ZombieType::Conehead.spec();
+    assert_eq!((conehead.health, conehead.speed, conehead.attack_damage), (500, 0.02, 100));
+    let mut normal = Zombie::new(ZombieType::Normal, 0);
+    assert!(!normal.take_damage(199));
+    // 路障僵尸按调校后的生命值计算路障何时掉落
+    let mut conehead = Zombie::new(ZombieType::Conehead, 0);
+    conehead.take_damage(250);
+    assert!(conehead.has_armor());
+
+    // 大嘴花的消化按实际经过的时间推进，不受调校后的动作间隔影响
+    let mut chomper = Plant::new(PlantType::Chomper, 2, 1);
+    let mut prey = Zombie::new(ZombieType::Normal, 1);
+    prey.x = LawnGeometry::STANDARD.column_x(3) - 40.0;
+    let zombies = vec![prey];
+    let mut effects = Vec::new();
+    chomper.update(1000, &zombies, &mut effects);
+    assert_eq!(effects.len(), 1);
+    effects.clear();
+    for _ in 0..(DIGEST_DURATION / 1000) {
+        chomper.update(1000, &zombies, &mut effects);
+    }
+    assert!(effects.is_empty());
+    chomper.update(1000, &zombies, &mut effects);
+    assert_eq!(effects.len(), 1);
+
+    // 校验失败时保留当前数值
+    let mut invalid = Tuning::default();
+    invalid.initial_sun = -1;
+    assert!(tuning::install(invalid).is_err());
+    assert_eq!(tuning::current().initial_sun, 150);
+
+    tuning::install(Tuning::default()).unwrap();
+    assert_eq!(ProjectileKind::Pea.damage(), 20);
+    assert_eq!(PlantType::WallNut.spec().health, 5000);
+}
+
+#[test]
+fn test_asset_manifest() {
+    use pvz_rust_wsll_lib::core::asset_manifest::AssetManifest;
+    use pvz_rust_wsll_lib::core::registry;
+    use std::path::Path;
+
+    // 随游戏发布的清单可以解析，并覆盖注册表用到的全部贴图
+    let manifest = AssetManifest::load(Path::new("Resource")).unwrap();
+    for key in registry::sprite_keys() {
+        assert!(manifest.sprites.contains_key(key), "missing sprite '{}'", key);
+    }
+    let peashooter = manifest.sprites["peashooter"].frame_paths();
+    assert_eq!(peashooter.len(), 13);
+    assert_eq!(peashooter[0], "/plants/Peashooter/1.png");
+    assert_eq!(manifest.sprites["background"].frame_paths().len(), 1);
+    assert!(manifest.sprites["chomper_digest"].frame_paths().is_empty());
+
+    // 不完整或数值不合理的条目会被拒绝
+    assert!(AssetManifest::from_toml("[sprites.a]\nfps = 10.0").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a/{}.png\"\nframes = [5, 1]").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a.png\"\nframes = [1, 5]").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a.png\"\nscale = [0.0, 1.0]").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a.png\"\nsize = 3").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a/{}.png\"\nframes = [1, 3]\nevents = { hit = 3 }").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a.png\"\nmode = \"rewind\"").is_err());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a.png\"").is_ok());
+}
+
+#[test]
+fn test_gif_decoding() {
+    use pvz_rust_wsll_lib::core::asset_manifest::AssetManifest;
+    use pvz_rust_wsll_lib::core::gif_animation::decode_gif;
+    use std::fs::File;
+
+    // 现有的 GIF 素材被解码成完整画布大小的帧序列，每帧都有正的延迟
+    let file = File::open("Resource/plants/SnowPea.gif").unwrap();
+    let frames = decode_gif(file, "SnowPea.gif").unwrap();
+    assert!(frames.len() > 1);
+    for frame in &frames {
+        assert_eq!(frame.rgba.len(), frame.width as usize * frame.height as usize * 4);
+        assert!(frame.delay_ms > 0);
+        assert!(frame.rgba.chunks(4).any(|pixel| pixel[3] != 0));
+    }
+
+    // 不是 GIF 的数据会被拒绝
+    assert!(decode_gif(&b"not a gif"[..], "broken.gif").is_err());
+
+    // 清单中的 GIF 条目直接从文件取得帧序列，不能再给出帧号范围
+    let manifest = AssetManifest::from_toml("[sprites.a]\npath = \"/plants/SnowPea.gif\"").unwrap();
+    assert!(manifest.sprites["a"].is_gif());
+    assert!(AssetManifest::from_toml("[sprites.a]\npath = \"/a/{}.gif\"\nframes = [1, 5]").is_err());
+}
+
+#[test]
+fn test_missing_asset_errors() {
+    use ggez::GameError;
+    use pvz_rust_wsll_lib::core::asset_manifest::AssetManifest;
+    use pvz_rust_wsll_lib::core::resources::{AssetError, MissingAsset};
+    use std::path::Path;
+
+    // 只有背景和商店栏是必需的素材，其余缺失的素材以棋盘格代替
+    let manifest = AssetManifest::load(Path::new("Resource")).unwrap();
+    let required: Vec<&str> = manifest.sprites.iter().filter(|(_, entry)| entry.required).map(|(name, _)| name.as_str()).collect();
+    assert_eq!(required, ["background", "shop"]);
+    assert!(AssetManifest::from_toml("[sprites.a]\nrequired = true\nplaceholder = { color = [0, 0, 0, 255], shape = \"plant\" }").is_err());
+
+    // 缺失必需素材的错误列出每一个缺失的文件，并能转换为 ggez 的错误
+    let error = AssetError::RequiredMissing(vec![MissingAsset {
+        sprite: "background".to_string(),
+        path: "/other_image/Background.png".to_string(),
+        reason: "file not found".to_string(),
+    }]);
+    let message = error.to_string();
+    assert!(message.contains("1 required asset(s) missing"));
+    assert!(message.contains("background (/other_image/Background.png): file not found"));
+    assert!(matches!(GameError::from(error), GameError::ResourceLoadError(m) if m == message));
+}
+
+#[test]
+fn test_file_watcher_reports_changes() {
+    use pvz_rust_wsll_lib::core::hot_reload::FileWatcher;
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    let dir = std::env::temp_dir().join("pvz_rust_wsll_test_watcher");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("plants")).unwrap();
+    fs::write(dir.join("tuning.toml"), "initial_sun = 50").unwrap();
+    fs::write(dir.join("plants/1.png"), "frame").unwrap();
+
+    // 开始监视时已有的文件不算改动
+    let mut watcher = FileWatcher::new(&dir);
+    assert!(watcher.poll().is_empty());
+
+    // 修改和新增的文件都会被报告一次，路径相对于被监视的目录
+    let later = SystemTime::now() + Duration::from_secs(5);
+    File::options().write(true).open(dir.join("tuning.toml")).unwrap().set_modified(later).unwrap();
+    fs::write(dir.join("plants/2.png"), "frame").unwrap();
+    assert_eq!(watcher.poll(), [PathBuf::from("plants/2.png"), PathBuf::from("tuning.toml")]);
+    assert!(watcher.poll().is_empty());
+
+    // 未到检查间隔时不扫描目录
+    fs::write(dir.join("plants/3.png"), "frame").unwrap();
+    assert!(watcher.update(1).is_empty());
+    assert_eq!(watcher.update(1000), [PathBuf::from("plants/3.png")]);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_animation_clips() {
+    use pvz_rust_wsll_lib::core::animation::{AnimationClip, Animator, PlaybackMode};
+    use std::collections::BTreeMap;
+
+    let clip = |mode| AnimationClip {
+        frame_durations: vec![100; 4],
+        mode,
+        events: BTreeMap::from([("bite".to_string(), 2)]),
+    };
+
+    // 三种播放方式在第一个周期之后的表现不同
+    let frames = |clip: &AnimationClip| (0..8).map(|i| clip.frame_at(i * 100)).collect::<Vec<_>>();
+    assert_eq!(frames(&clip(PlaybackMode::Loop)), [0, 1, 2, 3, 0, 1, 2, 3]);
+    assert_eq!(frames(&clip(PlaybackMode::HoldLast)), [0, 1, 2, 3, 3, 3, 3, 3]);
+    assert_eq!(frames(&clip(PlaybackMode::PingPong)), [0, 1, 2, 3, 2, 1, 0, 1]);
+    assert!(clip(PlaybackMode::HoldLast).is_finished(400));
+    assert!(!clip(PlaybackMode::Loop).is_finished(10_000));
+
+    // 帧事件在进入对应帧时触发，循环播放时每个周期触发一次，只播放一次的片段只触发一次
+    assert!(clip(PlaybackMode::Loop).events_between(0, 199).is_empty());
+    assert_eq!(clip(PlaybackMode::Loop).events_between(0, 1000), ["bite", "bite", "bite"]);
+    assert_eq!(clip(PlaybackMode::Loop).events_between(200, 600), ["bite"]);
+    assert_eq!(clip(PlaybackMode::HoldLast).events_between(0, 1000), ["bite"]);
+    assert_eq!(clip(PlaybackMode::PingPong).events_between(0, 1000), ["bite", "bite", "bite", "bite"]);
+
+    // 随游戏发布的死亡动画播放一次，在第 2 帧让头部掉落
+    let mut animator = Animator::new("zombie_die");
+    assert!(animator.update(399).is_empty());
+    assert_eq!(animator.update(1), ["head_drop"]);
+    assert!(!animator.is_finished());
+    animator.update(1600);
+    assert!(animator.is_finished());
+
+    // 切换到另一个片段时从头播放，重复切换到同一个片段不会重置
+    animator.play("zombie_walk");
+    assert_eq!((animator.clip_name(), animator.elapsed()), ("zombie_walk", 0));
+    animator.update(250);
+    animator.play("zombie_walk");
+    assert_eq!(animator.elapsed(), 250);
+}
+
+#[test]
+fn test_text_cache_reuses_and_drops_text() {
+    use ggez::graphics::Color;
+    use pvz_rust_wsll_lib::core::render_cache::TextCache;
+
+    // 同样的内容、颜色和字号只创建一次
+    let mut texts = TextCache::new();
+    texts.get("125", Color::BLACK, 25.0);
+    texts.get("125", Color::BLACK, 25.0);
+    texts.get("125", Color::RED, 25.0);
+    assert_eq!(texts.len(), 2);
+
+    // 一帧内没有再用到的文字在帧结束时被丢弃，不断变化的阳光数量不会让缓存增长
+    texts.end_frame();
+    texts.get("150", Color::BLACK, 25.0);
+    texts.end_frame();
+    assert_eq!(texts.len(), 1);
+    texts.end_frame();
+    assert!(texts.is_empty());
+}
@@ -1,23 +1,23 @@
-use pvz_rust_wsll_lib::entities::pea::{Pea, PeaType};
+use pvz_rust_wsll_lib::entities::projectile::{Projectile, ProjectileKind};
 
 #[test]
 fn test_pea_creation() {
-    let pea = Pea::new(100.0, 200.0, 2, PeaType::Normal);
+    let pea = Projectile::straight(100.0, 200.0, 2, ProjectileKind::Pea);
     
     assert_eq!(pea.x, 100.0);
     assert_eq!(pea.y, 200.0);
     assert_eq!(pea.row, 2);
-    assert_eq!(pea.pea_type, PeaType::Normal);
+    assert_eq!(pea.kind, ProjectileKind::Pea);
     assert!(pea.active);
     assert!(pea.damage > 0);
-    assert!(pea.speed > 0.0);
+    assert!(pea.velocity.0 > 0.0);
 }
 
 #[test]
 fn test_pea_update() {
-    let mut pea = Pea::new(100.0, 200.0, 2, PeaType::Normal);
+    let mut pea = Projectile::straight(100.0, 200.0, 2, ProjectileKind::Pea);
     let initial_x = pea.x;
-    let speed = pea.speed;
+    let speed = pea.velocity.0;
     
     // 模拟更新，使豌豆向前飞行 (100毫秒)
     pea.update(100);
@@ -29,7 +29,7 @@ fn test_pea_update() {
 
 #[test]
 fn test_inactive_pea() {
-    let mut pea = Pea::new(100.0, 200.0, 2, PeaType::Normal);
+    let mut pea = Projectile::straight(100.0, 200.0, 2, ProjectileKind::Pea);
     
     // 设置豌豆为非活动状态
     pea.active = false;
@@ -37,3 +37,66 @@ fn test_inactive_pea() {
     // 非活动的豌豆应该能被标识
     assert!(!pea.active);
 }
+
+#[test]
+fn test_pea_hits_roof_slope() {
+    use pvz_rust_wsll_lib::ui::lawn::LawnLayout;
+
+    let roof = LawnLayout::roof().geometry;
+    let step_ms = 10;
+
+    // 从斜坡最低处发射的豌豆会撞上前方升高的斜坡
+    let mut low = Projectile::straight_on_lawn(roof.column_x(0) + 40.0, 100.0, 0, ProjectileKind::Pea, roof);
+    while low.active && low.x < roof.column_x(roof.slope_columns) {
+        low.update(step_ms);
+    }
+    assert!(!low.active);
+    assert!(low.x < roof.column_x(roof.slope_columns));
+
+    // 从斜坡顶端发射的豌豆可以一直飞到草坪右侧
+    let mut high = Projectile::straight_on_lawn(roof.column_x(roof.slope_columns - 1) + 40.0, 100.0, 0, ProjectileKind::Pea, roof);
+    while high.active && high.x < roof.right() {
+        high.update(step_ms);
+    }
+    assert!(high.active);
+}
+
+#[test]
+fn test_lobbed_projectile_leads_target_and_stuns() {
+    use pvz_rust_wsll_lib::entities::projectile::{HitEffect, LOB_FLIGHT_TIME, BUTTER_STUN_DURATION};
+    use pvz_rust_wsll_lib::ui::lawn::LawnLayout;
+    use pvz_rust_wsll_lib::zombies::{Zombie, ZombieType};
+
+    let geometry = LawnLayout::standard().geometry;
+    let near = Zombie::on_lawn(ZombieType::Normal, 2, geometry);
+    let mut far = Zombie::on_lawn(ZombieType::Normal, 2, geometry);
+    far.x += 200.0;
+    let other_row = Zombie::on_lawn(ZombieType::Normal, 1, geometry);
+    let zombies = vec![far, near, other_row];
+
+    // 瞄准同一行中最近的僵尸，并提前量到飞行结束时的位置
+    let mut lob = Projectile::aimed_at_zombie((100.0, 200.0), 2, ProjectileKind::Cabbage, &zombies, &geometry)
+        .expect("应该能瞄准到第2行的僵尸");
+    assert!(lob.target().unwrap().0 < zombies[1].x + 50.0);
+    assert!(Projectile::aimed_at_zombie((100.0, 200.0), 0, ProjectileKind::Cabbage, &zombies, &geometry).is_none());
+
+    lob.update(LOB_FLIGHT_TIME / 2);
+    assert!(!lob.has_landed());
+    assert!(lob.y < 200.0, "投掷物在飞行途中应高于发射点");
+    lob.update(LOB_FLIGHT_TIME);
+    assert!(lob.has_landed());
+    assert_eq!(Some((lob.x, lob.y)), lob.target());
+
+    // 黄油会定身僵尸，定身期间僵尸不移动
+    assert_eq!(ProjectileKind::Butter.effects(), vec![HitEffect::Stun(BUTTER_STUN_DURATION)]);
+    let mut zombie = Zombie::on_lawn(ZombieType::Normal, 2, geometry);
+    let start_x = zombie.x;
+    zombie.stun(BUTTER_STUN_DURATION);
+    zombie.update(1000);
+    assert!(zombie.is_stunned());
+    assert_eq!(zombie.x, start_x);
+    zombie.update(BUTTER_STUN_DURATION);
+    assert!(!zombie.is_stunned());
+    zombie.update(1000);
+    assert!(zombie.x < start_x);
+}
@@ -14,9 +14,9 @@ fn test_plant_factory() {
     assert!(PlantType::WallNut.cost() > 0);
     
     // 测试获取植物生命值
-    assert!(peashooter.get_initial_health() > 0);
-    assert!(sunflower.get_initial_health() > 0);
-    assert!(wallnut.get_initial_health() > 0);
+    assert!(PlantType::Peashooter.spec().health > 0);
+    assert!(PlantType::Sunflower.spec().health > 0);
+    assert!(PlantType::WallNut.spec().health > 0);
     
     // 验证不同植物有不同的冷却时间
     assert!(peashooter.get_cooldown() > 0);
@@ -29,19 +29,37 @@ fn test_zombie_factory() {
     // 测试工厂能否创建不同类型的僵尸
     let normal_zombie = ZombieFactory::create_zombie(ZombieType::Normal);
     let conehead_zombie = ZombieFactory::create_zombie(ZombieType::Conehead);
-    
+    assert!(!normal_zombie.has_special_ability());
+    assert!(conehead_zombie.has_special_ability());
+
+    let normal = ZombieType::Normal.spec();
+    let conehead = ZombieType::Conehead.spec();
+
     // 测试僵尸初始健康值
-    assert!(normal_zombie.get_initial_health() > 0);
-    assert!(conehead_zombie.get_initial_health() > 0);
+    assert!(normal.health > 0);
+    assert!(conehead.health > 0);
     
     // 路障僵尸应该比普通僵尸有更多的生命值
-    assert!(conehead_zombie.get_initial_health() > normal_zombie.get_initial_health());
+    assert!(conehead.health > normal.health);
     
     // 测试僵尸攻击属性
-    assert!(normal_zombie.get_attack_damage() > 0);
-    assert!(conehead_zombie.get_attack_damage() > 0);
-    assert!(normal_zombie.get_attack_interval() > 0);
-    assert!(conehead_zombie.get_attack_interval() > 0);
+    assert!(normal.attack_damage > 0);
+    assert!(conehead.attack_damage > 0);
+    assert!(normal.attack_interval > 0);
+    assert!(conehead.attack_interval > 0);
+    assert!(normal.speed > 0.0);
+}
+
+#[test]
+fn test_specs_expose_static_stats() {
+    // 静态属性直接从注册表读取，无需创建植物实例
+    for plant_type in [PlantType::Peashooter, PlantType::Sunflower, PlantType::WallNut, PlantType::SnowPea] {
+        let spec = plant_type.spec();
+        assert!(spec.cost > 0);
+        assert!(spec.recharge_ms > 0);
+        assert!(spec.health > 0);
+    }
+    assert!(PlantType::WallNut.spec().recharge_ms > PlantType::Peashooter.spec().recharge_ms);
 }
 
 #[test]
@@ -58,3 +76,79 @@ fn test_zombie_type_enum() {
     assert_eq!(ZombieType::Normal, ZombieType::Normal);
     assert_ne!(ZombieType::Normal, ZombieType::Conehead);
 }
+
+#[test]
+fn test_registry_adds_custom_content() {
+    use pvz_rust_wsll_lib::core::profile::Profile;
+    use pvz_rust_wsll_lib::core::registry::{self, PlantSpec, ZombieSpec};
+    use pvz_rust_wsll_lib::mechanics::level_controller::LevelController;
+    use pvz_rust_wsll_lib::plants::plant_trait::PlantTrait;
+    use pvz_rust_wsll_lib::plants::{Plant, PlantContext, PlantLayer};
+    use pvz_rust_wsll_lib::ui::shop::Shop;
+    use pvz_rust_wsll_lib::zombies::normal_zombie::NormalZombie;
+    use pvz_rust_wsll_lib::zombies::Zombie;
+    use std::time::Duration;
+
+    struct Cactus;
+    impl PlantTrait for Cactus {
+        fn get_cooldown(&self) -> u64 {
+            1500
+        }
+        fn update_action(&mut self, _world: &mut PlantContext) {}
+    }
+
+    let cactus = registry::register_plant(PlantSpec {
+        id: "test_cactus",
+        name: "仙人掌",
+        cost: 125,
+        recharge_ms: 7500,
+        health: 300,
+        image: "test/cactus",
+        card: "test/cactus_card",
+        layer: PlantLayer::Main,
+        aquatic: false,
+        constructor: || Box::new(Cactus),
+    });
+    assert!(matches!(cactus, PlantType::Custom(_)));
+    assert_eq!(registry::plant_by_id("test_cactus"), Some(cactus));
+    assert!(registry::plant_types().contains(&cactus));
+    assert!(registry::sprite_keys().contains(&"test/cactus_card"));
+
+    // 工厂、商店和存档都通过注册表使用自定义植物
+    assert_eq!(cactus.cost(), 125);
+    assert_eq!(cactus.layer(), PlantLayer::Main);
+    assert!(!cactus.is_aquatic());
+    assert_eq!(PlantFactory::create_plant(cactus).get_cooldown(), 1500);
+    assert_eq!(Plant::new(cactus, 2, 2).health, 300);
+    let shop = Shop::with_plants(&[PlantType::Peashooter, cactus]);
+    assert_eq!(shop.cards[1].cooldown, Duration::from_millis(7500));
+
+    let mut profile = Profile::default();
+    profile.unlocked_plants.push(cactus);
+    let saved = toml::to_string(&profile).unwrap();
+    assert!(saved.contains("\"test_cactus\""));
+    assert_eq!(toml::from_str::<Profile>(&saved).unwrap(), profile);
+
+    // 重复注册同一标识会替换规格，而不是新增类型
+    let replaced = registry::register_plant(PlantSpec { cost: 150, ..cactus.spec() });
+    assert_eq!(replaced, cactus);
+    assert_eq!(cactus.cost(), 150);
+
+    let brute = registry::register_zombie(ZombieSpec {
+        id: "test_brute",
+        name: "壮汉僵尸",
+        health: 800,
+        speed: 0.02,
+        attack_damage: 100,
+        attack_interval: 1000,
+        walk: "zombie_walk",
+        attack: "zombie_attack",
+        swim: "zombie_swim",
+        constructor: || Box::new(NormalZombie::new()),
+    });
+    assert!(matches!(brute, ZombieType::Custom(_)));
+    let mut brute_zombie = Zombie::new(brute, 1);
+    assert!(!brute_zombie.take_damage(799));
+    assert!(brute_zombie.take_damage(1));
+    assert_eq!(LevelController::spawn_cost(brute), 4);
+}
@@ -0,0 +1,41 @@
+use pvz_rust_wsll_lib::core::leaderboard::{EndlessStats, Leaderboard, LeaderboardEntry, LEADERBOARD_SIZE};
+
+fn entry(flags_survived: usize, zombies_killed: u32) -> LeaderboardEntry {
+    LeaderboardEntry::new(
+        EndlessStats { flags_survived, zombies_killed, sun_produced: 0 },
+        0,
+    )
+}
+
+#[test]
+fn test_leaderboard_orders_and_truncates() {
+    let mut leaderboard = Leaderboard::default();
+
+    assert_eq!(leaderboard.record(entry(2, 10)), Some(0));
+    // 旗帜数更多的成绩排在前面
+    assert_eq!(leaderboard.record(entry(3, 5)), Some(0));
+    // 旗帜数相同时比较击杀数
+    assert_eq!(leaderboard.record(entry(2, 20)), Some(1));
+
+    for _ in 0..LEADERBOARD_SIZE {
+        leaderboard.record(entry(5, 0));
+    }
+    assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+    // 排行榜已满时，较差的成绩不会进入
+    assert_eq!(leaderboard.record(entry(1, 0)), None);
+    assert!(leaderboard.entries.iter().all(|e| e.flags_survived == 5));
+}
+
+#[test]
+fn test_leaderboard_save_and_load() {
+    let dir = std::env::temp_dir().join("pvz_rust_wsll_test_leaderboard");
+    let mut leaderboard = Leaderboard::default();
+    leaderboard.record(entry(4, 42));
+    leaderboard.record(entry(1, 3));
+
+    leaderboard.save(&dir).expect("leaderboard should be saved");
+    let loaded = Leaderboard::load(&dir);
+
+    assert_eq!(loaded, leaderboard);
+    let _ = std::fs::remove_dir_all(&dir);
+}
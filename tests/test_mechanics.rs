@@ -1,4 +1,4 @@
-use pvz_rust_wsll_lib::entities::pea::{Pea, PeaType};
+use pvz_rust_wsll_lib::entities::projectile::{Projectile, ProjectileKind};
 use pvz_rust_wsll_lib::zombies::Zombie;
 use pvz_rust_wsll_lib::zombies::zombie_factory::ZombieType;
 use pvz_rust_wsll_lib::plants::Plant;
@@ -7,7 +7,7 @@ use pvz_rust_wsll_lib::plants::plant_factory::PlantType;
 #[test]
 fn test_collision_detection() {
     // 创建一个豌豆，用于测试
-    let mut pea = Pea::new(500.0, 100.0, 1, PeaType::Normal);
+    let mut pea = Projectile::straight(500.0, 100.0, 1, ProjectileKind::Pea);
     
     // 豌豆的原始状态应该是活动的
     assert!(pea.active);
@@ -47,3 +47,156 @@ fn test_zombie_plant_interaction() {
     // 验证植物健康状态
     assert!(plant.health < initial_health);
 }
+
+#[test]
+fn test_projectiles_share_one_collision_path() {
+    use pvz_rust_wsll_lib::entities::projectile::{Flight, HitEffect};
+    use pvz_rust_wsll_lib::mechanics::collision::CollisionManager;
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+
+    let geometry = LawnGeometry::STANDARD;
+    let mut plants: Vec<Plant> = Vec::new();
+    let at_zombie = |zombie: &Zombie, kind: ProjectileKind| {
+        let rect = zombie.get_rect();
+        Projectile::straight(rect.x, rect.y + 10.0, zombie.row, kind)
+    };
+
+    // 寒冰豌豆命中后使僵尸减速并消失
+    let mut zombies = vec![Zombie::new(ZombieType::Normal, 1)];
+    let mut projectiles = vec![at_zombie(&zombies[0], ProjectileKind::SnowPea)];
+    CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    assert!(zombies[0].is_slowed());
+    assert!(projectiles.is_empty());
+
+    // 尖刺可以穿透多个僵尸，但不会重复命中同一个僵尸
+    let mut zombies = vec![Zombie::new(ZombieType::Normal, 1), Zombie::new(ZombieType::Normal, 1)];
+    let mut projectiles = vec![at_zombie(&zombies[0], ProjectileKind::Spike)];
+    for _ in 0..3 {
+        CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    }
+    assert_eq!(projectiles.len(), 1);
+    assert_eq!(projectiles[0].pierce, 0);
+
+    // 自由飞行的投射物会命中其他行中碰到的僵尸，直线飞行的不会
+    let mut zombies = vec![Zombie::new(ZombieType::Normal, 1)];
+    let mut lane = at_zombie(&zombies[0], ProjectileKind::Pea);
+    lane.row = 0;
+    let mut free = at_zombie(&zombies[0], ProjectileKind::Star);
+    free.row = 0;
+    free.flight = Flight::Free;
+    let mut projectiles = vec![lane, free];
+    CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    assert_eq!(projectiles.len(), 1);
+    assert_eq!(projectiles[0].flight, Flight::Lane);
+
+    // 打掉护甲的效果让路障僵尸直接以普通僵尸的生命值承受伤害
+    let mut zombies = vec![Zombie::new(ZombieType::Conehead, 1), Zombie::new(ZombieType::Conehead, 2)];
+    let mut knock = at_zombie(&zombies[0], ProjectileKind::Pea);
+    knock.damage = 200;
+    knock.effects.push(HitEffect::KnockOffArmor);
+    let mut plain = at_zombie(&zombies[1], ProjectileKind::Pea);
+    plain.damage = 200;
+    let mut projectiles = vec![knock, plain];
+    let kills = CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    assert_eq!(kills, 1);
+    assert!(zombies[0].is_dying);
+    assert!(!zombies[1].is_dying);
+}
+
+#[test]
+fn test_torchwood_converts_passing_peas() {
+    use pvz_rust_wsll_lib::entities::projectile::HitEffect;
+    use pvz_rust_wsll_lib::mechanics::collision::CollisionManager;
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+
+    let geometry = LawnGeometry::STANDARD;
+    let plants = vec![Plant::new(PlantType::Torchwood, 3, 1), Plant::new(PlantType::Torchwood, 5, 1)];
+    let fly_through = |kind: ProjectileKind, until_col: usize| {
+        let mut projectile = Projectile::straight(geometry.column_x(1), geometry.row_y(1), 1, kind);
+        let mut projectiles = Vec::new();
+        while projectile.x < geometry.column_x(until_col) {
+            projectile.update(10);
+            projectiles.push(projectile);
+            CollisionManager::handle_projectile_plant_interaction(&mut projectiles, &plants);
+            projectile = projectiles.pop().unwrap();
+        }
+        projectile
+    };
+
+    // 豌豆穿过火炬树桩后变成伤害加倍、带溅射的火焰豌豆
+    let fire = fly_through(ProjectileKind::Pea, 4);
+    assert_eq!(fire.kind, ProjectileKind::FirePea);
+    assert_eq!(fire.damage, ProjectileKind::Pea.damage() * 2);
+    assert!(fire.splash_radius > 0.0);
+
+    // 寒冰豌豆穿过第一个火炬树桩后变成普通豌豆，穿过第二个后才被点燃
+    let thawed = fly_through(ProjectileKind::SnowPea, 4);
+    assert_eq!(thawed.kind, ProjectileKind::Pea);
+    assert!(!thawed.effects.iter().any(|effect| matches!(effect, HitEffect::Slow(_))));
+    let lit = fly_through(ProjectileKind::SnowPea, 6);
+    assert_eq!(lit.kind, ProjectileKind::FirePea);
+
+    // 其他行的豌豆不受影响
+    let mut other_row = vec![Projectile::straight(geometry.column_x(3), geometry.row_y(2), 2, ProjectileKind::Pea)];
+    CollisionManager::handle_projectile_plant_interaction(&mut other_row, &plants);
+    assert_eq!(other_row[0].kind, ProjectileKind::Pea);
+}
+
+#[test]
+fn test_tangle_kelp_drownings_count_as_kills() {
+    use pvz_rust_wsll_lib::mechanics::collision::CollisionManager;
+    use pvz_rust_wsll_lib::ui::grid::Grid;
+    use pvz_rust_wsll_lib::ui::lawn::{LawnGeometry, LawnLayout};
+
+    let geometry = LawnGeometry::STANDARD;
+    let mut grid = Grid::with_layout(LawnLayout::pool());
+    grid.place(3, 2, PlantType::TangleKelp);
+    let mut plants = vec![Plant::new(PlantType::TangleKelp, 3, 2)];
+
+    // 游到缠绕水草面前的僵尸被拖入水下，计入击杀数
+    let mut zombies = vec![Zombie::new(ZombieType::Normal, 2)];
+    zombies[0].set_swimming(true);
+    zombies[0].x = geometry.column_x(3) - geometry.cell_width;
+    let kills = CollisionManager::handle_zombie_plant_interaction(&mut zombies, &mut plants, &mut grid, 16);
+    assert_eq!(kills, 1);
+    assert!(zombies[0].is_dying);
+    assert!(plants.is_empty());
+    assert!(grid.can_place(3, 2, PlantType::TangleKelp));
+}
+
+#[test]
+fn test_umbrella_leaf_absorbs_hostile_lobs() {
+    use pvz_rust_wsll_lib::mechanics::collision::CollisionManager;
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+
+    let geometry = LawnGeometry::STANDARD;
+    let mut zombies: Vec<Zombie> = Vec::new();
+    let land = |projectile: Projectile, plants: &mut Vec<Plant>, zombies: &mut Vec<Zombie>| {
+        let mut projectiles = vec![projectile];
+        while !projectiles.is_empty() {
+            projectiles[0].update(100);
+            CollisionManager::handle_projectile_collision(&mut projectiles, zombies, plants, &geometry);
+        }
+    };
+
+    // 投篮车僵尸向所在行最左侧的植物投出敌方篮球
+    let mut plants = vec![Plant::new(PlantType::Peashooter, 2, 1), Plant::new(PlantType::Peashooter, 1, 1)];
+    let mut catapult = Zombie::new(ZombieType::Catapult, 1);
+    assert!(catapult.throw_at_plants(&plants, 1000).is_none());
+    let lob = catapult.throw_at_plants(&plants, 2000).expect("catapult should throw once its timer is ready");
+    assert!(lob.hostile);
+    assert_eq!(lob.kind, ProjectileKind::Basketball);
+
+    // 没有保护伞时，篮球砸中落点单元格中的植物
+    let initial_health = plants[1].health;
+    let unblocked = Projectile::hostile_lobbed((lob.x, lob.y), lob.target().unwrap(), 1, ProjectileKind::Basketball, geometry);
+    land(unblocked, &mut plants, &mut zombies);
+    assert_eq!(plants[1].health, initial_health - ProjectileKind::Basketball.damage());
+    assert_eq!(plants[0].health, initial_health);
+
+    // 落在叶子保护伞旁边一格内的篮球被挡住，植物不受伤害
+    plants.push(Plant::new(PlantType::UmbrellaLeaf, 2, 2));
+    let damaged_health = plants[1].health;
+    land(lob, &mut plants, &mut zombies);
+    assert_eq!(plants[1].health, damaged_health);
+}
@@ -63,3 +63,197 @@ fn test_plant_types() {
         assert!(cost > 0);
     }
 }
+
+#[test]
+fn test_chomper_swallows_and_digests() {
+    use pvz_rust_wsll_lib::plants::chomper::DIGEST_DURATION;
+    use pvz_rust_wsll_lib::plants::{WorldEffect, WorldState};
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+    use pvz_rust_wsll_lib::zombies::{Zombie, ZombieType};
+
+    let geometry = LawnGeometry::STANDARD;
+    let mut chomper = Plant::new(PlantType::Chomper, 2, 1);
+    let in_front = |row: usize| {
+        let mut zombie = Zombie::new(ZombieType::Normal, row);
+        zombie.x = geometry.column_x(3) - 40.0;
+        zombie
+    };
+    let mut far = Zombie::new(ZombieType::Normal, 1);
+    far.x = geometry.column_x(8);
+    let mut zombies = vec![far, in_front(0), in_front(1)];
+    let (mut plants, mut projectiles, mut suns, mut events) = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+
+    // 吞掉同一行前方一格内的僵尸，不会吞掉远处或其他行的僵尸
+    let mut effects = Vec::new();
+    chomper.update(100, &zombies, &mut effects);
+    assert_eq!(effects.len(), 1);
+    assert!(matches!(effects[0], WorldEffect::SwallowZombie { zombie_id } if zombie_id == zombies[2].id()));
+    let mut world = WorldState {
+        zombies: &mut zombies,
+        plants: &mut plants,
+        projectiles: &mut projectiles,
+        suns: &mut suns,
+        events: &mut events,
+    };
+    assert_eq!(effects.pop().unwrap().apply(&mut world), 1);
+    assert!(zombies[2].is_dying);
+    assert!(!zombies[0].is_dying && !zombies[1].is_dying);
+
+    // 消化期间不能再吞食
+    let mut zombies = vec![in_front(1)];
+    for _ in 0..(DIGEST_DURATION / 100 - 1) {
+        chomper.update(100, &zombies, &mut effects);
+    }
+    assert!(effects.is_empty());
+
+    // 消化完成后可以再次吞食
+    for _ in 0..2 {
+        chomper.update(100, &zombies, &mut effects);
+    }
+    assert_eq!(effects.len(), 1);
+    let mut world = WorldState {
+        zombies: &mut zombies,
+        plants: &mut plants,
+        projectiles: &mut projectiles,
+        suns: &mut suns,
+        events: &mut events,
+    };
+    assert_eq!(effects.pop().unwrap().apply(&mut world), 1);
+}
+
+#[test]
+fn test_plant_context_queries_and_commands() {
+    use pvz_rust_wsll_lib::entities::projectile::HitEffect;
+    use pvz_rust_wsll_lib::plants::{PlantContext, PlantEvent, WorldEffect, WorldState};
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+    use pvz_rust_wsll_lib::zombies::{HitKind, Zombie, ZombieType};
+
+    let geometry = LawnGeometry::STANDARD;
+    let at = |col: usize, row: usize| {
+        let mut zombie = Zombie::new(ZombieType::Normal, row);
+        zombie.x = geometry.column_x(col);
+        zombie
+    };
+    let mut zombies = vec![at(0, 2), at(4, 2), at(6, 2), at(4, 3)];
+    let mut plants = vec![Plant::new(PlantType::WallNut, 3, 2), Plant::new(PlantType::Blover, 1, 1)];
+
+    // 按行、范围、单元格和前方查询僵尸
+    let mut effects = Vec::new();
+    let mut world = PlantContext::new(3, 2, PlantType::WallNut, geometry, 0, &zombies, &mut effects);
+    assert_eq!(world.zombies_in_lane(2).count(), 3);
+    assert_eq!(world.zombies_in_cell(6, 2).count(), 1);
+    assert_eq!(world.zombies_in_range(3, 0.0, f32::INFINITY).count(), 1);
+    assert_eq!(world.zombies_ahead().count(), 2);
+
+    // 命令写入缓冲区，统一执行后才改变世界
+    let target = world.zombies_ahead().next().unwrap().id();
+    world.damage_zombie(target, 20, HitKind::Straight);
+    world.affect_zombie(target, HitEffect::Slow(1000));
+    world.damage_plant(3, 2, 300);
+    world.heal_plant(3, 2, 500);
+    assert_eq!(effects.len(), 4);
+
+    // 三叶草发出吹散迷雾的事件并移除自身
+    plants[1].update(1000, &zombies, &mut effects);
+    assert!(matches!(effects[4], WorldEffect::Event(PlantEvent::BlowFog)));
+
+    let (mut projectiles, mut suns, mut events) = (Vec::new(), Vec::new(), Vec::new());
+    let mut world = WorldState {
+        zombies: &mut zombies,
+        plants: &mut plants,
+        projectiles: &mut projectiles,
+        suns: &mut suns,
+        events: &mut events,
+    };
+    for effect in effects {
+        effect.apply(&mut world);
+    }
+    let target = zombies.iter().find(|zombie| zombie.id() == target).unwrap();
+    assert!(target.is_slowed());
+    assert_eq!(plants[0].health, Plant::new(PlantType::WallNut, 0, 0).health);
+    assert!(plants[1].is_dead);
+    assert_eq!(events, vec![PlantEvent::BlowFog]);
+}
+
+#[test]
+fn test_cactus_starfruit_and_magnet_shroom_producers() {
+    use pvz_rust_wsll_lib::entities::projectile::{Flight, ProjectileKind};
+    use pvz_rust_wsll_lib::mechanics::collision::CollisionManager;
+    use pvz_rust_wsll_lib::plants::magnet_shroom::RECHARGE_DURATION;
+    use pvz_rust_wsll_lib::plants::{WorldEffect, WorldState};
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+    use pvz_rust_wsll_lib::zombies::{Zombie, ZombieType};
+
+    let geometry = LawnGeometry::STANDARD;
+    let at = |zombie_type: ZombieType, col: usize, row: usize| {
+        let mut zombie = Zombie::new(zombie_type, row);
+        zombie.x = geometry.column_x(col);
+        zombie
+    };
+    let fire = |plant: &mut Plant, zombies: &[Zombie]| {
+        let mut effects = Vec::new();
+        plant.update(1400, zombies, &mut effects);
+        effects
+            .into_iter()
+            .filter_map(|effect| match effect {
+                WorldEffect::SpawnProjectile(projectile) => Some(projectile),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut plants: Vec<Plant> = Vec::new();
+
+    // 仙人掌发射的尖刺穿透同一行中的两个僵尸
+    let mut zombies = vec![at(ZombieType::Normal, 4, 1), at(ZombieType::Normal, 6, 1)];
+    let mut projectiles = fire(&mut Plant::new(PlantType::Cactus, 0, 1), &zombies);
+    assert_eq!(projectiles.len(), 1);
+    assert_eq!(projectiles[0].kind, ProjectileKind::Spike);
+    while projectiles[0].x < geometry.column_x(8) {
+        projectiles[0].update(10);
+        CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    }
+    assert!(zombies.iter().all(|zombie| projectiles[0].has_hit(zombie.id())));
+
+    // 杨桃向五个方向发射自由飞行的星星，向上的星星命中上方一行中的僵尸
+    let mut stars = fire(&mut Plant::new(PlantType::Starfruit, 4, 2), &[at(ZombieType::Normal, 8, 2)]);
+    assert_eq!(stars.len(), 5);
+    assert!(stars.iter().all(|star| star.kind == ProjectileKind::Star && star.flight == Flight::Free));
+    let mut projectiles = vec![stars.remove(0)];
+    let mut above = at(ZombieType::Normal, 4, 1);
+    let rect = above.get_rect();
+    above.x += projectiles[0].x - (rect.x + rect.w / 2.0);
+    above.take_damage(190);
+    let mut zombies = vec![above];
+    let mut kills = 0;
+    while !projectiles.is_empty() {
+        projectiles[0].update(10);
+        kills += CollisionManager::handle_projectile_collision(&mut projectiles, &mut zombies, &mut plants, &geometry);
+    }
+    assert_eq!(kills, 1);
+
+    // 磁力菇打掉附近路障僵尸的护甲，之后需要充能，远处的僵尸不受影响
+    let mut magnet = Plant::new(PlantType::MagnetShroom, 3, 1);
+    let mut zombies = vec![at(ZombieType::Conehead, 8, 1), at(ZombieType::Conehead, 4, 1)];
+    let mut effects = Vec::new();
+    magnet.update(100, &zombies, &mut effects);
+    assert_eq!(effects.len(), 1);
+    let (mut projectiles, mut suns, mut events) = (Vec::new(), Vec::new(), Vec::new());
+    let mut world = WorldState {
+        zombies: &mut zombies,
+        plants: &mut plants,
+        projectiles: &mut projectiles,
+        suns: &mut suns,
+        events: &mut events,
+    };
+    effects.pop().unwrap().apply(&mut world);
+    assert!(zombies[0].has_armor());
+    assert!(!zombies[1].has_armor());
+
+    let zombies = vec![at(ZombieType::Conehead, 4, 1)];
+    for _ in 0..(RECHARGE_DURATION / 100) {
+        magnet.update(100, &zombies, &mut effects);
+    }
+    assert!(effects.is_empty());
+    magnet.update(100, &zombies, &mut effects);
+    assert_eq!(effects.len(), 1);
+}
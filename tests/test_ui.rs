@@ -1,6 +1,8 @@
 use pvz_rust_wsll_lib::ui::grid::Grid;
 use pvz_rust_wsll_lib::ui::shop::Shop;
 use pvz_rust_wsll_lib::ui::shovel::Shovel;
+use pvz_rust_wsll_lib::ui::seed_chooser::SeedChooser;
+use pvz_rust_wsll_lib::plants::PlantType;
 
 #[test]
 fn test_grid_creation() {
@@ -28,3 +30,215 @@ fn test_shovel_creation() {
     // 因为我们无法直接访问Shovel的属性，只是验证创建不会崩溃
     assert!(true);
 }
+
+#[test]
+fn test_seed_chooser_slots() {
+    let available = [PlantType::Peashooter, PlantType::Sunflower, PlantType::WallNut];
+    let mut chooser = SeedChooser::new(&available, 2);
+
+    // 未选择卡片时不能开始
+    assert!(!chooser.is_ready());
+
+    assert!(chooser.pick(PlantType::Sunflower));
+    // 同一种植物不能重复选择
+    assert!(!chooser.pick(PlantType::Sunflower));
+    assert!(chooser.pick(PlantType::Peashooter));
+    // 卡槽已满
+    assert!(chooser.is_full());
+    assert!(!chooser.pick(PlantType::WallNut));
+
+    // 移除第一张卡片后，后面的卡片前移
+    assert_eq!(chooser.remove(0), Some(PlantType::Sunflower));
+    assert_eq!(chooser.chosen, vec![PlantType::Peashooter]);
+    assert!(chooser.is_ready());
+}
+
+#[test]
+fn test_shop_with_chosen_plants() {
+    let shop = Shop::with_plants(&[PlantType::WallNut, PlantType::Peashooter]);
+
+    // 卡片按照选择顺序排列，且位置依次向右
+    assert_eq!(shop.cards.len(), 2);
+    assert_eq!(shop.cards[0].plant_type, PlantType::WallNut);
+    assert_eq!(shop.cards[1].plant_type, PlantType::Peashooter);
+    assert!(shop.cards[1].position.0 > shop.cards[0].position.0);
+}
+
+#[test]
+fn test_grid_graves_block_planting() {
+    use pvz_rust_wsll_lib::ui::grid::GRAVE_MIN_COLUMN;
+    use pvz_rust_wsll_lib::ui::lawn::{LawnLayout, TileType};
+
+    let grid = Grid::with_graves(LawnLayout::pool(), 5);
+    let graves = grid.graves();
+
+    // 墓碑数量正确，且都位于右侧区域的草地上
+    assert_eq!(graves.len(), 5);
+    for &(x, y) in &graves {
+        assert!(x >= GRAVE_MIN_COLUMN);
+        assert_eq!(grid.layout().tile(y), TileType::Grass);
+        assert!(grid.has_grave(x, y));
+        assert!(!grid.can_place(x, y, PlantType::Peashooter));
+        // 墓碑不算作植物占据
+        assert!(!grid.is_occupied(x, y));
+    }
+
+    // 普通网格的所有单元格都可以种植
+    let mut grid = Grid::new();
+    assert!(grid.graves().is_empty());
+    assert!(grid.can_place(0, 0, PlantType::Peashooter));
+    grid.place(0, 0, PlantType::Peashooter);
+    assert!(!grid.can_place(0, 0, PlantType::Peashooter));
+}
+
+#[test]
+fn test_lawn_layouts() {
+    use pvz_rust_wsll_lib::ui::lawn::{LawnGeometry, LawnLayout, TileType};
+    use pvz_rust_wsll_lib::zombies::{Zombie, ZombieType};
+
+    // 标准草坪与原来的固定尺寸一致
+    let standard = LawnGeometry::STANDARD;
+    assert_eq!((standard.rows, standard.cols), (5, 9));
+    assert_eq!(Zombie::new(ZombieType::Normal, 0).x, standard.zombie_spawn_x());
+
+    // 6行草坪：最后一行也可以被点击和种植
+    let six_rows = LawnGeometry { rows: 6, cell_height: 85.0, ..LawnGeometry::STANDARD };
+    let bottom_y = six_rows.row_y(5) + 10.0;
+    assert_eq!(six_rows.cell_at(six_rows.origin_x + 1.0, bottom_y), Some((0, 5)));
+    let grid = Grid::with_layout(LawnLayout { geometry: six_rows, row_tiles: vec![TileType::Grass; 6] });
+    assert!(grid.can_place(8, 5, PlantType::Peashooter));
+
+    // 3行教学草坪：僵尸只会出现在这3行中，标准草坪的第一行位置不在草坪内
+    let tutorial = LawnLayout::tutorial();
+    assert_eq!(tutorial.spawn_rows(), vec![0, 1, 2]);
+    let grid = Grid::with_layout(tutorial);
+    assert_eq!(grid.get_grid_position(standard.origin_x + 1.0, standard.origin_y + 1.0), None);
+
+    // 泥地行不能种植，也不会出现僵尸
+    let dirt_layout = LawnLayout {
+        geometry: LawnGeometry { rows: 3, ..LawnGeometry::STANDARD },
+        row_tiles: vec![TileType::Dirt, TileType::Grass, TileType::Dirt],
+    };
+    assert_eq!(dirt_layout.spawn_rows(), vec![1]);
+    let grid = Grid::with_layout(dirt_layout);
+    assert!(!grid.can_place(0, 0, PlantType::Peashooter));
+    assert!(grid.can_place(0, 1, PlantType::Peashooter));
+}
+
+#[test]
+fn test_pool_placement_rules() {
+    use pvz_rust_wsll_lib::ui::lawn::{LawnLayout, TileType};
+
+    // 泳池草坪有6行，中间两行是水面，僵尸也会从水面行出现
+    let pool = LawnLayout::pool();
+    assert_eq!(pool.geometry.rows, 6);
+    assert_eq!(pool.tile(2), TileType::Water);
+    assert_eq!(pool.tile(3), TileType::Water);
+    assert_eq!(pool.spawn_rows(), vec![0, 1, 2, 3, 4, 5]);
+
+    let mut grid = Grid::with_layout(pool);
+
+    // 草地上只能种陆地植物
+    assert!(grid.can_place(0, 0, PlantType::Peashooter));
+    assert!(!grid.can_place(0, 0, PlantType::LilyPad));
+    assert!(!grid.can_place(0, 0, PlantType::TangleKelp));
+
+    // 水面上只能直接种水生植物
+    assert!(!grid.can_place(0, 2, PlantType::Peashooter));
+    assert!(grid.can_place(0, 2, PlantType::LilyPad));
+    assert!(grid.can_place(0, 2, PlantType::TangleKelp));
+
+    // 种下睡莲后，陆地植物可以种在睡莲上，但不能再种水生植物
+    grid.place(0, 2, PlantType::LilyPad);
+    assert!(grid.can_place(0, 2, PlantType::Peashooter));
+    assert!(!grid.can_place(0, 2, PlantType::TangleKelp));
+
+    grid.place(0, 2, PlantType::Peashooter);
+    assert!(!grid.can_place(0, 2, PlantType::WallNut));
+
+    // 铲除睡莲上的植物后，睡莲仍然保留
+    grid.remove(0, 2, PlantType::Peashooter);
+    assert!(grid.can_place(0, 2, PlantType::WallNut));
+    grid.remove(0, 2, PlantType::LilyPad);
+    assert!(!grid.can_place(0, 2, PlantType::WallNut));
+}
+
+#[test]
+fn test_grid_cell_layers() {
+    use pvz_rust_wsll_lib::plants::PlantLayer;
+
+    let mut grid = Grid::new();
+
+    // 南瓜头可以套在已有的植物外面
+    grid.place(0, 0, PlantType::Peashooter);
+    assert!(grid.can_place(0, 0, PlantType::Pumpkin));
+    grid.place(0, 0, PlantType::Pumpkin);
+    assert!(grid.cell(0, 0).has(PlantLayer::Shell));
+    assert!(!grid.can_place(0, 0, PlantType::Pumpkin));
+
+    // 也可以先种南瓜头，再往里面种植物
+    grid.place(1, 0, PlantType::Pumpkin);
+    assert!(grid.can_place(1, 0, PlantType::WallNut));
+
+    // 移除南瓜头后主层植物保留
+    grid.remove(0, 0, PlantType::Pumpkin);
+    assert!(grid.is_occupied(0, 0));
+    assert!(!grid.cell(0, 0).has(PlantLayer::Shell));
+}
+
+#[test]
+fn test_fog_hides_right_columns_until_lit_or_blown() {
+    use pvz_rust_wsll_lib::plants::Plant;
+    use pvz_rust_wsll_lib::ui::fog::{Fog, BLOVER_CLEAR_DURATION};
+    use pvz_rust_wsll_lib::ui::lawn::LawnGeometry;
+
+    let geometry = LawnGeometry::STANDARD;
+    let mut fog = Fog::new(3);
+
+    // 只有最右侧3列被遮挡
+    let mask = fog.mask(&geometry, &[]);
+    assert!(!mask.is_cell_hidden(5, 0));
+    assert!(mask.is_cell_hidden(6, 0));
+    assert!(mask.hides(geometry.zombie_spawn_x(), 2));
+    assert!(!mask.hides(geometry.origin_x + 1.0, 2));
+
+    // 路灯花照亮周围的单元格
+    let plants = vec![Plant::new(PlantType::Plantern, 6, 2)];
+    let mask = fog.mask(&geometry, &plants);
+    assert!(!mask.is_cell_hidden(7, 2));
+    assert!(!mask.is_cell_hidden(7, 3));
+    assert!(mask.is_cell_hidden(8, 2));
+    assert!(mask.is_cell_hidden(6, 0));
+
+    // 三叶草吹散迷雾一段时间
+    fog.blow_away();
+    assert!(!fog.mask(&geometry, &[]).is_cell_hidden(8, 0));
+    fog.update(BLOVER_CLEAR_DURATION);
+    assert!(fog.mask(&geometry, &[]).is_cell_hidden(8, 0));
+
+    // 没有迷雾的关卡
+    assert!(!Fog::none().mask(&geometry, &[]).is_cell_hidden(8, 0));
+}
+
+#[test]
+fn test_roof_requires_flower_pot() {
+    use pvz_rust_wsll_lib::ui::lawn::LawnLayout;
+
+    let mut grid = Grid::with_layout(LawnLayout::roof());
+
+    // 屋顶上只能直接种花盆
+    assert!(!grid.can_place(0, 0, PlantType::Peashooter));
+    assert!(!grid.can_place(0, 0, PlantType::Pumpkin));
+    assert!(!grid.can_place(0, 0, PlantType::LilyPad));
+    assert!(grid.can_place(0, 0, PlantType::FlowerPot));
+
+    // 种下花盆后，其他植物可以种在花盆上
+    grid.place(0, 0, PlantType::FlowerPot);
+    assert!(grid.can_place(0, 0, PlantType::Peashooter));
+    assert!(grid.can_place(0, 0, PlantType::Pumpkin));
+
+    // 花盆不能种在水面上，但可以种在草地上
+    let pool = Grid::with_layout(LawnLayout::pool());
+    assert!(!pool.can_place(0, 2, PlantType::FlowerPot));
+    assert!(pool.can_place(0, 0, PlantType::FlowerPot));
+}
@@ -78,3 +78,33 @@ fn test_zombie_types() {
         assert_eq!(zombie.get_zombie_type(), zombie_types[i]);
     }
 }
+
+#[test]
+fn test_level_sprite_keys() {
+    use pvz_rust_wsll_lib::zombies::sprite_keys;
+
+    // 只有普通僵尸的关卡也需要死亡和头部掉落动画
+    let normal = ZombieType::Normal.spec();
+    let keys = sprite_keys(&[ZombieType::Normal]);
+    assert_eq!(keys, ["zombie_die", "zombie_head", normal.walk, normal.attack, normal.swim]);
+
+    // 路障僵尸掉落路障后使用普通僵尸的动画，所以两者的贴图都要加载，且不重复
+    let conehead = ZombieType::Conehead.spec();
+    let keys = sprite_keys(&[ZombieType::Conehead, ZombieType::Normal]);
+    assert_eq!(keys.len(), 8);
+    assert!(keys.contains(&conehead.walk) && keys.contains(&conehead.attack) && keys.contains(&conehead.swim));
+    assert!(keys.contains(&normal.walk));
+}
+
+#[test]
+fn test_death_animation_completes_after_clip() {
+    // 死亡动画按清单中的 `zombie_die` 片段播放一次（10 帧，每秒 5 帧），播放完成后僵尸才可以移除
+    let mut zombie = Zombie::new(ZombieType::Normal, 0);
+    assert!(zombie.take_damage(10_000));
+    assert!(zombie.is_dying);
+
+    zombie.update(1999);
+    assert!(!zombie.death_animation_complete);
+    zombie.update(1);
+    assert!(zombie.death_animation_complete);
+}